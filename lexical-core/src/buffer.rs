@@ -0,0 +1,187 @@
+//! Stack-allocated buffer to write numbers to string.
+
+use core::{fmt, ops, str};
+
+use crate::{Result, ToLexical, ToLexicalWithOptions, BUFFER_SIZE};
+
+/// Reusable buffer to write numbers to string without allocating.
+///
+/// The buffer holds a `SIZE`-byte array on the stack, and dereferences to
+/// the last number written, so it can be used anywhere a [`str`] can, such
+/// as through [`Display`][fmt::Display]. The default `SIZE` is
+/// [`BUFFER_SIZE`], which fits any number written with the default options.
+/// When writing with custom options, use [`buffer_size_const`] for the
+/// size of the buffer.
+///
+/// The size is a const generic rather than the number type, since array
+/// lengths cannot depend on the associated constants of a generic type.
+/// This also allows the same buffer to write numbers of any type.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "write-integers", feature = "write-floats"))] {
+/// use lexical_core::NumberBuffer;
+///
+/// let mut buffer = NumberBuffer::new();
+/// assert_eq!(buffer.format(1234u32), "1234");
+/// assert_eq!(buffer.format(1.5f64), "1.5");
+/// assert_eq!(buffer.len(), 3);
+/// # }
+/// ```
+///
+/// [`buffer_size_const`]: https://docs.rs/lexical-core/latest/lexical_core/struct.WriteFloatOptions.html#method.buffer_size_const
+#[derive(Clone, Copy)]
+pub struct NumberBuffer<const SIZE: usize = BUFFER_SIZE> {
+    /// The buffer to write digits to.
+    bytes: [u8; SIZE],
+    /// The number of bytes written by the last number.
+    len: usize,
+}
+
+impl NumberBuffer {
+    /// Create a new, empty buffer with the default size.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_sized()
+    }
+}
+
+impl<const SIZE: usize> NumberBuffer<SIZE> {
+    /// Create a new, empty buffer with a custom size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "write-integers")] {
+    /// use lexical_core::{FormattedSize, NumberBuffer};
+    ///
+    /// let buffer = NumberBuffer::<{ u64::FORMATTED_SIZE_DECIMAL }>::new_sized();
+    /// assert_eq!(buffer.as_str(), "");
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn new_sized() -> Self {
+        Self {
+            bytes: [0; SIZE],
+            len: 0,
+        }
+    }
+
+    /// Write a number to the buffer, returning the written string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough to hold the serialized
+    /// number, with the same requirements as [`write`][crate::write].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "write-integers")] {
+    /// use lexical_core::{FormattedSize, NumberBuffer};
+    ///
+    /// let mut buffer = NumberBuffer::<{ i8::FORMATTED_SIZE_DECIMAL }>::new_sized();
+    /// assert_eq!(buffer.format(-128i8), "-128");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn format<N: ToLexical>(&mut self, n: N) -> &str {
+        self.len = n.to_lexical(&mut self.bytes).len();
+        self.as_str()
+    }
+
+    /// Write a number to the buffer with custom options, returning the
+    /// written string.
+    ///
+    /// * `FORMAT`  - Packed struct containing the number format.
+    /// * `n`       - Number to serialize.
+    /// * `options` - Options to customize number writing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is not large enough to hold the
+    /// serialized number, or if the format or options are invalid, with the
+    /// same requirements as
+    /// [`try_write_with_options`][crate::try_write_with_options]. On error,
+    /// the buffer is left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "write-floats")] {
+    /// use lexical_core::{format, NumberBuffer, WriteFloatOptions};
+    ///
+    /// const FORMAT: u128 = format::STANDARD;
+    /// const OPTIONS: WriteFloatOptions = WriteFloatOptions::builder()
+    ///     .trim_floats(true)
+    ///     .build_strict();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<f64, FORMAT>();
+    ///
+    /// let mut buffer = NumberBuffer::<SIZE>::new_sized();
+    /// assert_eq!(buffer.format_with_options::<_, FORMAT>(1.0f64, &OPTIONS), Ok("1"));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn format_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+        &mut self,
+        n: N,
+        options: &N::Options,
+    ) -> Result<&str> {
+        self.len = 0;
+        self.len = n.try_to_lexical_with_options::<FORMAT>(&mut self.bytes, options)?.len();
+        Ok(self.as_str())
+    }
+
+    /// Get the last number written as a string.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: safe since the written numbers are always valid ASCII: the
+        // default options are always valid, and custom options are validated
+        // before writing.
+        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Get the last number written as bytes.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl<const SIZE: usize> Default for NumberBuffer<SIZE> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new_sized()
+    }
+}
+
+impl<const SIZE: usize> ops::Deref for NumberBuffer<SIZE> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const SIZE: usize> AsRef<str> for NumberBuffer<SIZE> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const SIZE: usize> fmt::Display for NumberBuffer<SIZE> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const SIZE: usize> fmt::Debug for NumberBuffer<SIZE> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
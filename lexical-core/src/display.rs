@@ -0,0 +1,231 @@
+//! Adapters to write numbers through `core::fmt`.
+
+use core::fmt;
+
+use lexical_util::format::{NumberFormat, STANDARD};
+
+use crate::{Error, Result, ToLexicalWithOptions, BUFFER_SIZE};
+
+/// Size of the fallback buffer for numbers larger than [`BUFFER_SIZE`].
+///
+/// This fits any number unless it has digit separators, or a large minimum
+/// number of significant digits or decimal places, since the exact number
+/// of bytes required is checked.
+#[cfg(not(feature = "std"))]
+const LARGE_BUFFER_SIZE: usize = 1200;
+
+/// Adapter to write a number with custom options through [`fmt::Display`].
+///
+/// This writes the number using the `FORMAT` and options, and then pads
+/// it based on the width, fill, alignment, and sign flags of the
+/// [`Formatter`][fmt::Formatter]. The precision of the formatter is
+/// ignored, since the significant digits are specified by the options.
+/// Numbers are right-aligned by default, and zero padding is inserted
+/// after the sign and base prefix.
+///
+/// Numbers are written to a buffer on the stack, so no allocation is
+/// required. If the options require more than [`BUFFER_SIZE`] bytes,
+/// the number is written to the heap with the `std` feature, or to a
+/// larger buffer on the stack otherwise, which returns [`fmt::Error`]
+/// if the number cannot fit.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "write-floats")] {
+/// use core::fmt::Write;
+///
+/// use lexical_core::{format, NumberDisplay, WriteFloatOptions};
+///
+/// const OPTIONS: WriteFloatOptions = WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build_strict();
+///
+/// let mut string = String::new();
+/// write!(string, "[{:>6}]", lexical_core::display(2.0f64, &OPTIONS)).unwrap();
+/// assert_eq!(string, "[     2]");
+///
+/// string.clear();
+/// let value = NumberDisplay::<_, { format::STANDARD }>::new(-1.5f64, &OPTIONS);
+/// write!(string, "{:+08}", value).unwrap();
+/// assert_eq!(string, "-00001.5");
+/// # }
+/// ```
+pub struct NumberDisplay<'a, N: ToLexicalWithOptions, const FORMAT: u128 = STANDARD> {
+    /// The number to write.
+    value: N,
+    /// The options to write the number with.
+    options: &'a N::Options,
+}
+
+impl<'a, N: ToLexicalWithOptions, const FORMAT: u128> NumberDisplay<'a, N, FORMAT> {
+    /// Create an adapter to write the number with custom options.
+    ///
+    /// * `FORMAT`  - Packed struct containing the number format.
+    /// * `value`   - Number to serialize.
+    /// * `options` - Options to customize number writing.
+    #[inline(always)]
+    pub const fn new(value: N, options: &'a N::Options) -> Self {
+        Self {
+            value,
+            options,
+        }
+    }
+}
+
+impl<N: ToLexicalWithOptions, const FORMAT: u128> Clone for NumberDisplay<'_, N, FORMAT> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N: ToLexicalWithOptions, const FORMAT: u128> Copy for NumberDisplay<'_, N, FORMAT> {
+}
+
+impl<N: ToLexicalWithOptions, const FORMAT: u128> fmt::Display for NumberDisplay<'_, N, FORMAT> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.value;
+        let options = self.options;
+        // NaN is never equal to itself, and is written without a sign.
+        #[allow(clippy::eq_op)] // reason = "checks for NaN"
+        let is_nan = value != value;
+        let format = NumberFormat::<FORMAT> {};
+        let base_prefix = if format.has_base_prefix() {
+            Some(format.base_prefix())
+        } else {
+            None
+        };
+        fmt_number(f, is_nan, base_prefix, |bytes| {
+            value.try_to_lexical_with_options::<FORMAT>(bytes, options).map(|x| x.len())
+        })
+    }
+}
+
+/// Create an adapter to write a number with custom options through
+/// [`fmt::Display`], using the [`STANDARD`] format.
+///
+/// See [`NumberDisplay`] for how the number is padded, and to use
+/// a custom number format.
+///
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number writing.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "write-integers")] {
+/// use core::fmt::Write;
+///
+/// use lexical_core::WriteIntegerOptions;
+///
+/// const OPTIONS: WriteIntegerOptions = WriteIntegerOptions::new();
+///
+/// let mut string = String::new();
+/// write!(string, "{:*^7}", lexical_core::display(-123i32, &OPTIONS)).unwrap();
+/// assert_eq!(string, "*-123**");
+/// # }
+/// ```
+#[inline(always)]
+pub const fn display<N: ToLexicalWithOptions>(
+    value: N,
+    options: &N::Options,
+) -> NumberDisplay<'_, N, STANDARD> {
+    NumberDisplay::new(value, options)
+}
+
+/// Write the number using `write` to a buffer, and then pad it.
+fn fmt_number<Cb>(
+    f: &mut fmt::Formatter<'_>,
+    is_nan: bool,
+    base_prefix: Option<u8>,
+    write: Cb,
+) -> fmt::Result
+where
+    Cb: Fn(&mut [u8]) -> Result<usize>,
+{
+    let mut buffer = [0u8; BUFFER_SIZE];
+    match write(&mut buffer) {
+        Ok(count) => pad_number(f, &buffer[..count], is_nan, base_prefix),
+        Err(Error::BufferTooSmall(size)) => fmt_large_number(f, is_nan, base_prefix, size, write),
+        Err(_) => Err(fmt::Error),
+    }
+}
+
+/// Write a number that does not fit in [`BUFFER_SIZE`] bytes, and then pad it.
+///
+/// This is separate so the larger buffer is only on the stack when needed.
+#[inline(never)]
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+fn fmt_large_number<Cb>(
+    f: &mut fmt::Formatter<'_>,
+    is_nan: bool,
+    base_prefix: Option<u8>,
+    size: usize,
+    write: Cb,
+) -> fmt::Result
+where
+    Cb: Fn(&mut [u8]) -> Result<usize>,
+{
+    #[cfg(feature = "std")]
+    let mut buffer = vec![0u8; size];
+    #[cfg(not(feature = "std"))]
+    let mut buffer = [0u8; LARGE_BUFFER_SIZE];
+    let count = write(&mut buffer).map_err(|_| fmt::Error)?;
+    pad_number(f, &buffer[..count], is_nan, base_prefix)
+}
+
+/// Write the number, padded based on the flags of the formatter.
+fn pad_number(
+    f: &mut fmt::Formatter<'_>,
+    bytes: &[u8],
+    is_nan: bool,
+    base_prefix: Option<u8>,
+) -> fmt::Result {
+    // SAFETY: safe since the written numbers are always valid UTF-8.
+    // They are ASCII except for SI prefixes such as `µ`.
+    let number = unsafe { core::str::from_utf8_unchecked(bytes) };
+    let (sign, digits) = match bytes.first() {
+        Some(b'-' | b'+') => number.split_at(1),
+        _ if f.sign_plus() && !is_nan => ("+", number),
+        _ => ("", number),
+    };
+
+    // The width is in characters, not bytes, like `core::fmt`.
+    let length = sign.len() + digits.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > length => width - length,
+        _ => return f.write_str(sign).and_then(|_| f.write_str(digits)),
+    };
+
+    if f.sign_aware_zero_pad() {
+        // The zeros go after the base prefix, as with `{:#010x}`.
+        let (prefix, digits) = match base_prefix {
+            Some(c) if digits.as_bytes().starts_with(&[b'0', c]) => digits.split_at(2),
+            _ => ("", digits),
+        };
+        f.write_str(sign)?;
+        f.write_str(prefix)?;
+        write_fill(f, '0', padding)?;
+        return f.write_str(digits);
+    }
+
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    write_fill(f, fill, before)?;
+    f.write_str(sign)?;
+    f.write_str(digits)?;
+    write_fill(f, fill, after)
+}
+
+/// Write the fill character `count` times.
+fn write_fill(f: &mut fmt::Formatter<'_>, fill: char, count: usize) -> fmt::Result {
+    for _ in 0..count {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
@@ -509,6 +509,7 @@
 //! - The exponent [`break-point`][`write-float-positive_exponent_break`] for scientific notation.
 //! - The [`maximum`][`write-float-max_significant_digits`] and [`minimum`][`write-float-min_significant_digits`] number of significant digits to write.
 //! - The rounding [`mode`][`write-float-round_mode`] when truncating significant digits while writing.
+//! - An explicit [`sign`][`write-float-exponent_plus_sign`] for positive exponents, and the [`minimum`][`write-float-min_exponent_digits`] number of exponent digits to write.
 //!
 //! <!-- Spacer for Rustfmt -->
 #![cfg_attr(
@@ -522,6 +523,8 @@
 [`write-float-max_significant_digits`]: WriteFloatOptionsBuilder::max_significant_digits
 [`write-float-min_significant_digits`]: WriteFloatOptionsBuilder::min_significant_digits
 [`write-float-round_mode`]: WriteFloatOptionsBuilder::round_mode
+[`write-float-exponent_plus_sign`]: WriteFloatOptionsBuilder::exponent_plus_sign
+[`write-float-min_exponent_digits`]: WriteFloatOptionsBuilder::min_exponent_digits
 "
 )]
 #![cfg_attr(
@@ -535,6 +538,8 @@
 [`write-float-max_significant_digits`]: https://docs.rs/lexical-core/latest/lexical_core/struct.WriteFloatOptionsBuilder.html#method.max_significant_digits
 [`write-float-min_significant_digits`]: https://docs.rs/lexical-core/latest/lexical_core/struct.WriteFloatOptionsBuilder.html#method.min_significant_digits
 [`write-float-round_mode`]: https://docs.rs/lexical-core/latest/lexical_core/struct.WriteFloatOptionsBuilder.html#method.round_mode
+[`write-float-exponent_plus_sign`]: https://docs.rs/lexical-core/latest/lexical_core/struct.WriteFloatOptionsBuilder.html#method.exponent_plus_sign
+[`write-float-min_exponent_digits`]: https://docs.rs/lexical-core/latest/lexical_core/struct.WriteFloatOptionsBuilder.html#method.min_exponent_digits
 "
 )]
 //!
@@ -686,8 +691,7 @@
     clippy::unnecessary_safety_comment,
     clippy::semicolon_if_nothing_returned,
     clippy::unwrap_used,
-    clippy::as_underscore,
-    clippy::doc_markdown
+    clippy::as_underscore
 )]
 #![allow(
     // used when concepts are logically separate
@@ -738,8 +742,11 @@ use lexical_util::{from_lexical, from_lexical_with_options};
 #[cfg(feature = "parse-floats")]
 pub use lexical_parse_float::{
     options as parse_float_options,
+    Exactness,
+    FromLexicalExact,
     Options as ParseFloatOptions,
     OptionsBuilder as ParseFloatOptionsBuilder,
+    RoundMode as ParseRoundMode,
 };
 
 #[cfg(feature = "parse-floats")]
@@ -762,7 +769,7 @@ use lexical_parse_integer::{
 // WRITE
 
 #[cfg(any(feature = "write-floats", feature = "write-integers"))]
-pub use lexical_util::options::WriteOptions;
+pub use lexical_util::options::{DigitGrouping, WriteOptions};
 
 #[cfg(any(feature = "write-floats", feature = "write-integers"))]
 use lexical_util::{to_lexical, to_lexical_with_options};
@@ -770,11 +777,28 @@ use lexical_util::{to_lexical, to_lexical_with_options};
 #[cfg(any(feature = "write-floats", feature = "write-integers"))]
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+use core::mem::MaybeUninit;
+
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+mod buffer;
+
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub use self::buffer::NumberBuffer;
+
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+mod display;
+
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub use self::display::{display, NumberDisplay};
+
 #[cfg(feature = "write-floats")]
 pub use lexical_write_float::{
     options as write_float_options,
+    Notation,
     Options as WriteFloatOptions,
     OptionsBuilder as WriteFloatOptionsBuilder,
+    RoundMode as WriteRoundMode,
 };
 #[cfg(feature = "write-floats")]
 use lexical_write_float::{ToLexical as ToFloat, ToLexicalWithOptions as ToFloatWithOptions};
@@ -784,6 +808,7 @@ pub use lexical_write_integer::{
     options as write_integer_options,
     Options as WriteIntegerOptions,
     OptionsBuilder as WriteIntegerOptionsBuilder,
+    Padding,
 };
 
 #[cfg(feature = "write-integers")]
@@ -911,6 +936,11 @@ macro_rules! to_lexical_impl {
             fn to_lexical(self, bytes: &mut [u8]) -> &mut [u8] {
                 <Self as $to>::to_lexical(self, bytes)
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn try_to_lexical(self, bytes: &mut [u8]) -> Result<&mut [u8]> {
+                <Self as $to>::try_to_lexical(self, bytes)
+            }
         }
 
         impl ToLexicalWithOptions for $t {
@@ -923,6 +953,15 @@ macro_rules! to_lexical_impl {
             ) -> &'a mut [u8] {
                 <Self as $to_options>::to_lexical_with_options::<FORMAT>(self, bytes, options)
             }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> Result<&'a mut [u8]> {
+                <Self as $to_options>::try_to_lexical_with_options::<FORMAT>(self, bytes, options)
+            }
         }
     };
 }
@@ -949,6 +988,46 @@ macro_rules! float_to_lexical {
 #[cfg(feature = "write-floats")]
 float_to_lexical! { f32 f64 }
 
+/// Call a function with the standard format for a radix only known at
+/// runtime as the `FORMAT`.
+///
+/// Every radix enabled by the features is matched and monomorphized once,
+/// so without `radix` or `power-of-two`, only the decimal format is
+/// compiled. Any other radix returns [`Error::InvalidMantissaRadix`].
+///
+/// * `radix`   - The radix known at runtime.
+/// * `const`   - The name of the const to use in the call.
+/// * `call`    - The expression to call with the const format.
+#[cfg(any(
+    feature = "parse-floats",
+    feature = "parse-integers",
+    feature = "write-floats",
+    feature = "write-integers"
+))]
+macro_rules! with_radix {
+    ($radix:ident, $const:ident => $call:expr) => {{
+        if $radix == 10 {
+            const $const: u128 = format::STANDARD;
+            return Ok($call);
+        }
+        #[cfg(feature = "power-of-two")]
+        with_radix!(@radix $radix, $const => $call; 2 4 8 16 32);
+        #[cfg(feature = "radix")]
+        with_radix!(
+            @radix $radix, $const => $call;
+            3 5 6 7 9 11 12 13 14 15 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 33 34 35 36
+        );
+        Err(Error::InvalidMantissaRadix)
+    }};
+
+    (@radix $radix:ident, $const:ident => $call:expr; $($value:literal)*) => {$({
+        const $const: u128 = NumberFormatBuilder::from_radix($value);
+        if $radix == $value {
+            return Ok($call);
+        }
+    })*};
+}
+
 /// Write number to string.
 ///
 /// Returns a subslice of the input buffer containing the written bytes,
@@ -961,7 +1040,8 @@ float_to_lexical! { f32 f64 }
 ///
 /// Panics if the buffer may not be large enough to hold the serialized
 /// number. In order to ensure the function will not panic, provide a
-/// buffer with at least `{integer}::FORMATTED_SIZE` elements.
+/// buffer with at least `{integer}::FORMATTED_SIZE` elements, or use
+/// [`try_write`] to return an error instead.
 ///
 /// # Examples
 ///
@@ -1022,6 +1102,7 @@ pub fn write<N: ToLexical>(n: N, bytes: &mut [u8]) -> &mut [u8] {
 /// If the provided `FORMAT` is not valid, the function may panic. Please
 /// ensure `is_valid()` is called prior to using the format, or checking
 /// its validity using a static assertion.
+/// Use [`try_write_with_options`] to return an error instead.
 ///
 /// # Examples
 ///
@@ -1067,6 +1148,276 @@ pub fn write_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
     n.to_lexical_with_options::<FORMAT>(bytes, options)
 }
 
+/// Write number to string, returning an error if the buffer is too small.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. This is
+/// identical to [`write`], except it never panics, so any buffer may be
+/// provided.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Errors
+///
+/// Returns [`Error::BufferTooSmall`] with the number of bytes required
+/// if the number does not fit in the buffer.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-floats")] {
+/// use lexical_core::Error;
+///
+/// let mut buffer = [0u8; 4];
+/// let digits = lexical_core::try_write(1.5_f64, &mut buffer);
+/// assert_eq!(digits.map(|x| &*x), Ok(&b"1.5"[..]));
+///
+/// let digits = lexical_core::try_write(3.14159265359_f32, &mut buffer);
+/// assert_eq!(digits, Err(Error::BufferTooSmall(9)));
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn try_write<N: ToLexical>(n: N, bytes: &mut [u8]) -> Result<&mut [u8]> {
+    n.try_to_lexical(bytes)
+}
+
+/// Write number to string with custom options, returning an error if it
+/// cannot be written.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. This is
+/// identical to [`write_with_options`], except it never panics, so any
+/// buffer, format, and options may be provided.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number parsing.
+///
+/// # Errors
+///
+/// Returns [`Error::BufferTooSmall`] with the exact number of bytes
+/// required if the number does not fit in the buffer, so a buffer of that
+/// length always succeeds. Also returns an error if the format or options
+/// are invalid, or if a special float is disabled by the format or options.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-integers")] {
+/// use lexical_core::{Error, Padding, WriteIntegerOptions};
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// const OPTIONS: WriteIntegerOptions = WriteIntegerOptions::builder()
+///     .min_width(6)
+///     .padding(Padding::Zero)
+///     .build_strict();
+///
+/// let mut buffer = [0u8; 8];
+/// let digits = lexical_core::try_write_with_options::<_, FORMAT>(255_u32, &mut buffer, &OPTIONS);
+/// assert_eq!(digits.map(|x| &*x), Ok(&b"000255"[..]));
+///
+/// let mut buffer = [0u8; 4];
+/// let digits = lexical_core::try_write_with_options::<_, FORMAT>(255_u32, &mut buffer, &OPTIONS);
+/// assert_eq!(digits, Err(Error::BufferTooSmall(6)));
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn try_write_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [u8],
+    options: &N::Options,
+) -> Result<&'a mut [u8]> {
+    n.try_to_lexical_with_options::<FORMAT>(bytes, options)
+}
+
+/// Write a number to an uninitialized buffer using `write`.
+///
+/// The writers require initialized bytes, so the number is first written
+/// to a buffer on the stack, and exactly the written bytes are copied to
+/// the uninitialized buffer. Only if the number is larger than
+/// [`BUFFER_SIZE`], such as with a large minimum width, is it written
+/// directly to the uninitialized buffer, after initializing exactly the
+/// number of bytes required.
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_uninit_impl<Cb>(bytes: &mut [MaybeUninit<u8>], mut write: Cb) -> Result<&mut [u8]>
+where
+    Cb: FnMut(&mut [u8]) -> Result<usize>,
+{
+    let mut buffer = [0u8; BUFFER_SIZE];
+    match write(&mut buffer) {
+        Ok(count) => {
+            let digits = bytes.get_mut(..count).ok_or(Error::BufferTooSmall(count))?;
+            for (dst, &src) in digits.iter_mut().zip(buffer.iter()) {
+                *dst = MaybeUninit::new(src);
+            }
+            // SAFETY: safe since every element of `digits` was initialized
+            // above, and `MaybeUninit<u8>` has the same layout as `u8`.
+            Ok(unsafe { &mut *(digits as *mut [MaybeUninit<u8>] as *mut [u8]) })
+        },
+        Err(Error::BufferTooSmall(count)) => {
+            let digits = bytes.get_mut(..count).ok_or(Error::BufferTooSmall(count))?;
+            digits.fill(MaybeUninit::new(0));
+            // SAFETY: safe since every element of `digits` was initialized
+            // above, and `MaybeUninit<u8>` has the same layout as `u8`.
+            let digits = unsafe { &mut *(digits as *mut [MaybeUninit<u8>] as *mut [u8]) };
+            let count = write(digits)?;
+            Ok(&mut digits[..count])
+        },
+        Err(error) => Err(error),
+    }
+}
+
+/// Write number to an uninitialized buffer, returning an error if it
+/// cannot be written.
+///
+/// Returns the initialized prefix of the input buffer containing the
+/// written bytes, starting from the same address in memory as the input
+/// slice. This is identical to [`try_write`], except the buffer does not
+/// need to be initialized beforehand: only the written bytes are
+/// initialized.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Errors
+///
+/// Returns [`Error::BufferTooSmall`] with the exact number of bytes
+/// required if the number does not fit in the buffer, so a buffer of that
+/// length always succeeds. A buffer with at least
+/// `{integer}::FORMATTED_SIZE_DECIMAL` elements never returns an error.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-floats")] {
+/// use core::mem::MaybeUninit;
+///
+/// use lexical_core::{Error, BUFFER_SIZE};
+///
+/// let mut buffer = [MaybeUninit::<u8>::uninit(); BUFFER_SIZE];
+/// let digits = lexical_core::write_uninit(3.14159265359_f32, &mut buffer);
+/// assert_eq!(digits.map(|x| &*x), Ok(&b"3.1415927"[..]));
+///
+/// let mut buffer = [MaybeUninit::<u8>::uninit(); 4];
+/// let digits = lexical_core::write_uninit(3.14159265359_f32, &mut buffer);
+/// assert_eq!(digits, Err(Error::BufferTooSmall(9)));
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn write_uninit<N: ToLexical>(n: N, bytes: &mut [MaybeUninit<u8>]) -> Result<&mut [u8]> {
+    write_uninit_impl(bytes, |buffer| n.try_to_lexical(buffer).map(|digits| digits.len()))
+}
+
+/// Write number to an uninitialized buffer with custom options, returning
+/// an error if it cannot be written.
+///
+/// Returns the initialized prefix of the input buffer containing the
+/// written bytes, starting from the same address in memory as the input
+/// slice. This is identical to [`try_write_with_options`], except the
+/// buffer does not need to be initialized beforehand: only the written
+/// bytes are initialized.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number parsing.
+///
+/// # Errors
+///
+/// Returns [`Error::BufferTooSmall`] with the exact number of bytes
+/// required if the number does not fit in the buffer, so a buffer of that
+/// length always succeeds. Also returns an error if the format or options
+/// are invalid, or if a special float is disabled by the format or options.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "write-floats")] {
+/// use core::mem::MaybeUninit;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// const OPTIONS: lexical_core::WriteFloatOptions = lexical_core::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build_strict();
+/// const SIZE: usize = OPTIONS.buffer_size_const::<f64, FORMAT>();
+///
+/// let mut buffer = [MaybeUninit::<u8>::uninit(); SIZE];
+/// let digits = lexical_core::write_uninit_with_options::<_, FORMAT>(1.0f64, &mut buffer, &OPTIONS);
+/// assert_eq!(digits.map(|x| &*x), Ok(&b"1"[..]));
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn write_uninit_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [MaybeUninit<u8>],
+    options: &N::Options,
+) -> Result<&'a mut [u8]> {
+    write_uninit_impl(bytes, |buffer| {
+        n.try_to_lexical_with_options::<FORMAT>(buffer, options).map(|digits| digits.len())
+    })
+}
+
+/// Write number to string with the standard format for a radix selected
+/// at runtime.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. This is
+/// identical to [`write_with_options`] with the format
+/// [`NumberFormatBuilder::from_radix`], except the radix is selected at
+/// runtime, such as from a configuration file.
+///
+/// Only the radix is selected at runtime: custom formats, such as with
+/// digit separators or base prefixes, require the const generic API.
+/// Every radix enabled by the features is compiled once for each number
+/// type this is called with, so with the `radix` feature, this compiles
+/// 35 formats.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `radix`   - The radix of the mantissa and exponent.
+/// * `options` - Options to customize number writing.
+///
+/// # Errors
+///
+/// If the radix is not enabled by the features, returns
+/// [`Error::InvalidMantissaRadix`].
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number, with the same requirements as [`write_with_options`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "write-integers", feature = "power-of-two"))] {
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let radix = 16;
+/// const OPTIONS: lexical_core::WriteIntegerOptions = lexical_core::WriteIntegerOptions::new();
+/// let digits = lexical_core::write_with_radix(255_u32, &mut buffer, radix, &OPTIONS);
+/// assert_eq!(digits.map(|x| &*x), Ok(&b"FF"[..]));
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn write_with_radix<'a, N: ToLexicalWithOptions>(
+    n: N,
+    bytes: &'a mut [u8],
+    radix: u8,
+    options: &N::Options,
+) -> Result<&'a mut [u8]> {
+    with_radix!(radix, FORMAT => n.to_lexical_with_options::<FORMAT>(bytes, options))
+}
+
 /// Parse complete number from string.
 ///
 /// This method parses the entire string, returning an error if
@@ -1170,3 +1521,209 @@ pub fn parse_partial_with_options<N: FromLexicalWithOptions, const FORMAT: u128>
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options::<FORMAT>(bytes, options)
 }
+
+/// Parse complete number from string with the standard format for a radix
+/// selected at runtime.
+///
+/// This is identical to [`parse_with_options`] with the format
+/// [`NumberFormatBuilder::from_radix`], except the radix is selected at
+/// runtime, such as for each column of a CSV file.
+///
+/// Only the radix is selected at runtime: custom formats, such as with
+/// digit separators or base prefixes, require the const generic API.
+/// Every radix enabled by the features is compiled once for each number
+/// type this is called with, so with the `radix` feature, this compiles
+/// 35 formats.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - The radix of the mantissa and exponent.
+/// * `options` - Options to customize number parsing.
+///
+/// # Errors
+///
+/// If the radix is not enabled by the features, returns
+/// [`Error::InvalidMantissaRadix`], or if any invalid digits are found
+/// during parsing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "parse-integers", feature = "power-of-two"))] {
+/// use lexical_core::Error;
+///
+/// const OPTIONS: lexical_core::ParseIntegerOptions = lexical_core::ParseIntegerOptions::new();
+/// for (radix, expected) in [(2, 5), (8, 65), (10, 101), (16, 257)] {
+///     let result = lexical_core::parse_with_radix::<u32>(b"101", radix, &OPTIONS);
+///     assert_eq!(result, Ok(expected));
+/// }
+///
+/// let result = lexical_core::parse_with_radix::<u32>(b"101", 1, &OPTIONS);
+/// assert_eq!(result, Err(Error::InvalidMantissaRadix));
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub fn parse_with_radix<N: FromLexicalWithOptions>(
+    bytes: &[u8],
+    radix: u8,
+    options: &N::Options,
+) -> Result<N> {
+    with_radix!(radix, FORMAT => N::from_lexical_with_options::<FORMAT>(bytes, options)?)
+}
+
+/// Parse partial number from string with the standard format for a radix
+/// selected at runtime.
+///
+/// This is identical to [`parse_partial_with_options`] with the format
+/// [`NumberFormatBuilder::from_radix`], except the radix is selected at
+/// runtime. Like [`parse_with_radix`], only the radix is selected at
+/// runtime.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - The radix of the mantissa and exponent.
+/// * `options` - Options to customize number parsing.
+///
+/// # Errors
+///
+/// If the radix is not enabled by the features, returns
+/// [`Error::InvalidMantissaRadix`], or if the number is invalid.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// const OPTIONS: lexical_core::ParseFloatOptions = lexical_core::ParseFloatOptions::new();
+/// let result = lexical_core::parse_partial_with_radix::<f64>(b"1.5 hello", 10, &OPTIONS);
+/// assert_eq!(result, Ok((1.5, 3)));
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub fn parse_partial_with_radix<N: FromLexicalWithOptions>(
+    bytes: &[u8],
+    radix: u8,
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    with_radix!(radix, FORMAT => N::from_lexical_partial_with_options::<FORMAT>(bytes, options)?)
+}
+
+/// Parse complete float from string, reporting if the float is exact.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The [`Exactness`]
+/// reports if the float is exactly equal to the digits, or if it was
+/// rounded down or up.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Exactness;
+///
+/// let result = lexical_core::parse_exact::<f64>(b"2.5");
+/// assert_eq!(result, Ok((2.5, Exactness::Exact)));
+///
+/// let result = lexical_core::parse_exact::<f64>(b"0.1");
+/// assert_eq!(result, Ok((0.1, Exactness::RoundedUp)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_exact<N: FromLexicalExact>(bytes: &[u8]) -> Result<(N, Exactness)> {
+    N::from_lexical_exact(bytes)
+}
+
+/// Parse partial float from string, reporting if the float is exact.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Exactness;
+///
+/// let result = lexical_core::parse_partial_exact::<f32>(b"0.3 hello");
+/// assert_eq!(result, Ok((0.3, Exactness::RoundedUp, 3)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_partial_exact<N: FromLexicalExact>(bytes: &[u8]) -> Result<(N, Exactness, usize)> {
+    N::from_lexical_partial_exact(bytes)
+}
+
+/// Parse complete float from string with custom parsing options,
+/// reporting if the float is exact.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// use lexical_core::{format, Exactness, ParseFloatOptions, ParseRoundMode};
+///
+/// const OPTIONS: ParseFloatOptions = ParseFloatOptions::builder()
+///     .round_mode(ParseRoundMode::TowardZero)
+///     .build_strict();
+/// let result = lexical_core::parse_exact_with_options::<f64, { format::STANDARD }>(
+///     b"0.1",
+///     &OPTIONS,
+/// );
+/// assert_eq!(result, Ok((0.09999999999999999, Exactness::RoundedDown)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_exact_with_options<N: FromLexicalExact, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<(N, Exactness)> {
+    N::from_lexical_exact_with_options::<FORMAT>(bytes, options)
+}
+
+/// Parse partial float from string with custom parsing options,
+/// reporting if the float is exact.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// use lexical_core::{format, Exactness, ParseFloatOptions};
+///
+/// const OPTIONS: ParseFloatOptions = ParseFloatOptions::new();
+/// let result = lexical_core::parse_partial_exact_with_options::<f64, { format::STANDARD }>(
+///     b"0.5 hello",
+///     &OPTIONS,
+/// );
+/// assert_eq!(result, Ok((0.5, Exactness::Exact, 3)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_partial_exact_with_options<N: FromLexicalExact, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<(N, Exactness, usize)> {
+    N::from_lexical_partial_exact_with_options::<FORMAT>(bytes, options)
+}
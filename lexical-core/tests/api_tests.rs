@@ -24,6 +24,162 @@ fn float_to_string_test() {
     );
 }
 
+#[test]
+#[cfg(all(feature = "write-integers", feature = "write-floats"))]
+fn try_write_test() {
+    use lexical_core::Error;
+
+    let mut buffer = [b'0'; 6];
+    assert_eq!(lexical_core::try_write(12345u32, &mut buffer).map(|x| &*x), Ok(&b"12345"[..]));
+    assert_eq!(lexical_core::try_write(1234567u32, &mut buffer), Err(Error::BufferTooSmall(7)));
+    assert_eq!(lexical_core::try_write(1.5f64, &mut buffer).map(|x| &*x), Ok(&b"1.5"[..]));
+    assert_eq!(lexical_core::try_write(0.1f64 + 0.2, &mut buffer), Err(Error::BufferTooSmall(19)));
+
+    const OPTIONS: lexical_write_float::Options =
+        lexical_core::WriteFloatOptions::builder().inf_string(None).build_strict();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(f32::INFINITY, &mut buffer, &OPTIONS),
+        Err(Error::UnsupportedSpecial)
+    );
+
+    const PLACES: lexical_write_float::Options =
+        lexical_core::WriteFloatOptions::builder().decimal_places(Some(2)).build_strict();
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(1.5f64, &mut buffer, &PLACES)
+            .map(|x| &*x),
+        Ok(&b"1.50"[..])
+    );
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "write-floats"))]
+fn write_uninit_test() {
+    use core::mem::MaybeUninit;
+
+    use lexical_core::Error;
+
+    let mut buffer = [MaybeUninit::<u8>::uninit(); lexical_core::BUFFER_SIZE];
+    let digits = lexical_core::write_uninit(12345u32, &mut buffer);
+    assert_eq!(digits.map(|x| &*x), Ok(&b"12345"[..]));
+    let digits = lexical_core::write_uninit(12345.0f32, &mut buffer);
+    assert_eq!(digits.map(|x| &*x), Ok(&b"12345.0"[..]));
+    let digits = lexical_core::write_uninit(12345u32, &mut buffer[..4]);
+    assert_eq!(digits, Err(Error::BufferTooSmall(5)));
+
+    const OPTIONS: lexical_write_integer::Options =
+        lexical_core::WriteIntegerOptions::builder().min_width(300).build_strict();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut buffer = [MaybeUninit::<u8>::uninit(); 300];
+    let digits = lexical_core::write_uninit_with_options::<_, FORMAT>(1u8, &mut buffer, &OPTIONS);
+    let digits = digits.unwrap();
+    assert_eq!(digits.len(), 300);
+    assert_eq!(digits.last(), Some(&b'1'));
+    let digits =
+        lexical_core::write_uninit_with_options::<_, FORMAT>(1u8, &mut buffer[..299], &OPTIONS);
+    assert_eq!(digits, Err(Error::BufferTooSmall(300)));
+
+    const INVALID: lexical_write_float::Options =
+        lexical_core::WriteFloatOptions::builder().decimal_point(0xFF).build_unchecked();
+    let digits =
+        lexical_core::write_uninit_with_options::<_, FORMAT>(1.5f64, &mut buffer, &INVALID);
+    assert_eq!(digits, Err(Error::InvalidDecimalPoint));
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "write-floats"))]
+fn number_buffer_test() {
+    use core::fmt::Write;
+
+    use lexical_core::{FormattedSize, NumberBuffer};
+
+    let mut buffer = NumberBuffer::new();
+    assert_eq!(&*buffer, "");
+    assert_eq!(buffer.format(u128::MAX), "340282366920938463463374607431768211455");
+    assert_eq!(buffer.format(-1.5f32), "-1.5");
+    assert_eq!(buffer.as_ref(), "-1.5");
+    assert_eq!(buffer.as_bytes(), b"-1.5");
+
+    let mut string = String::new();
+    write!(string, "[{buffer:>6}]").unwrap();
+    assert_eq!(string, "[  -1.5]");
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    const OPTIONS: lexical_core::WriteIntegerOptions =
+        lexical_core::WriteIntegerOptions::builder().plus_sign(true).build_strict();
+    const SIZE: usize = OPTIONS.buffer_size_const::<u8, FORMAT>();
+    let mut buffer = NumberBuffer::<SIZE>::new_sized();
+    assert_eq!(buffer.format_with_options::<_, FORMAT>(255u8, &OPTIONS), Ok("+255"));
+    assert_eq!(buffer.format(u8::MAX), "255");
+
+    const INVALID: lexical_core::WriteFloatOptions =
+        lexical_core::WriteFloatOptions::builder().decimal_point(0xFF).build_unchecked();
+    let mut buffer = NumberBuffer::new();
+    assert_eq!(buffer.format(1.5f64), "1.5");
+    let result = buffer.format_with_options::<_, FORMAT>(1.5f64, &INVALID);
+    assert_eq!(result, Err(lexical_core::Error::InvalidDecimalPoint));
+    assert_eq!(&*buffer, "");
+
+    let mut buffer = NumberBuffer::<{ i64::FORMATTED_SIZE_DECIMAL }>::default();
+    assert_eq!(buffer.format(i64::MIN), "-9223372036854775808");
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "write-floats"))]
+fn display_test() {
+    use core::fmt::Write;
+
+    use lexical_core::{NumberDisplay, WriteFloatOptions, WriteIntegerOptions};
+
+    const FLOAT: WriteFloatOptions = WriteFloatOptions::new();
+    const INTEGER: WriteIntegerOptions = WriteIntegerOptions::new();
+    let mut string = String::new();
+    write!(string, "{}|", lexical_core::display(1.5f64, &FLOAT)).unwrap();
+    write!(string, "{:5}|", lexical_core::display(12u8, &INTEGER)).unwrap();
+    write!(string, "{:<5}|", lexical_core::display(-12i32, &INTEGER)).unwrap();
+    write!(string, "{:_^6}|", lexical_core::display(-12i32, &INTEGER)).unwrap();
+    write!(string, "{:+}|", lexical_core::display(12u8, &INTEGER)).unwrap();
+    write!(string, "{:+06}|", lexical_core::display(12u8, &INTEGER)).unwrap();
+    write!(string, "{:06}|", lexical_core::display(-1.5f32, &FLOAT)).unwrap();
+    write!(string, "{:+}|", lexical_core::display(f64::NAN, &FLOAT)).unwrap();
+    write!(string, "{:+}|", lexical_core::display(f64::INFINITY, &FLOAT)).unwrap();
+    write!(string, "{:2}", lexical_core::display(12345u32, &INTEGER)).unwrap();
+    assert_eq!(string, "1.5|   12|-12  |_-12__|+12|+00012|-001.5|NaN|+inf|12345");
+
+    // The options may write their own sign.
+    const PLUS: WriteIntegerOptions = WriteIntegerOptions::builder().plus_sign(true).build_strict();
+    string.clear();
+    write!(string, "{:+05}", lexical_core::display(7i8, &PLUS)).unwrap();
+    assert_eq!(string, "+0007");
+
+    // Numbers larger than the stack buffer are still written.
+    const FIXED: WriteFloatOptions =
+        WriteFloatOptions::builder().decimal_places(Some(400)).build_strict();
+    string.clear();
+    let value = NumberDisplay::<_, { lexical_core::format::STANDARD }>::new(0.5f64, &FIXED);
+    write!(string, "{:>410}", value).unwrap();
+    assert_eq!(string.len(), 410);
+    assert!(string.starts_with("        0.5000"));
+
+    // The width counts characters, not bytes.
+    const SI: WriteFloatOptions =
+        WriteFloatOptions::builder().notation(lexical_core::Notation::SiPrefix).build_strict();
+    string.clear();
+    write!(
+        string,
+        "{:>6}|{:06}",
+        lexical_core::display(1.5e-6f64, &SI),
+        lexical_core::display(-1.5e-6f64, &SI)
+    )
+    .unwrap();
+    assert_eq!(string, "  1.5µ|-01.5µ");
+
+    // Values that cannot be written return an error.
+    const NO_NAN: WriteFloatOptions = WriteFloatOptions::builder().nan_string(None).build_strict();
+    string.clear();
+    assert!(write!(string, "{}", lexical_core::display(f64::NAN, &NO_NAN)).is_err());
+}
+
 #[test]
 #[cfg(feature = "parse-integers")]
 fn string_to_integer_test() {
@@ -54,6 +210,345 @@ fn string_to_float_test() {
     );
 }
 
+#[test]
+#[cfg(feature = "parse-floats")]
+fn string_to_float_exact_test() {
+    use lexical_core::Exactness;
+
+    assert_eq!(lexical_core::parse_exact(b"0.5"), Ok((0.5f64, Exactness::Exact)));
+    assert_eq!(lexical_core::parse_exact(b"0.1"), Ok((0.1f64, Exactness::RoundedUp)));
+    assert_eq!(lexical_core::parse_partial_exact(b"0.1,"), Ok((0.1f32, Exactness::RoundedUp, 3)));
+
+    const OPTIONS: lexical_core::ParseFloatOptions =
+        lexical_core::ParseFloatOptions::builder().require_exact(true).build_strict();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    assert_eq!(
+        lexical_core::parse_exact_with_options::<f64, FORMAT>(b"-0.5", &OPTIONS),
+        Ok((-0.5, Exactness::Exact))
+    );
+    assert_eq!(
+        lexical_core::parse_partial_exact_with_options::<f64, FORMAT>(b"0.3", &OPTIONS),
+        Err(lexical_core::Error::InexactFloat(3))
+    );
+    assert_eq!(
+        lexical_core::parse_with_options::<f64, FORMAT>(b"0.3", &OPTIONS),
+        Err(lexical_core::Error::InexactFloat(3))
+    );
+}
+
+#[cfg(any(feature = "parse-integers", feature = "write-integers"))]
+fn is_enabled_radix(radix: u8) -> bool {
+    match radix {
+        10 => true,
+        2 | 4 | 8 | 16 | 32 => cfg!(feature = "power-of-two"),
+        _ => cfg!(feature = "radix") && (2..=36).contains(&radix),
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn string_to_integer_radix_test() {
+    use lexical_core::Error;
+
+    const OPTIONS: lexical_core::ParseIntegerOptions = lexical_core::ParseIntegerOptions::new();
+    assert_eq!(lexical_core::parse_with_radix::<u32>(b"1234", 10, &OPTIONS), Ok(1234));
+    assert_eq!(lexical_core::parse_with_radix::<i8>(b"-12", 10, &OPTIONS), Ok(-12));
+    assert_eq!(lexical_core::parse_partial_with_radix::<u64>(b"12a", 10, &OPTIONS), Ok((12, 2)));
+    assert_eq!(lexical_core::parse_with_radix::<u8>(b"256", 10, &OPTIONS), Err(Error::Overflow(2)));
+    assert_eq!(
+        lexical_core::parse_with_radix::<u32>(b"1", 0, &OPTIONS),
+        Err(Error::InvalidMantissaRadix)
+    );
+    assert_eq!(
+        lexical_core::parse_with_radix::<u32>(b"1", 37, &OPTIONS),
+        Err(Error::InvalidMantissaRadix)
+    );
+
+    for radix in 2..=36 {
+        let result = lexical_core::parse_with_radix::<u32>(b"11", radix, &OPTIONS);
+        if is_enabled_radix(radix) {
+            assert_eq!(result, Ok(radix as u32 + 1));
+        } else {
+            assert_eq!(result, Err(Error::InvalidMantissaRadix));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn string_to_float_radix_test() {
+    const OPTIONS: lexical_core::ParseFloatOptions = lexical_core::ParseFloatOptions::new();
+    assert_eq!(lexical_core::parse_with_radix::<f64>(b"1.5e3", 10, &OPTIONS), Ok(1500.0));
+    assert_eq!(lexical_core::parse_partial_with_radix::<f32>(b"0.1,", 10, &OPTIONS), Ok((0.1, 3)));
+
+    #[cfg(feature = "power-of-two")]
+    {
+        const BINARY: u128 = lexical_core::NumberFormatBuilder::from_radix(2);
+        const BINARY_OPTIONS: lexical_core::ParseFloatOptions =
+            lexical_core::ParseFloatOptions::from_radix(2);
+        assert_eq!(
+            lexical_core::parse_with_radix::<f64>(b"-101.1", 2, &BINARY_OPTIONS),
+            lexical_core::parse_with_options::<f64, BINARY>(b"-101.1", &BINARY_OPTIONS),
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_to_string_radix_test() {
+    use lexical_core::Error;
+
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    const OPTIONS: lexical_core::WriteIntegerOptions = lexical_core::WriteIntegerOptions::new();
+    let result = lexical_core::write_with_radix(-1234i32, &mut buffer, 10, &OPTIONS);
+    assert_eq!(result.map(|x| &*x), Ok(&b"-1234"[..]));
+    let result = lexical_core::write_with_radix(1234u32, &mut buffer, 1, &OPTIONS);
+    assert_eq!(result.map(|x| &*x), Err(Error::InvalidMantissaRadix));
+
+    #[cfg(feature = "power-of-two")]
+    {
+        let result = lexical_core::write_with_radix(0xABCu32, &mut buffer, 16, &OPTIONS);
+        assert_eq!(result.map(|x| &*x), Ok(&b"ABC"[..]));
+    }
+
+    for radix in 2..=36 {
+        let result = lexical_core::write_with_radix(radix as u32 + 1, &mut buffer, radix, &OPTIONS);
+        if is_enabled_radix(radix) {
+            assert_eq!(result.map(|x| &*x), Ok(&b"11"[..]));
+        } else {
+            assert_eq!(result.map(|x| &*x), Err(Error::InvalidMantissaRadix));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_to_string_radix_test() {
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    const OPTIONS: lexical_core::WriteFloatOptions = lexical_core::WriteFloatOptions::new();
+    let result = lexical_core::write_with_radix(1.5f64, &mut buffer, 10, &OPTIONS);
+    assert_eq!(result.map(|x| &*x), Ok(&b"1.5"[..]));
+
+    #[cfg(feature = "power-of-two")]
+    {
+        const OPTIONS: lexical_core::WriteFloatOptions =
+            lexical_core::WriteFloatOptions::from_radix(2);
+        let result = lexical_core::write_with_radix(-2.5f64, &mut buffer, 2, &OPTIONS);
+        assert_eq!(result.map(|x| &*x), Ok(&b"-10.1"[..]));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "format", feature = "power-of-two"))]
+fn display_base_prefix_test() {
+    use core::fmt::Write;
+    use core::num;
+
+    use lexical_core::NumberDisplay;
+
+    const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .build_strict();
+    const OPTIONS: lexical_core::WriteIntegerOptions = lexical_core::WriteIntegerOptions::new();
+
+    let mut string = String::new();
+    write!(string, "{:010}|", NumberDisplay::<_, FORMAT>::new(255u8, &OPTIONS)).unwrap();
+    write!(string, "{:+08}|", NumberDisplay::<_, FORMAT>::new(-255i32, &OPTIONS)).unwrap();
+    write!(string, "{:>6}", NumberDisplay::<_, FORMAT>::new(255u8, &OPTIONS)).unwrap();
+    assert_eq!(string, "0x000000FF|-0x000FF|  0xFF");
+}
+
+#[test]
+#[cfg(all(
+    feature = "write-integers",
+    feature = "parse-integers",
+    feature = "format",
+    feature = "power-of-two"
+))]
+fn integer_base_prefix_roundtrip_test() {
+    use core::num;
+
+    const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .build_strict();
+    const WRITE: lexical_core::WriteIntegerOptions =
+        lexical_core::WriteIntegerOptions::builder().lowercase_digits(true).build_strict();
+    const PARSE: lexical_core::ParseIntegerOptions = lexical_core::ParseIntegerOptions::new();
+
+    let mut buffer = [b'0'; WRITE.buffer_size_const::<i32, FORMAT>()];
+    let digits = lexical_core::write_with_options::<_, FORMAT>(-0x1Fi32, &mut buffer, &WRITE);
+    assert_eq!(digits, b"-0x1f");
+    assert_eq!(lexical_core::parse_with_options::<i32, FORMAT>(digits, &PARSE), Ok(-0x1F));
+}
+
+#[test]
+#[cfg(all(
+    feature = "write-floats",
+    feature = "parse-floats",
+    feature = "format",
+    feature = "power-of-two"
+))]
+fn float_base_prefix_roundtrip_test() {
+    use core::num;
+
+    const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .build_strict();
+    const WRITE: lexical_core::WriteFloatOptions = lexical_core::WriteFloatOptions::builder()
+        .exponent(b'p')
+        .lowercase_digits(true)
+        .build_strict();
+    const PARSE: lexical_core::ParseFloatOptions =
+        lexical_core::ParseFloatOptions::builder().exponent(b'p').build_strict();
+
+    let mut buffer = [b'0'; WRITE.buffer_size_const::<f64, FORMAT>()];
+    let digits = lexical_core::write_with_options::<_, FORMAT>(255.5f64, &mut buffer, &WRITE);
+    assert_eq!(digits, b"0xff.8");
+    assert_eq!(lexical_core::parse_with_options::<f64, FORMAT>(digits, &PARSE), Ok(255.5));
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats"))]
+fn float_si_prefix_roundtrip_test() {
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    const WRITE: lexical_core::WriteFloatOptions = lexical_core::WriteFloatOptions::builder()
+        .notation(lexical_core::Notation::SiPrefix)
+        .build_strict();
+    const PARSE: lexical_core::ParseFloatOptions =
+        lexical_core::ParseFloatOptions::builder().si_prefix(true).build_strict();
+
+    let mut buffer = [b'0'; WRITE.buffer_size_const::<f64, FORMAT>()];
+    let digits = lexical_core::write_with_options::<_, FORMAT>(12.5e3f64, &mut buffer, &WRITE);
+    assert_eq!(digits, b"12.5k");
+    for &x in &[1.0, 12.5e3, 3.3e-6, 47e-9, -0.1, 1e-30, 1e30, 6.02214076e23, 1e-300, 1e300] {
+        let digits = lexical_core::write_with_options::<f64, FORMAT>(x, &mut buffer, &WRITE);
+        assert_eq!(lexical_core::parse_with_options::<f64, FORMAT>(digits, &PARSE), Ok(x));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "write-floats", feature = "parse-floats"))]
+fn float_format_roundtrip_test() {
+    use core::num;
+
+    use lexical_core::format::{
+        C_PRINTF_G17,
+        GO_FORMAT_FLOAT,
+        JAVASCRIPT_TO_STRING,
+        JAVA_TO_STRING,
+        JSON,
+        PYTHON_REPR,
+    };
+    use lexical_core::{write_float_options, Notation, NumberFormatBuilder, WriteFloatOptions};
+
+    const SIGNED: u128 = NumberFormatBuilder::new()
+        .required_digits(true)
+        .required_mantissa_sign(true)
+        .required_exponent_sign(true)
+        .build_strict();
+    const UNSIGNED: u128 = NumberFormatBuilder::new()
+        .required_digits(true)
+        .no_positive_mantissa_sign(true)
+        .no_positive_exponent_sign(true)
+        .no_exponent_without_fraction(true)
+        .no_float_leading_zeros(true)
+        .build_strict();
+    const STANDARD: WriteFloatOptions = WriteFloatOptions::new();
+    const TRIM: WriteFloatOptions = WriteFloatOptions::builder()
+        .trim_floats(true)
+        .exponent_plus_sign(true)
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .build_strict();
+    const ENGINEERING: WriteFloatOptions = WriteFloatOptions::builder()
+        .trim_floats(true)
+        .notation(Notation::Engineering)
+        .build_strict();
+    const VALUES: [f64; 11] = [
+        0.0,
+        -0.0,
+        0.5,
+        1.0,
+        -1.5e3,
+        1.5e-3,
+        123456.789,
+        1e21,
+        1e100,
+        5e-324,
+        1.7976931348623157e308,
+    ];
+
+    macro_rules! roundtrip {
+        ($format:ident, $($options:expr),*) => {{
+            const FORMAT: u128 = $format;
+            $(
+                let mut buffer = [0u8; 512];
+                for &x in VALUES.iter() {
+                    let digits =
+                        lexical_core::write_with_options::<_, FORMAT>(x, &mut buffer, &$options);
+                    let parsed = lexical_core::parse_with_options::<f64, FORMAT>(
+                        digits,
+                        &lexical_core::ParseFloatOptions::new(),
+                    );
+                    assert_eq!(parsed, Ok(x), "{}", core::str::from_utf8(digits).unwrap());
+                }
+            )*
+        }};
+    }
+
+    roundtrip!(SIGNED, STANDARD, TRIM, ENGINEERING);
+    roundtrip!(UNSIGNED, STANDARD, TRIM, ENGINEERING);
+    roundtrip!(JSON, STANDARD, TRIM, ENGINEERING);
+    roundtrip!(JAVASCRIPT_TO_STRING, write_float_options::JAVASCRIPT_TO_STRING);
+    roundtrip!(PYTHON_REPR, write_float_options::PYTHON_REPR);
+    roundtrip!(GO_FORMAT_FLOAT, write_float_options::GO_FORMAT_FLOAT);
+    roundtrip!(JAVA_TO_STRING, write_float_options::JAVA_TO_STRING);
+    roundtrip!(C_PRINTF_G17, write_float_options::C_PRINTF_G17);
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "write-integers", feature = "parse-integers"))]
+fn integer_format_roundtrip_test() {
+    use lexical_core::{NumberFormatBuilder, Padding, WriteIntegerOptions};
+
+    const SIGNED: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build_strict();
+    const UNSIGNED: u128 = NumberFormatBuilder::new()
+        .no_positive_mantissa_sign(true)
+        .no_integer_leading_zeros(true)
+        .build_strict();
+    const PLUS: WriteIntegerOptions = WriteIntegerOptions::builder().plus_sign(true).build_strict();
+    const PADDED: WriteIntegerOptions =
+        WriteIntegerOptions::builder().min_width(4).padding(Padding::Zero).build_strict();
+
+    macro_rules! roundtrip {
+        ($format:ident, $($options:ident),*) => {{
+            const FORMAT: u128 = $format;
+            $(
+                let mut buffer = [0u8; 64];
+                for &x in [0i32, 1, -1, 100, i32::MAX, i32::MIN].iter() {
+                    let digits =
+                        lexical_core::write_with_options::<_, FORMAT>(x, &mut buffer, &$options);
+                    let parsed = lexical_core::parse_with_options::<i32, FORMAT>(
+                        digits,
+                        &lexical_core::ParseIntegerOptions::new(),
+                    );
+                    assert_eq!(parsed, Ok(x), "{}", core::str::from_utf8(digits).unwrap());
+                }
+            )*
+        }};
+    }
+
+    roundtrip!(SIGNED, PLUS, PADDED);
+    roundtrip!(UNSIGNED, PLUS);
+
+    // Zero padding would write leading zeros the format cannot parse.
+    let mut buffer = [0u8; 64];
+    let result = lexical_core::try_write_with_options::<_, UNSIGNED>(1i32, &mut buffer, &PADDED);
+    assert_eq!(result.map(|x| &*x), Err(lexical_core::Error::UnsupportedPadding));
+}
+
 /// Test that converting the specified value into a buffer of FORMATTED_SIZE
 /// yields the expected string
 #[cfg(feature = "write-integers")]
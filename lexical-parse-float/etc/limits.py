@@ -68,6 +68,10 @@ def exponent_limit(radix, mantissa_size, max_exp):
         base = remove_pow2(radix)
         precision = mantissa_size + 1
         exp_limit = int(precision / math.log2(base))
+        # For small float types, such as half-precision floats, the
+        # power can overflow before it becomes inexact, so we need to
+        # limit it to the maximum binary exponent.
+        exp_limit = min(exp_limit, int(max_exp / math.log2(radix)))
         return (-exp_limit, exp_limit)
 
 
@@ -115,3 +119,5 @@ def all_limits(mantissa_size, exponent_size, type_name):
 
 all_limits(23, 8, 'f32')
 all_limits(52, 11, 'f64')
+all_limits(10, 5, 'f16')
+all_limits(7, 8, 'bf16')
@@ -17,6 +17,163 @@ use crate::parse::ParseFloat;
 
 const DEFAULT_OPTIONS: Options = Options::new();
 
+/// How a parsed float compares to the exact value of its digits.
+///
+/// The rounding direction is relative to the signed value, so rounding
+/// `-0.1` to the nearest `f64` gives [`RoundedDown`][Exactness::RoundedDown],
+/// since the float is below `-0.1`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Exactness {
+    /// The float is exactly equal to the digits.
+    Exact,
+
+    /// The float is less than the digits.
+    RoundedDown,
+
+    /// The float is greater than the digits.
+    RoundedUp,
+}
+
+/// Trait for floats that can be parsed from bytes, reporting if the float
+/// is exactly equal to the parsed digits.
+///
+/// Special values, such as `NaN` and `Infinity`, are always exact, however,
+/// a value that overflows to infinity is rounded. This requires parsing the
+/// value multiple times if it is not trivially exact, so it is much slower
+/// than [`FromLexical`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{Exactness, FromLexicalExact};
+///
+/// assert_eq!(f64::from_lexical_exact(b"0.125"), Ok((0.125, Exactness::Exact)));
+/// assert_eq!(f64::from_lexical_exact(b"0.1"), Ok((0.1, Exactness::RoundedUp)));
+/// assert_eq!(f64::from_lexical_exact(b"0.3"), Ok((0.3, Exactness::RoundedDown)));
+/// ```
+pub trait FromLexicalExact: FromLexicalWithOptions<Options = Options> {
+    /// Checked parser for a string-to-float conversion, reporting if the
+    /// float is exact.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    fn from_lexical_exact(bytes: &[u8]) -> lexical_util::result::Result<(Self, Exactness)>;
+
+    /// Checked parser for a string-to-float conversion, reporting if the
+    /// float is exact.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    fn from_lexical_partial_exact(
+        bytes: &[u8],
+    ) -> lexical_util::result::Result<(Self, Exactness, usize)>;
+
+    /// Checked parser for a string-to-float conversion with custom options,
+    /// reporting if the float is exact.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    ///
+    /// * `FORMAT`  - Flags and characters designating the number grammar.
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to dictate number parsing.
+    fn from_lexical_exact_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> lexical_util::result::Result<(Self, Exactness)>;
+
+    /// Checked parser for a string-to-float conversion with custom options,
+    /// reporting if the float is exact.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point.
+    ///
+    /// * `FORMAT`  - Flags and characters designating the number grammar.
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to dictate number parsing.
+    fn from_lexical_partial_exact_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> lexical_util::result::Result<(Self, Exactness, usize)>;
+}
+
+/// Trait for floats that can be parsed from bytes as the interval
+/// containing the exact value of the digits.
+///
+/// This returns the largest float less than or equal to the digits and the
+/// smallest float greater than or equal to the digits, which are equal if
+/// the digits are exact. The bounds may be infinite if the digits are
+/// larger than the largest finite float. Special values, such as `NaN` and
+/// `Infinity`, are always exact. The rounding mode, lossy, and overflow and
+/// underflow error options are ignored, since the bounds are always
+/// correctly rounded.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::FromLexicalInterval;
+///
+/// assert_eq!(f64::from_lexical_interval(b"0.5"), Ok((0.5, 0.5)));
+/// assert_eq!(f64::from_lexical_interval(b"0.1"), Ok((0.09999999999999999, 0.1)));
+/// assert_eq!(f64::from_lexical_interval(b"-0.1"), Ok((-0.1, -0.09999999999999999)));
+/// assert_eq!(f64::from_lexical_interval(b"1e400"), Ok((f64::MAX, f64::INFINITY)));
+/// ```
+pub trait FromLexicalInterval: FromLexicalWithOptions<Options = Options> {
+    /// Checked parser for the floats surrounding a numeric string.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    fn from_lexical_interval(bytes: &[u8]) -> lexical_util::result::Result<(Self, Self)>;
+
+    /// Checked parser for the floats surrounding a numeric string.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed bounds until that point.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    fn from_lexical_partial_interval(
+        bytes: &[u8],
+    ) -> lexical_util::result::Result<(Self, Self, usize)>;
+
+    /// Checked parser for the floats surrounding a numeric string with
+    /// custom options.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    ///
+    /// * `FORMAT`  - Flags and characters designating the number grammar.
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to dictate number parsing.
+    fn from_lexical_interval_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> lexical_util::result::Result<(Self, Self)>;
+
+    /// Checked parser for the floats surrounding a numeric string with
+    /// custom options.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed bounds until that point.
+    ///
+    /// * `FORMAT`  - Flags and characters designating the number grammar.
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to dictate number parsing.
+    fn from_lexical_partial_interval_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> lexical_util::result::Result<(Self, Self, usize)>;
+}
+
 /// Implement `FromLexical` for numeric type.
 ///
 /// Need to inline these, otherwise code generation is sub-optimal.
@@ -68,6 +225,90 @@ macro_rules! float_from_lexical {
                 Self::parse_partial::<FORMAT>(bytes, options)
             }
         }
+
+        impl FromLexicalExact for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_exact(
+                bytes: &[u8],
+            ) -> lexical_util::result::Result<(Self, Exactness)>
+            {
+                Self::parse_complete_exact::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_exact(
+                bytes: &[u8],
+            ) -> lexical_util::result::Result<(Self, Exactness, usize)>
+            {
+                Self::parse_partial_exact::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_exact_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Options,
+            ) -> lexical_util::result::Result<(Self, Exactness)>
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                if !format.is_valid() {
+                    return Err(format.error());
+                } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+                    return Err(Error::InvalidPunctuation);
+                }
+                Self::parse_complete_exact::<FORMAT>(bytes, options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_exact_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Options,
+            ) -> lexical_util::result::Result<(Self, Exactness, usize)>
+            {
+                Self::parse_partial_exact::<FORMAT>(bytes, options)
+            }
+        }
+
+        impl FromLexicalInterval for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_interval(
+                bytes: &[u8],
+            ) -> lexical_util::result::Result<(Self, Self)>
+            {
+                Self::parse_complete_interval::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_interval(
+                bytes: &[u8],
+            ) -> lexical_util::result::Result<(Self, Self, usize)>
+            {
+                Self::parse_partial_interval::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_interval_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Options,
+            ) -> lexical_util::result::Result<(Self, Self)>
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                if !format.is_valid() {
+                    return Err(format.error());
+                } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+                    return Err(Error::InvalidPunctuation);
+                }
+                Self::parse_complete_interval::<FORMAT>(bytes, options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_interval_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Options,
+            ) -> lexical_util::result::Result<(Self, Self, usize)>
+            {
+                Self::parse_partial_interval::<FORMAT>(bytes, options)
+            }
+        }
     )*)
 }
 
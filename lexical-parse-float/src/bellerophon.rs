@@ -22,7 +22,7 @@ use lexical_util::format::NumberFormat;
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::mask::{lower_n_halfway, lower_n_mask};
 use crate::number::Number;
-use crate::shared;
+use crate::shared::{self, RoundDirection};
 use crate::table::bellerophon_powers;
 
 // ALGORITHM
@@ -37,7 +37,11 @@ use crate::table::bellerophon_powers;
 /// unable to unambiguously round the significant digits.
 ///
 /// This has been modified to return a biased, rather than unbiased exponent.
-pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> ExtendedFloat80 {
+pub fn bellerophon<F: RawFloat, const FORMAT: u128>(
+    num: &Number,
+    lossy: bool,
+    direction: RoundDirection,
+) -> ExtendedFloat80 {
     let format = NumberFormat::<{ FORMAT }> {};
     debug_assert!(
         !matches!(format.radix(), 2 | 4 | 8 | 16 | 32),
@@ -130,7 +134,7 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
     }
 
     // Too many errors accumulated, return an error.
-    if !lossy && !error_is_accurate::<F>(errors, &fp) {
+    if !lossy && !error_is_accurate::<F>(errors, &fp, direction) {
         // Bias the exponent so we know it's invalid.
         fp.exp += shared::INVALID_FP;
         return fp;
@@ -144,11 +148,17 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
         return fp_zero;
     }
 
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
-            is_above || (is_odd && is_halfway)
-        });
-    });
+    match direction {
+        RoundDirection::Nearest => shared::round::<F, _>(&mut fp, |f, s| {
+            shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
+                is_above || (is_odd && is_halfway)
+            });
+        }),
+        RoundDirection::AwayFromZero => {
+            shared::round::<F, _>(&mut fp, |f, s| shared::round_up(f, s, false));
+        },
+        RoundDirection::TowardZero => shared::round::<F, _>(&mut fp, shared::round_down),
+    }
     fp
 }
 
@@ -185,7 +195,11 @@ const fn error_halfscale() -> u32 {
 
 /// Determine if the number of errors is tolerable for float precision.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80) -> bool {
+fn error_is_accurate<F: RawFloat>(
+    errors: u32,
+    fp: &ExtendedFloat80,
+    direction: RoundDirection,
+) -> bool {
     // Check we can't have a literal 0 denormal float.
     debug_assert!(fp.exp >= -64, "cannot have a literal 0 float");
 
@@ -257,6 +271,22 @@ fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80) -> bool {
     let maskbits = extrabits as u64;
     let errors = errors as u64;
 
+    if direction != RoundDirection::Nearest {
+        // Round-toward, need to use the truncation point.
+        if extrabits > 64 {
+            // Underflow, we have a shift larger than the mantissa.
+            // The value is always above 0 and below the smallest denormal,
+            // since the errors can never carry to the next bit.
+            return true;
+        }
+        // Need to check if we're close to the truncation point, either
+        // below or above it. If we're exactly at it, the value might be
+        // exactly representable, which we cannot determine here.
+        let mask = lower_n_mask(maskbits);
+        let extra = fp.mant & mask;
+        return extra > errors && mask - extra >= errors;
+    }
+
     // Round-to-nearest, need to use the halfway point.
     if extrabits > 64 {
         // Underflow, we have a shift larger than the mantissa.
@@ -16,14 +16,18 @@ use lexical_util::step::u64_step;
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::mask::lower_n_halfway;
 use crate::number::Number;
-use crate::shared;
+use crate::shared::{self, RoundDirection};
 
 // ALGORITHM
 // ---------
 
 /// Algorithm specialized for radixes of powers-of-two.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> ExtendedFloat80 {
+pub fn binary<F: RawFloat, const FORMAT: u128>(
+    num: &Number,
+    lossy: bool,
+    direction: RoundDirection,
+) -> ExtendedFloat80 {
     let format = NumberFormat::<{ FORMAT }> {};
     debug_assert!(
         matches!(format.radix(), 2 | 4 | 8 | 16 | 32),
@@ -35,6 +39,11 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
         exp: 0,
     };
 
+    // Short-circuit if the value is a literal 0.
+    if num.mantissa == 0 {
+        return fp_zero;
+    }
+
     // Normalize our mantissa for simpler results.
     let ctlz = num.mantissa.leading_zeros();
     let mantissa = num.mantissa << ctlz;
@@ -69,6 +78,27 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
     let halfway = lower_n_halfway(shift as u64);
     let is_even = mantissa & last_bit == 0;
     let is_halfway = mantissa & truncated == halfway;
+    let mut fp = ExtendedFloat80 {
+        mant: mantissa,
+        exp: power2,
+    };
+    if direction != RoundDirection::Nearest {
+        // For the directed rounding modes, we're only ambiguous if no
+        // truncated bits are set, since the truncated digits may be zero.
+        let is_above = mantissa & truncated != 0;
+        if !lossy && !is_above && num.many_digits {
+            return ExtendedFloat80 {
+                mant: mantissa,
+                exp: power2 + shared::INVALID_FP,
+            };
+        }
+        if direction == RoundDirection::AwayFromZero {
+            shared::round::<F, _>(&mut fp, |f, s| shared::round_up(f, s, num.many_digits));
+        } else {
+            shared::round::<F, _>(&mut fp, shared::round_down);
+        }
+        return fp;
+    }
     if !lossy && is_even && is_halfway && num.many_digits {
         // Exactly halfway and even, cannot safely determine our representation.
         // Bias the exponent so we know it's invalid.
@@ -81,11 +111,6 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
     // Shift our digits into place, and round up if needed.
     let is_above = mantissa & truncated > halfway;
     let round_up = is_above || (!is_even && is_halfway);
-    let mut fp = ExtendedFloat80 {
-        mant: mantissa,
-        exp: power2,
-    };
-
     shared::round::<F, _>(&mut fp, |f, s| {
         shared::round_nearest_tie_even(f, s, |_, _, _| round_up);
     });
@@ -149,7 +174,10 @@ pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
 ///
 /// This avoids the need for arbitrary-precision arithmetic, since the result
 /// will always be a near-halfway representation where rounded-down it's even.
-pub fn slow_binary<F: RawFloat, const FORMAT: u128>(num: Number) -> ExtendedFloat80 {
+pub fn slow_binary<F: RawFloat, const FORMAT: u128>(
+    num: Number,
+    direction: RoundDirection,
+) -> ExtendedFloat80 {
     let format = NumberFormat::<{ FORMAT }> {};
     let radix = format.radix();
     debug_assert!(matches!(radix, 2 | 4 | 8 | 16 | 32), "algorithm requires a power-of-two");
@@ -207,8 +235,14 @@ pub fn slow_binary<F: RawFloat, const FORMAT: u128>(num: Number) -> ExtendedFloa
         exp: power2,
     };
 
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |_, _, _| !zero);
-    });
+    match direction {
+        RoundDirection::Nearest => shared::round::<F, _>(&mut fp, |f, s| {
+            shared::round_nearest_tie_even(f, s, |_, _, _| !zero);
+        }),
+        RoundDirection::AwayFromZero => {
+            shared::round::<F, _>(&mut fp, |f, s| shared::round_up(f, s, !zero));
+        },
+        RoundDirection::TowardZero => shared::round::<F, _>(&mut fp, shared::round_down),
+    }
     fp
 }
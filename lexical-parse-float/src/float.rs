@@ -64,7 +64,7 @@ pub trait RawFloat: Float + ExactFloat + MaxDigits {
     #[must_use]
     #[inline(always)]
     fn int_pow_fast_path(exponent: usize, radix: u32) -> u64 {
-        // SAFETY: safe as long as the exponent is smaller than the radix table.
+        // NOTE: this panics if the exponent is larger than the radix table.
         #[cfg(not(feature = "compact"))]
         return get_small_int_power(exponent, radix);
 
@@ -76,7 +76,7 @@ pub trait RawFloat: Float + ExactFloat + MaxDigits {
 impl RawFloat for f32 {
     #[inline(always)]
     fn pow_fast_path(exponent: usize, radix: u32) -> Self {
-        // SAFETY: safe as long as the exponent is smaller than the radix table.
+        // NOTE: this panics if the exponent is larger than the radix table.
         #[cfg(not(feature = "compact"))]
         return get_small_f32_power(exponent, radix);
 
@@ -88,7 +88,7 @@ impl RawFloat for f32 {
 impl RawFloat for f64 {
     #[inline(always)]
     fn pow_fast_path(exponent: usize, radix: u32) -> Self {
-        // SAFETY: safe as long as the exponent is smaller than the radix table.
+        // NOTE: this panics if the exponent is larger than the radix table.
         #[cfg(not(feature = "compact"))]
         return get_small_f64_power(exponent, radix);
 
@@ -97,19 +97,39 @@ impl RawFloat for f64 {
     }
 }
 
+// NOTE: There are no native operations for the half-precision floats, which
+// are instead implemented via `f32`. Since `f32` has at least `2p + 2` bits
+// of precision for both `f16` and `bf16`, double rounding cannot occur for
+// a single multiplication or division, so the fast path is still correct
+// as long as the power is exactly representable (see `exponent_limit`).
 #[cfg(feature = "f16")]
 impl RawFloat for f16 {
     #[inline(always)]
-    fn pow_fast_path(_: usize, _: u32) -> Self {
-        unimplemented!()
+    fn pow_fast_path(exponent: usize, radix: u32) -> Self {
+        // NOTE: this panics if the exponent is larger than the radix table.
+        #[cfg(not(feature = "compact"))]
+        return Self::from_f32(get_small_f32_power(exponent, radix));
+
+        #[cfg(feature = "compact")]
+        return Self::from_f32(powf(radix as f32, exponent as f32));
     }
 }
 
+// NOTE: There are no native operations for the half-precision floats, which
+// are instead implemented via `f32`. Since `f32` has at least `2p + 2` bits
+// of precision for both `f16` and `bf16`, double rounding cannot occur for
+// a single multiplication or division, so the fast path is still correct
+// as long as the power is exactly representable (see `exponent_limit`).
 #[cfg(feature = "f16")]
 impl RawFloat for bf16 {
     #[inline(always)]
-    fn pow_fast_path(_: usize, _: u32) -> Self {
-        unimplemented!()
+    fn pow_fast_path(exponent: usize, radix: u32) -> Self {
+        // NOTE: this panics if the exponent is larger than the radix table.
+        #[cfg(not(feature = "compact"))]
+        return Self::from_f32(get_small_f32_power(exponent, radix));
+
+        #[cfg(feature = "compact")]
+        return Self::from_f32(powf(radix as f32, exponent as f32));
     }
 }
 
@@ -165,20 +185,20 @@ impl LemireFloat for f64 {
 
 #[cfg(feature = "f16")]
 impl LemireFloat for f16 {
-    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = 0;
-    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 0;
-    const MINIMUM_EXPONENT: i32 = 0;
-    const SMALLEST_POWER_OF_TEN: i32 = 0;
-    const LARGEST_POWER_OF_TEN: i32 = 0;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -22;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 5;
+    const MINIMUM_EXPONENT: i32 = -15;
+    const SMALLEST_POWER_OF_TEN: i32 = -27;
+    const LARGEST_POWER_OF_TEN: i32 = 4;
 }
 
 #[cfg(feature = "f16")]
 impl LemireFloat for bf16 {
-    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = 0;
-    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 0;
-    const MINIMUM_EXPONENT: i32 = 0;
-    const SMALLEST_POWER_OF_TEN: i32 = 0;
-    const LARGEST_POWER_OF_TEN: i32 = 0;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -24;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 3;
+    const MINIMUM_EXPONENT: i32 = -127;
+    const SMALLEST_POWER_OF_TEN: i32 = -60;
+    const LARGEST_POWER_OF_TEN: i32 = 38;
 }
 
 #[inline(always)]
@@ -63,10 +63,11 @@ mod fpu_precision {
 
         // Compute the value for the Precision Control field that is appropriate for
         // `T`.
+        // NOTE: Half-precision floats use single-precision operations.
         let cw_precision = match size_of::<T>() {
-            4 => 0x0000, // 32 bits
-            8 => 0x0200, // 64 bits
-            _ => 0x0300, // default, 80 bits
+            2 | 4 => 0x0000, // 32 bits
+            8 => 0x0200,     // 64 bits
+            _ => 0x0300,     // default, 80 bits
         };
 
         // Get the original value of the control word to restore it later, when the
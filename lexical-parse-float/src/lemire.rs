@@ -7,24 +7,30 @@
 #![doc(hidden)]
 
 use crate::float::{ExtendedFloat80, LemireFloat};
+use crate::mask::lower_n_mask;
 use crate::number::Number;
-use crate::shared;
+use crate::shared::{self, RoundDirection};
 use crate::table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
 
 /// Ensure truncation of digits doesn't affect our computation, by doing 2
 /// passes.
 #[must_use]
 #[inline(always)]
-pub fn lemire<F: LemireFloat>(num: &Number, lossy: bool) -> ExtendedFloat80 {
+pub fn lemire<F: LemireFloat>(
+    num: &Number,
+    lossy: bool,
+    direction: RoundDirection,
+) -> ExtendedFloat80 {
     // If significant digits were truncated, then we can have rounding error
     // only if `mantissa + 1` produces a different result. We also avoid
     // redundantly using the Eisel-Lemire algorithm if it was unable to
-    // correctly round on the first pass.
-    let mut fp = compute_float::<F>(num.exponent, num.mantissa, lossy);
+    // correctly round on the first pass. This holds for every rounding
+    // direction, since rounding is monotonic.
+    let mut fp = compute_float::<F>(num.exponent, num.mantissa, lossy, direction);
     if !lossy
         && num.many_digits
         && fp.exp >= 0
-        && fp != compute_float::<F>(num.exponent, num.mantissa + 1, false)
+        && fp != compute_float::<F>(num.exponent, num.mantissa + 1, false, direction)
     {
         // Need to re-calculate, since the previous values are rounded
         // when the slow path algorithm expects a normalized extended float.
@@ -46,6 +52,8 @@ pub fn lemire<F: LemireFloat>(num: &Number, lossy: bool) -> ExtendedFloat80 {
 /// representation can be unambiguously rounded to the nearest
 /// machine float. Near-halfway cases are not handled here,
 /// and are represented by a negative, biased binary exponent.
+/// For the directed rounding modes, the same is true for cases
+/// near a machine float, including exactly representable values.
 ///
 /// The algorithm is described in detail in "Daniel Lemire, Number Parsing
 /// at a Gigabyte per Second" in section 5, "Fast Algorithm", and
@@ -54,7 +62,12 @@ pub fn lemire<F: LemireFloat>(num: &Number, lossy: bool) -> ExtendedFloat80 {
 #[inline]
 #[must_use]
 #[allow(clippy::missing_inline_in_public_items)] // reason="public for testing only"
-pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> ExtendedFloat80 {
+pub fn compute_float<F: LemireFloat>(
+    q: i64,
+    mut w: u64,
+    lossy: bool,
+    direction: RoundDirection,
+) -> ExtendedFloat80 {
     let fp_zero = ExtendedFloat80 {
         mant: 0,
         exp: 0,
@@ -74,6 +87,9 @@ pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> Extende
     let lz = w.leading_zeros() as i32;
     w <<= lz;
     let (lo, hi) = compute_product_approx(q, w, F::MANTISSA_SIZE as usize + 3);
+    if direction != RoundDirection::Nearest {
+        return compute_float_directed::<F>(q, hi, lz, lossy, direction);
+    }
     if !lossy && lo == 0xFFFF_FFFF_FFFF_FFFF {
         // If we have failed to approximate `w x 5^-q` with our 128-bit value.
         // Since the addition of 1 could lead to an overflow which could then
@@ -104,7 +120,17 @@ pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> Extende
             return fp_zero;
         }
         // Have a subnormal value.
-        mantissa >>= -power2 + 1;
+        let shift = -power2 + 1;
+        // Exactly halfway subnormal values cannot be represented in 19
+        // digits for `f32` or `f64`, but they can for `f16`. Since we only
+        // round up here, defer any near-halfway cases to the slow path.
+        let truncated = upperbit + 64 - F::MANTISSA_SIZE - 3 + shift;
+        let mask = 1_u64.checked_shl(truncated as u32).map_or(u64::MAX, |x| x - 1);
+        let halfway = (mantissa >> shift) & 1 == 1;
+        if !lossy && ((halfway && hi & mask == 0) || (!halfway && hi & mask == mask)) {
+            return compute_error_scaled::<F>(q, hi, lz);
+        }
+        mantissa >>= shift;
         mantissa += mantissa & 1;
         mantissa >>= 1;
         power2 = (mantissa >= (1_u64 << F::MANTISSA_SIZE)) as i32;
@@ -155,6 +181,82 @@ pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> Extende
     }
 }
 
+/// Round the approximate product toward or away from zero.
+///
+/// The approximation of `w * 5^q` can be off by 1 in the least-significant
+/// bit of `hi`, so we can only unambiguously round if the truncated bits
+/// are not within 1 of a machine float. This means exactly representable
+/// values always fall back to the slow path.
+#[must_use]
+#[inline(always)]
+fn compute_float_directed<F: LemireFloat>(
+    q: i64,
+    hi: u64,
+    lz: i32,
+    lossy: bool,
+    direction: RoundDirection,
+) -> ExtendedFloat80 {
+    let fp_zero = ExtendedFloat80 {
+        mant: 0,
+        exp: 0,
+    };
+    let fp_inf = ExtendedFloat80 {
+        mant: 0,
+        exp: F::INFINITE_POWER,
+    };
+
+    // Calculate the number of bits to truncate, so only the hidden and
+    // mantissa bits remain, or fewer for subnormal values.
+    let upperbit = (hi >> 63) as i32;
+    let mut power2 = power(q as i32) + upperbit - lz - F::MINIMUM_EXPONENT;
+    let mut shift = upperbit + 64 - F::MANTISSA_SIZE - 2;
+    if power2 <= 0 {
+        if -power2 + 1 >= 64 {
+            // Have more than 64 bits below the minimum exponent, must be 0.
+            return fp_zero;
+        }
+        shift += -power2 + 1;
+    }
+
+    // If we truncate more than 64 bits, the value is always between 0
+    // and the smallest subnormal float, even with errors.
+    let mask = lower_n_mask(shift.min(64) as u64);
+    let truncated = hi & mask;
+    if !lossy && shift <= 64 && (truncated <= 1 || truncated >= mask - 1) {
+        return compute_error_scaled::<F>(q, hi, lz);
+    }
+    let mut mantissa = match shift >= 64 {
+        true => 0,
+        false => hi >> shift,
+    };
+    mantissa += (direction == RoundDirection::AwayFromZero) as u64;
+
+    if power2 <= 0 {
+        // Have a subnormal value, which might have rounded up to the
+        // smallest normal value.
+        power2 = (mantissa >= (1_u64 << F::MANTISSA_SIZE)) as i32;
+        return ExtendedFloat80 {
+            mant: mantissa,
+            exp: power2,
+        };
+    }
+    if mantissa >= (2_u64 << F::MANTISSA_SIZE) {
+        // Rounding up overflowed, so the carry bit is set.
+        mantissa = 1_u64 << F::MANTISSA_SIZE;
+        power2 += 1;
+    }
+    // Zero out the hidden bit.
+    mantissa &= !(1_u64 << F::MANTISSA_SIZE);
+    if power2 >= F::INFINITE_POWER {
+        // Exponent is above largest normal value, must be infinite.
+        return fp_inf;
+    }
+    ExtendedFloat80 {
+        mant: mantissa,
+        exp: power2,
+    }
+}
+
 /// Fallback algorithm to calculate the non-rounded representation.
 /// This calculates the extended representation, and then normalizes
 /// the resulting representation, so the high bit is set.
@@ -262,19 +262,26 @@
 //! #### f16
 //!
 //! This enables the use of the half-precision floats [`f16`][`ieee-f16`] and
-//! [`bf16`][`brain-float`]. However, since these have limited hardware support
-//! and are primarily used for vectorized operations, they are parsed as if
-//! they were an [`f32`]. Due to the low precision of 16-bit floats, the results
-//! may appear to have significant rounding error.
+//! [`bf16`][`brain-float`]. These are parsed directly from the decimal
+//! digits, and are therefore correctly rounded, rather than parsed as an
+//! [`f32`] and then narrowed, which could cause double rounding errors. Due
+//! to the low precision of 16-bit floats, the results may appear to have
+//! significant rounding error.
 //!
 //! ```rust
 //! # #[cfg(feature = "f16")] {
 //! # use core::str;
-//! use lexical_parse_float::{f16, FromLexical};
+//! use lexical_parse_float::{bf16, f16, FromLexical};
 //!
 //! let value = "1.234375";
 //! let result = f16::from_lexical(value.as_bytes());
 //! assert_eq!(result, Ok(f16::from_f64_const(1.234f64)));
+//!
+//! // Just above the halfway point between `1.0` and `1.0078125`, which
+//! // would round down to `1.0` if it was first rounded to an `f32`.
+//! let value = "1.00390625000000001";
+//! let result = bf16::from_lexical(value.as_bytes());
+//! assert_eq!(result, Ok(bf16::from_bits(0x3F81)));
 //! # }
 //! ```
 //!
@@ -518,8 +525,7 @@
     clippy::unnecessary_safety_comment,
     clippy::semicolon_if_nothing_returned,
     clippy::unwrap_used,
-    clippy::as_underscore,
-    clippy::doc_markdown
+    clippy::as_underscore
 )]
 #![allow(
     // used when concepts are logically separate
@@ -546,6 +552,7 @@ pub mod bigint;
 pub mod binary;
 pub mod float;
 pub mod fpu;
+pub mod incremental;
 pub mod lemire;
 pub mod libm;
 pub mod limits;
@@ -553,6 +560,8 @@ pub mod mask;
 pub mod number;
 pub mod options;
 pub mod parse;
+pub mod scan;
+pub mod segments;
 pub mod slow;
 pub mod table;
 
@@ -577,8 +586,18 @@ pub use lexical_util::error::Error;
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
 pub use lexical_util::options::ParseOptions;
-pub use lexical_util::result::Result;
+pub use lexical_util::result::{Progress, Result};
 
-pub use self::api::{FromLexical, FromLexicalWithOptions};
+pub use self::api::{
+    Exactness,
+    FromLexical,
+    FromLexicalExact,
+    FromLexicalInterval,
+    FromLexicalWithOptions,
+};
+#[cfg(feature = "std")]
+pub use self::incremental::IncrementalParser;
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{Options, OptionsBuilder, RoundMode};
+pub use self::scan::{Token, TokenKind};
+pub use self::segments::FromLexicalSegments;
@@ -73,26 +73,30 @@ impl ExactFloat for f64 {
 #[cfg(feature = "f16")]
 impl ExactFloat for f16 {
     #[inline(always)]
-    fn exponent_limit(_: u32) -> (i64, i64) {
-        unimplemented!()
+    fn exponent_limit(radix: u32) -> (i64, i64) {
+        debug_assert_radix(radix);
+        f16_exponent_limit(radix)
     }
 
     #[inline(always)]
-    fn mantissa_limit(_: u32) -> i64 {
-        unimplemented!()
+    fn mantissa_limit(radix: u32) -> i64 {
+        debug_assert_radix(radix);
+        f16_mantissa_limit(radix)
     }
 }
 
 #[cfg(feature = "f16")]
 impl ExactFloat for bf16 {
     #[inline(always)]
-    fn exponent_limit(_: u32) -> (i64, i64) {
-        unimplemented!()
+    fn exponent_limit(radix: u32) -> (i64, i64) {
+        debug_assert_radix(radix);
+        bf16_exponent_limit(radix)
     }
 
     #[inline(always)]
-    fn mantissa_limit(_: u32) -> i64 {
-        unimplemented!()
+    fn mantissa_limit(radix: u32) -> i64 {
+        debug_assert_radix(radix);
+        bf16_mantissa_limit(radix)
     }
 }
 
@@ -403,6 +407,306 @@ pub const fn f64_mantissa_limit(radix: u32) -> i64 {
     }
 }
 
+/// Get the exponent limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(feature = "radix")]
+pub const fn f16_exponent_limit(radix: u32) -> (i64, i64) {
+    match radix {
+        2 => (-15, 15),
+        3 => (-6, 6),
+        4 => (-7, 7),
+        5 => (-4, 4),
+        6 => (-5, 5),
+        7 => (-3, 3),
+        8 => (-5, 5),
+        9 => (-3, 3),
+        10 => (-4, 4),
+        11 => (-3, 3),
+        12 => (-4, 4),
+        13 => (-2, 2),
+        14 => (-3, 3),
+        15 => (-2, 2),
+        16 => (-3, 3),
+        17 => (-2, 2),
+        18 => (-3, 3),
+        19 => (-2, 2),
+        20 => (-3, 3),
+        21 => (-2, 2),
+        22 => (-3, 3),
+        23 => (-2, 2),
+        24 => (-3, 3),
+        25 => (-2, 2),
+        26 => (-2, 2),
+        27 => (-2, 2),
+        28 => (-3, 3),
+        29 => (-2, 2),
+        30 => (-2, 2),
+        31 => (-2, 2),
+        32 => (-3, 3),
+        33 => (-2, 2),
+        34 => (-2, 2),
+        35 => (-2, 2),
+        36 => (-2, 2),
+        _ => (0, 0),
+    }
+}
+
+/// Get the exponent limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(all(feature = "power-of-two", not(feature = "radix")))]
+pub const fn f16_exponent_limit(radix: u32) -> (i64, i64) {
+    match radix {
+        2 => (-15, 15),
+        4 => (-7, 7),
+        8 => (-5, 5),
+        10 => (-4, 4),
+        16 => (-3, 3),
+        32 => (-3, 3),
+        _ => (0, 0),
+    }
+}
+
+/// Get the exponent limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(not(feature = "power-of-two"))]
+pub const fn f16_exponent_limit(radix: u32) -> (i64, i64) {
+    match radix {
+        10 => (-4, 4),
+        _ => (0, 0),
+    }
+}
+
+/// Get the mantissa limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(feature = "radix")]
+pub const fn f16_mantissa_limit(radix: u32) -> i64 {
+    match radix {
+        2 => 11,
+        3 => 6,
+        4 => 5,
+        5 => 4,
+        6 => 4,
+        7 => 3,
+        8 => 3,
+        9 => 3,
+        10 => 3,
+        11 => 3,
+        12 => 3,
+        13 => 2,
+        14 => 2,
+        15 => 2,
+        16 => 2,
+        17 => 2,
+        18 => 2,
+        19 => 2,
+        20 => 2,
+        21 => 2,
+        22 => 2,
+        23 => 2,
+        24 => 2,
+        25 => 2,
+        26 => 2,
+        27 => 2,
+        28 => 2,
+        29 => 2,
+        30 => 2,
+        31 => 2,
+        32 => 2,
+        33 => 2,
+        34 => 2,
+        35 => 2,
+        36 => 2,
+        _ => 0,
+    }
+}
+
+/// Get the mantissa limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(all(feature = "power-of-two", not(feature = "radix")))]
+pub const fn f16_mantissa_limit(radix: u32) -> i64 {
+    match radix {
+        2 => 11,
+        4 => 5,
+        8 => 3,
+        10 => 3,
+        16 => 2,
+        32 => 2,
+        _ => 0,
+    }
+}
+
+/// Get the mantissa limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(not(feature = "power-of-two"))]
+pub const fn f16_mantissa_limit(radix: u32) -> i64 {
+    match radix {
+        10 => 3,
+        _ => 0,
+    }
+}
+
+/// Get the exponent limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(feature = "radix")]
+pub const fn bf16_exponent_limit(radix: u32) -> (i64, i64) {
+    match radix {
+        2 => (-127, 127),
+        3 => (-5, 5),
+        4 => (-63, 63),
+        5 => (-3, 3),
+        6 => (-5, 5),
+        7 => (-2, 2),
+        8 => (-42, 42),
+        9 => (-2, 2),
+        10 => (-3, 3),
+        11 => (-2, 2),
+        12 => (-5, 5),
+        13 => (-2, 2),
+        14 => (-2, 2),
+        15 => (-2, 2),
+        16 => (-31, 31),
+        17 => (-1, 1),
+        18 => (-2, 2),
+        19 => (-1, 1),
+        20 => (-3, 3),
+        21 => (-1, 1),
+        22 => (-2, 2),
+        23 => (-1, 1),
+        24 => (-5, 5),
+        25 => (-1, 1),
+        26 => (-2, 2),
+        27 => (-1, 1),
+        28 => (-2, 2),
+        29 => (-1, 1),
+        30 => (-2, 2),
+        31 => (-1, 1),
+        32 => (-25, 25),
+        33 => (-1, 1),
+        34 => (-1, 1),
+        35 => (-1, 1),
+        36 => (-2, 2),
+        _ => (0, 0),
+    }
+}
+
+/// Get the exponent limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(all(feature = "power-of-two", not(feature = "radix")))]
+pub const fn bf16_exponent_limit(radix: u32) -> (i64, i64) {
+    match radix {
+        2 => (-127, 127),
+        4 => (-63, 63),
+        8 => (-42, 42),
+        10 => (-3, 3),
+        16 => (-31, 31),
+        32 => (-25, 25),
+        _ => (0, 0),
+    }
+}
+
+/// Get the exponent limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(not(feature = "power-of-two"))]
+pub const fn bf16_exponent_limit(radix: u32) -> (i64, i64) {
+    match radix {
+        10 => (-3, 3),
+        _ => (0, 0),
+    }
+}
+
+/// Get the mantissa limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(feature = "radix")]
+pub const fn bf16_mantissa_limit(radix: u32) -> i64 {
+    match radix {
+        2 => 8,
+        3 => 5,
+        4 => 4,
+        5 => 3,
+        6 => 3,
+        7 => 2,
+        8 => 2,
+        9 => 2,
+        10 => 2,
+        11 => 2,
+        12 => 2,
+        13 => 2,
+        14 => 2,
+        15 => 2,
+        16 => 2,
+        17 => 1,
+        18 => 1,
+        19 => 1,
+        20 => 1,
+        21 => 1,
+        22 => 1,
+        23 => 1,
+        24 => 1,
+        25 => 1,
+        26 => 1,
+        27 => 1,
+        28 => 1,
+        29 => 1,
+        30 => 1,
+        31 => 1,
+        32 => 1,
+        33 => 1,
+        34 => 1,
+        35 => 1,
+        36 => 1,
+        _ => 0,
+    }
+}
+
+/// Get the mantissa limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(all(feature = "power-of-two", not(feature = "radix")))]
+pub const fn bf16_mantissa_limit(radix: u32) -> i64 {
+    match radix {
+        2 => 8,
+        4 => 4,
+        8 => 2,
+        10 => 2,
+        16 => 2,
+        32 => 1,
+        _ => 0,
+    }
+}
+
+/// Get the mantissa limit as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+#[cfg(not(feature = "power-of-two"))]
+pub const fn bf16_mantissa_limit(radix: u32) -> i64 {
+    match radix {
+        10 => 2,
+        _ => 0,
+    }
+}
+
 /// Get the exponent limit as a const fn.
 #[must_use]
 #[inline(always)]
@@ -884,19 +1188,25 @@ impl MaxDigits for f64 {
     }
 }
 
+/// emin = -14
+/// p2 = 11
 #[cfg(feature = "f16")]
 impl MaxDigits for f16 {
     #[inline(always)]
-    fn max_digits(_: u32) -> Option<usize> {
-        unimplemented!()
+    fn max_digits(radix: u32) -> Option<usize> {
+        debug_assert_radix(radix);
+        f16_max_digits(radix)
     }
 }
 
+/// emin = -126
+/// p2 = 8
 #[cfg(feature = "f16")]
 impl MaxDigits for bf16 {
     #[inline(always)]
-    fn max_digits(_: u32) -> Option<usize> {
-        unimplemented!()
+    fn max_digits(radix: u32) -> Option<usize> {
+        debug_assert_radix(radix);
+        bf16_max_digits(radix)
     }
 }
 
@@ -977,3 +1287,53 @@ pub const fn f64_max_digits(radix: u32) -> Option<usize> {
         _ => None,
     }
 }
+
+/// Get the maximum number of significant digits as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+pub const fn f16_max_digits(radix: u32) -> Option<usize> {
+    match radix {
+        6 => Some(21),
+        10 => Some(23),
+        12 => Some(23),
+        14 => Some(23),
+        18 => Some(23),
+        20 => Some(23),
+        22 => Some(24),
+        24 => Some(24),
+        26 => Some(24),
+        28 => Some(24),
+        30 => Some(24),
+        34 => Some(24),
+        36 => Some(24),
+        // Powers of two should be unreachable.
+        // Odd numbers will have infinite digits.
+        _ => None,
+    }
+}
+
+/// Get the maximum number of significant digits as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+pub const fn bf16_max_digits(radix: u32) -> Option<usize> {
+    match radix {
+        6 => Some(87),
+        10 => Some(98),
+        12 => Some(101),
+        14 => Some(103),
+        18 => Some(106),
+        20 => Some(107),
+        22 => Some(107),
+        24 => Some(108),
+        26 => Some(109),
+        28 => Some(109),
+        30 => Some(110),
+        34 => Some(111),
+        36 => Some(111),
+        // Powers of two should be unreachable.
+        // Odd numbers will have infinite digits.
+        _ => None,
+    }
+}
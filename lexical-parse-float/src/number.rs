@@ -48,6 +48,31 @@ impl Number<'_> {
             && !self.many_digits
     }
 
+    /// Detect if the float can be exactly reconstructed from native floats.
+    ///
+    /// The fast path always rounds to nearest, so this is required for the
+    /// directed rounding modes. This is true if the significant digits
+    /// multiplied by a small power of the radix fit in the mantissa, or
+    /// if they are exactly divisible by it, ignoring powers of two.
+    #[must_use]
+    #[inline(always)]
+    pub fn is_exact_fast_path<F: RawFloat, const FORMAT: u128>(&self) -> bool {
+        let format = NumberFormat::<FORMAT> {};
+        let radix = format.radix();
+        if self.many_digits
+            || self.mantissa > F::MAX_MANTISSA_FAST_PATH
+            || self.exponent.abs() > F::mantissa_limit(radix)
+        {
+            return false;
+        }
+        let power = F::int_pow_fast_path(self.exponent.unsigned_abs() as usize, radix);
+        if self.exponent >= 0 {
+            self.mantissa.checked_mul(power).map_or(false, |x| x <= F::MAX_MANTISSA_FAST_PATH)
+        } else {
+            self.mantissa % (power >> power.trailing_zeros()) == 0
+        }
+    }
+
     /// The fast path algorithm using machine-sized integers and floats.
     ///
     /// This is extracted into a separate function so that it can be attempted
@@ -195,6 +195,29 @@ use lexical_util::result::Result;
 /// Maximum length for a special string.
 pub const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 
+/// Enumeration for how to round parsed floats that cannot be exactly
+/// represented.
+///
+/// For example, using [`NearestTieEven`][RoundMode::NearestTieEven], `"0.1"`
+/// parsed as an `f32` would be `0.1` (`0x3DCCCCCD`), while
+/// [`TowardZero`][RoundMode::TowardZero] would be the next float below it
+/// (`0x3DCCCCCC`). Values that are exactly representable are never rounded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RoundMode {
+    /// Round to the nearest float, and to the float with an even significand
+    /// on a tie.
+    NearestTieEven,
+
+    /// Round toward positive infinity.
+    TowardPositive,
+
+    /// Round toward negative infinity.
+    TowardNegative,
+
+    /// Round toward zero, truncating the significant digits.
+    TowardZero,
+}
+
 /// Builder for [`Options`].
 ///
 /// This enables extensive control over how the float is parsed, from
@@ -219,6 +242,19 @@ pub struct OptionsBuilder {
     /// Disable the use of arbitrary-precision arithmetic, and always
     /// return the results from the fast or intermediate path algorithms.
     lossy: bool,
+    /// Rounding mode for values that cannot be exactly represented.
+    round_mode: RoundMode,
+    /// Reject values that cannot be exactly represented.
+    require_exact: bool,
+    /// Reject finite values that overflow to infinity.
+    error_on_overflow: bool,
+    /// Reject non-zero values that underflow to zero.
+    error_on_underflow: bool,
+    /// Reject values that are subnormal after rounding.
+    error_on_subnormal: bool,
+
+    /// Accept SI prefixes, such as `k` or `µ`, in place of an exponent.
+    si_prefix: bool,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
@@ -237,6 +273,12 @@ impl OptionsBuilder {
     pub const fn new() -> Self {
         Self {
             lossy: false,
+            round_mode: RoundMode::NearestTieEven,
+            require_exact: false,
+            error_on_overflow: false,
+            error_on_underflow: false,
+            error_on_subnormal: false,
+            si_prefix: false,
             exponent: b'e',
             decimal_point: b'.',
             nan_string: Some(b"NaN"),
@@ -264,6 +306,119 @@ impl OptionsBuilder {
         self.lossy
     }
 
+    /// Get the rounding mode for values that cannot be exactly represented.
+    ///
+    /// Directed rounding is exact for all inputs, including values that
+    /// overflow or underflow: for example, rounding toward zero never
+    /// produces an infinity. Defaults to [`RoundMode::NearestTieEven`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::{Options, RoundMode};
+    ///
+    /// assert_eq!(Options::builder().get_round_mode(), RoundMode::NearestTieEven);
+    /// ```
+    #[inline(always)]
+    pub const fn get_round_mode(&self) -> RoundMode {
+        self.round_mode
+    }
+
+    /// Get if we reject values that cannot be exactly represented.
+    ///
+    /// If set, parsing a value that must be rounded returns
+    /// [`Error::InexactFloat`]. Special values, such as `NaN`, are
+    /// always exact. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::builder().get_require_exact(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_require_exact(&self) -> bool {
+        self.require_exact
+    }
+
+    /// Get if we reject finite values that overflow to infinity.
+    ///
+    /// If set, parsing digits that round to infinity returns
+    /// [`Error::Overflow`], with the index of the end of the number.
+    /// Like IEEE-754, this includes digits that overflow when rounding
+    /// toward zero, which are otherwise clamped to the largest finite
+    /// float. Special values, such as `inf`, are never errors. Defaults
+    /// to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::builder().get_error_on_overflow(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_error_on_overflow(&self) -> bool {
+        self.error_on_overflow
+    }
+
+    /// Get if we reject non-zero values that underflow to zero.
+    ///
+    /// If set, parsing non-zero digits that round to zero returns
+    /// [`Error::Underflow`], with the index of the end of the number.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::builder().get_error_on_underflow(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_error_on_underflow(&self) -> bool {
+        self.error_on_underflow
+    }
+
+    /// Get if we reject values that are subnormal after rounding.
+    ///
+    /// If set, parsing digits that round to a subnormal float returns
+    /// [`Error::Underflow`], with the index of the end of the number.
+    /// Values that underflow to zero are only errors if
+    /// [`error_on_underflow`][Self::error_on_underflow] is set. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::builder().get_error_on_subnormal(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_error_on_subnormal(&self) -> bool {
+        self.error_on_subnormal
+    }
+
+    /// Get if we accept SI prefixes in place of an exponent.
+    ///
+    /// If set, decimal floats may end with an SI prefix from `q` (`1e-30`)
+    /// to `Q` (`1e30`), such as `12.5k` or `3.3µ`, which scales the value
+    /// by the matching power of 10. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::builder().get_si_prefix(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_si_prefix(&self) -> bool {
+        self.si_prefix
+    }
+
     /// Get the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -378,6 +533,177 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the rounding mode for values that cannot be exactly represented.
+    ///
+    /// Directed rounding is exact for all inputs, including values that
+    /// overflow or underflow: for example, rounding toward zero never
+    /// produces an infinity. Defaults to [`RoundMode::NearestTieEven`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::{Options, RoundMode};
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .round_mode(RoundMode::TowardZero)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.round_mode(), RoundMode::TowardZero);
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn round_mode(mut self, round_mode: RoundMode) -> Self {
+        self.round_mode = round_mode;
+        self
+    }
+
+    /// Set if we reject values that cannot be exactly represented.
+    ///
+    /// If set, parsing a value that must be rounded returns
+    /// [`Error::InexactFloat`]. Special values, such as `NaN`, are
+    /// always exact. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{Error, FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .require_exact(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.require_exact(), true);
+    ///
+    /// let parse = |x: &str| f64::from_lexical_with_options::<STANDARD>(x.as_bytes(), &OPTIONS);
+    /// assert_eq!(parse("0.125"), Ok(0.125));
+    /// assert_eq!(parse("0.1"), Err(Error::InexactFloat(3)));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn require_exact(mut self, require_exact: bool) -> Self {
+        self.require_exact = require_exact;
+        self
+    }
+
+    /// Set if we reject finite values that overflow to infinity.
+    ///
+    /// If set, parsing digits that round to infinity returns
+    /// [`Error::Overflow`], with the index of the end of the number.
+    /// Like IEEE-754, this includes digits that overflow when rounding
+    /// toward zero, which are otherwise clamped to the largest finite
+    /// float. Special values, such as `inf`, are never errors. Defaults
+    /// to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{Error, FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .error_on_overflow(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.error_on_overflow(), true);
+    ///
+    /// let parse = |x: &str| f64::from_lexical_with_options::<STANDARD>(x.as_bytes(), &OPTIONS);
+    /// assert_eq!(parse("1e400"), Err(Error::Overflow(5)));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn error_on_overflow(mut self, error_on_overflow: bool) -> Self {
+        self.error_on_overflow = error_on_overflow;
+        self
+    }
+
+    /// Set if we reject non-zero values that underflow to zero.
+    ///
+    /// If set, parsing non-zero digits that round to zero returns
+    /// [`Error::Underflow`], with the index of the end of the number.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{Error, FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .error_on_underflow(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.error_on_underflow(), true);
+    ///
+    /// let parse = |x: &str| f64::from_lexical_with_options::<STANDARD>(x.as_bytes(), &OPTIONS);
+    /// assert_eq!(parse("1e-400"), Err(Error::Underflow(6)));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn error_on_underflow(mut self, error_on_underflow: bool) -> Self {
+        self.error_on_underflow = error_on_underflow;
+        self
+    }
+
+    /// Set if we reject values that are subnormal after rounding.
+    ///
+    /// If set, parsing digits that round to a subnormal float returns
+    /// [`Error::Underflow`], with the index of the end of the number.
+    /// Values that underflow to zero are only errors if
+    /// [`error_on_underflow`][Self::error_on_underflow] is set. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{Error, FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .error_on_subnormal(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.error_on_subnormal(), true);
+    ///
+    /// let parse = |x: &str| f64::from_lexical_with_options::<STANDARD>(x.as_bytes(), &OPTIONS);
+    /// assert_eq!(parse("1e-310"), Err(Error::Underflow(6)));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn error_on_subnormal(mut self, error_on_subnormal: bool) -> Self {
+        self.error_on_subnormal = error_on_subnormal;
+        self
+    }
+
+    /// Set if we accept SI prefixes in place of an exponent.
+    ///
+    /// If set, decimal floats may end with an SI prefix from `q` (`1e-30`)
+    /// to `Q` (`1e30`), such as `12.5k` or `3.3µ`, which scales the value
+    /// by the matching power of 10 without any rounding error. Micro may be
+    /// written as `µ` (U+00B5), `μ` (U+03BC), or `u`. A number cannot have
+    /// both an exponent and an SI prefix, and SI prefixes are not accepted
+    /// for other radixes. A prefix that matches the
+    /// [`exponent`][Self::exponent] character, such as `E` (exa) with the
+    /// default case-insensitive `e`, always starts an exponent, so `1E` is
+    /// an error. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .si_prefix(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.si_prefix(), true);
+    ///
+    /// let parse = |x: &str| f64::from_lexical_with_options::<STANDARD>(x.as_bytes(), &OPTIONS);
+    /// assert_eq!(parse("12.5k"), Ok(12.5e3));
+    /// assert_eq!(parse("3.3µ"), Ok(3.3e-6));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn si_prefix(mut self, si_prefix: bool) -> Self {
+        self.si_prefix = si_prefix;
+        self
+    }
+
     /// Set the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -641,6 +967,12 @@ impl OptionsBuilder {
     pub const fn build_unchecked(&self) -> Options {
         Options {
             lossy: self.lossy,
+            round_mode: self.round_mode,
+            require_exact: self.require_exact,
+            error_on_overflow: self.error_on_overflow,
+            error_on_underflow: self.error_on_underflow,
+            error_on_subnormal: self.error_on_subnormal,
+            si_prefix: self.si_prefix,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
@@ -760,6 +1092,19 @@ pub struct Options {
     /// Disable the use of arbitrary-precision arithmetic, and always
     /// return the results from the fast or intermediate path algorithms.
     lossy: bool,
+    /// Rounding mode for values that cannot be exactly represented.
+    round_mode: RoundMode,
+    /// Reject values that cannot be exactly represented.
+    require_exact: bool,
+    /// Reject finite values that overflow to infinity.
+    error_on_overflow: bool,
+    /// Reject non-zero values that underflow to zero.
+    error_on_underflow: bool,
+    /// Reject values that are subnormal after rounding.
+    error_on_subnormal: bool,
+
+    /// Accept SI prefixes, such as `k` or `µ`, in place of an exponent.
+    si_prefix: bool,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
@@ -823,6 +1168,119 @@ impl Options {
         self.lossy
     }
 
+    /// Get the rounding mode for values that cannot be exactly represented.
+    ///
+    /// Directed rounding is exact for all inputs, including values that
+    /// overflow or underflow: for example, rounding toward zero never
+    /// produces an infinity. Defaults to [`RoundMode::NearestTieEven`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::{Options, RoundMode};
+    ///
+    /// assert_eq!(Options::new().round_mode(), RoundMode::NearestTieEven);
+    /// ```
+    #[inline(always)]
+    pub const fn round_mode(&self) -> RoundMode {
+        self.round_mode
+    }
+
+    /// Get if we reject values that cannot be exactly represented.
+    ///
+    /// If set, parsing a value that must be rounded returns
+    /// [`Error::InexactFloat`]. Special values, such as `NaN`, are
+    /// always exact. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().require_exact(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn require_exact(&self) -> bool {
+        self.require_exact
+    }
+
+    /// Get if we reject finite values that overflow to infinity.
+    ///
+    /// If set, parsing digits that round to infinity returns
+    /// [`Error::Overflow`], with the index of the end of the number.
+    /// Like IEEE-754, this includes digits that overflow when rounding
+    /// toward zero, which are otherwise clamped to the largest finite
+    /// float. Special values, such as `inf`, are never errors. Defaults
+    /// to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().error_on_overflow(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn error_on_overflow(&self) -> bool {
+        self.error_on_overflow
+    }
+
+    /// Get if we reject non-zero values that underflow to zero.
+    ///
+    /// If set, parsing non-zero digits that round to zero returns
+    /// [`Error::Underflow`], with the index of the end of the number.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().error_on_underflow(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn error_on_underflow(&self) -> bool {
+        self.error_on_underflow
+    }
+
+    /// Get if we reject values that are subnormal after rounding.
+    ///
+    /// If set, parsing digits that round to a subnormal float returns
+    /// [`Error::Underflow`], with the index of the end of the number.
+    /// Values that underflow to zero are only errors if
+    /// [`error_on_underflow`][Self::error_on_underflow] is set. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().error_on_subnormal(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn error_on_subnormal(&self) -> bool {
+        self.error_on_subnormal
+    }
+
+    /// Get if we accept SI prefixes in place of an exponent.
+    ///
+    /// If set, decimal floats may end with an SI prefix from `q` (`1e-30`)
+    /// to `Q` (`1e30`), such as `12.5k` or `3.3µ`, which scales the value
+    /// by the matching power of 10. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().si_prefix(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn si_prefix(&self) -> bool {
+        self.si_prefix
+    }
+
     /// Get the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -970,6 +1428,12 @@ impl Options {
     pub const fn rebuild(&self) -> OptionsBuilder {
         OptionsBuilder {
             lossy: self.lossy,
+            round_mode: self.round_mode,
+            require_exact: self.require_exact,
+            error_on_overflow: self.error_on_overflow,
+            error_on_underflow: self.error_on_underflow,
+            error_on_subnormal: self.error_on_subnormal,
+            si_prefix: self.si_prefix,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
@@ -19,9 +19,11 @@ use lexical_util::error::Error;
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
 use lexical_util::iterator::{AsBytes, Bytes, DigitsIter, Iter};
+use lexical_util::options::parse_si_prefix;
 use lexical_util::result::Result;
 use lexical_util::step::u64_step;
 
+use crate::api::Exactness;
 #[cfg(any(feature = "compact", feature = "radix"))]
 use crate::bellerophon::bellerophon;
 #[cfg(feature = "power-of-two")]
@@ -31,7 +33,7 @@ use crate::float::{extended_to_float, ExtendedFloat80, LemireFloat};
 use crate::lemire::lemire;
 use crate::number::Number;
 use crate::options::Options;
-use crate::shared;
+use crate::shared::{self, RoundDirection};
 use crate::slow::slow_radix;
 
 // API
@@ -91,6 +93,50 @@ pub trait ParseFloat: LemireFloat {
         parse_partial::<Self, FORMAT>(bytes, options)
     }
 
+    /// Forward complete parser parameters to the backend, reporting if the
+    /// float is exact.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_exact<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, Exactness)> {
+        check_radix!(FORMAT);
+        parse_complete_exact::<Self, FORMAT>(bytes, options)
+    }
+
+    /// Forward partial parser parameters to the backend, reporting if the
+    /// float is exact.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_exact<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, Exactness, usize)> {
+        check_radix!(FORMAT);
+        parse_partial_exact::<Self, FORMAT>(bytes, options)
+    }
+
+    /// Forward complete parser parameters to the backend, returning the
+    /// floats below and above the digits.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_interval<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, Self)> {
+        check_radix!(FORMAT);
+        parse_complete_interval::<Self, FORMAT>(bytes, options)
+    }
+
+    /// Forward partial parser parameters to the backend, returning the
+    /// floats below and above the digits.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_interval<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, Self, usize)> {
+        check_radix!(FORMAT);
+        parse_partial_interval::<Self, FORMAT>(bytes, options)
+    }
+
     /// Forward complete parser parameters to the backend, using only the fast
     /// path.
     #[cfg_attr(not(feature = "compact"), inline(always))]
@@ -120,44 +166,7 @@ macro_rules! parse_float_impl {
 parse_float_impl! { f32 f64 }
 
 #[cfg(feature = "f16")]
-macro_rules! parse_float_as_f32 {
-    ($($t:ty)*) => ($(
-        impl ParseFloat for $t {
-            #[cfg_attr(not(feature = "compact"), inline(always))]
-            fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options)
-                -> Result<Self>
-            {
-                Ok(Self::from_f32(parse_complete::<f32, FORMAT>(bytes, options)?))
-            }
-
-            #[cfg_attr(not(feature = "compact"), inline(always))]
-            fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options)
-                -> Result<(Self, usize)>
-            {
-                let (float, count) = parse_partial::<f32, FORMAT>(bytes, options)?;
-                Ok((Self::from_f32(float), count))
-            }
-
-            #[cfg_attr(not(feature = "compact"), inline(always))]
-            fn fast_path_complete<const FORMAT: u128>(bytes: &[u8], options: &Options)
-                -> Result<Self>
-            {
-                Ok(Self::from_f32(fast_path_complete::<f32, FORMAT>(bytes, options)?))
-            }
-
-            #[cfg_attr(not(feature = "compact"), inline(always))]
-            fn fast_path_partial<const FORMAT: u128>(bytes: &[u8], options: &Options)
-                -> Result<(Self, usize)>
-            {
-                let (float, count) = fast_path_partial::<f32, FORMAT>(bytes, options)?;
-                Ok((Self::from_f32(float), count))
-            }
-        }
-    )*)
-}
-
-#[cfg(feature = "f16")]
-parse_float_as_f32! { bf16 f16 }
+parse_float_impl! { bf16 f16 }
 
 // PARSE
 // -----
@@ -263,25 +272,54 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
     // Parse our a small representation of our number.
     let num: Number<'_> =
         parse_number!(FORMAT, byte, is_negative, options, parse_complete_number, parse_special);
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok(value);
+    let direction = RoundDirection::new(options.round_mode(), is_negative);
+    let (value, is_overflow) =
+        number_to_float_checked::<F, FORMAT>(num, options.lossy(), direction);
+    check_range(num, value, is_overflow, options, bytes.len())?;
+    if options.require_exact() && exactness::<F, FORMAT>(num, value) != Exactness::Exact {
+        return Err(Error::InexactFloat(bytes.len()));
     }
-    // Now try the moderate path algorithm.
-    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
+    Ok(value)
+}
 
-    // Unable to correctly round the float using the fast or moderate algorithms.
-    // Fallback to a slower, but always correct algorithm. If we have
-    // lossy, we can't be here.
-    if fp.exp < 0 {
-        debug_assert!(!options.lossy(), "lossy algorithms never use slow algorithms");
-        // Undo the invalid extended float biasing.
-        fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+/// Parse a float from bytes using a complete parser, and report if the
+/// float exactly represents the digits.
+#[inline(always)]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_complete_exact<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, Exactness)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok((F::ZERO, Exactness::Exact));
+        }
     }
 
-    // Convert to native float and return result.
-    Ok(to_native!(F, fp, is_negative))
+    // Parse our a small representation of our number.
+    let num: Number<'_> = parse_number!(
+        FORMAT,
+        byte,
+        is_negative,
+        options,
+        parse_complete_number,
+        parse_special_exact
+    );
+    let direction = RoundDirection::new(options.round_mode(), is_negative);
+    let (value, is_overflow) =
+        number_to_float_checked::<F, FORMAT>(num, options.lossy(), direction);
+    check_range(num, value, is_overflow, options, bytes.len())?;
+    let exactness = exactness::<F, FORMAT>(num, value);
+    if options.require_exact() && exactness != Exactness::Exact {
+        return Err(Error::InexactFloat(bytes.len()));
+    }
+    Ok((value, exactness))
 }
 
 /// Parse a float using only the fast path as a complete parser.
@@ -337,25 +375,124 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
         parse_partial_number,
         parse_partial_special
     );
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok((value, count));
+    let direction = RoundDirection::new(options.round_mode(), is_negative);
+    let (value, is_overflow) =
+        number_to_float_checked::<F, FORMAT>(num, options.lossy(), direction);
+    check_range(num, value, is_overflow, options, count)?;
+    if options.require_exact() && exactness::<F, FORMAT>(num, value) != Exactness::Exact {
+        return Err(Error::InexactFloat(count));
     }
-    // Now try the moderate path algorithm.
-    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
+    Ok((value, count))
+}
 
-    // Unable to correctly round the float using the fast or moderate algorithms.
-    // Fallback to a slower, but always correct algorithm. If we have
-    // lossy, we can't be here.
-    if fp.exp < 0 {
-        debug_assert!(!options.lossy(), "lossy algorithms never use slow algorithms");
-        // Undo the invalid extended float biasing.
-        fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+/// Parse a float from bytes using a partial parser, and report if the
+/// float exactly represents the digits.
+#[inline(always)]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_partial_exact<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, Exactness, usize)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok((F::ZERO, Exactness::Exact, byte.cursor()));
+        }
     }
 
-    // Convert to native float and return result.
-    Ok((to_native!(F, fp, is_negative), count))
+    // Parse our a small representation of our number.
+    let (num, count) = parse_number!(
+        FORMAT,
+        byte,
+        is_negative,
+        options,
+        parse_partial_number,
+        parse_partial_special_exact
+    );
+    let direction = RoundDirection::new(options.round_mode(), is_negative);
+    let (value, is_overflow) =
+        number_to_float_checked::<F, FORMAT>(num, options.lossy(), direction);
+    check_range(num, value, is_overflow, options, count)?;
+    let exactness = exactness::<F, FORMAT>(num, value);
+    if options.require_exact() && exactness != Exactness::Exact {
+        return Err(Error::InexactFloat(count));
+    }
+    Ok((value, exactness, count))
+}
+
+/// Parse the floats below and above the digits using a complete parser.
+#[inline(always)]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_complete_interval<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, F)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok((F::ZERO, F::ZERO));
+        }
+    }
+
+    // Parse our a small representation of our number.
+    let num: Number<'_> = parse_number!(
+        FORMAT,
+        byte,
+        is_negative,
+        options,
+        parse_complete_number,
+        parse_special_interval
+    );
+    let (lower, upper) = number_to_interval::<F, FORMAT>(num);
+    if options.require_exact() && lower.to_bits() != upper.to_bits() {
+        return Err(Error::InexactFloat(bytes.len()));
+    }
+    Ok((lower, upper))
+}
+
+/// Parse the floats below and above the digits using a partial parser.
+#[inline(always)]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_partial_interval<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, F, usize)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok((F::ZERO, F::ZERO, byte.cursor()));
+        }
+    }
+
+    // Parse our a small representation of our number.
+    let (num, count) = parse_number!(
+        FORMAT,
+        byte,
+        is_negative,
+        options,
+        parse_partial_number,
+        parse_partial_special_interval
+    );
+    let (lower, upper) = number_to_interval::<F, FORMAT>(num);
+    if options.require_exact() && lower.to_bits() != upper.to_bits() {
+        return Err(Error::InexactFloat(count));
+    }
+    Ok((lower, upper, count))
 }
 
 /// Parse a float using only the fast path as a partial parser.
@@ -392,6 +529,122 @@ pub fn fast_path_partial<F: LemireFloat, const FORMAT: u128>(
 // PATHS
 // -----
 
+/// Convert the parsed number to a native float, rounding in `direction`.
+#[must_use]
+#[inline(always)]
+pub fn number_to_float<F: LemireFloat, const FORMAT: u128>(
+    num: Number,
+    lossy: bool,
+    direction: RoundDirection,
+) -> F {
+    number_to_float_checked::<F, FORMAT>(num, lossy, direction).0
+}
+
+/// Convert the parsed number to a native float, rounding in `direction`,
+/// and determine if the rounded digits overflowed.
+///
+/// Like IEEE-754, the digits overflow if they are larger than the largest
+/// finite float once rounded with an unbounded exponent. This is the same
+/// as rounding to infinity, unless rounding toward zero, where the float
+/// is clamped to the largest finite value.
+#[must_use]
+#[inline(always)]
+pub fn number_to_float_checked<F: LemireFloat, const FORMAT: u128>(
+    num: Number,
+    lossy: bool,
+    direction: RoundDirection,
+) -> (F, bool) {
+    // Try the fast-path algorithm, which is only correct for the
+    // directed rounding modes if the value is exact.
+    if direction == RoundDirection::Nearest || num.is_exact_fast_path::<F, FORMAT>() {
+        if let Some(value) = num.try_fast_path::<_, FORMAT>() {
+            return (value, false);
+        }
+    }
+    // Now try the moderate path algorithm.
+    let mut fp = moderate_path::<F, FORMAT>(&num, lossy, direction);
+
+    // Unable to correctly round the float using the fast or moderate algorithms.
+    // Fallback to a slower, but always correct algorithm. If we have
+    // lossy, we can't be here.
+    if fp.exp < 0 {
+        debug_assert!(!lossy, "lossy algorithms never use slow algorithms");
+        // Undo the invalid extended float biasing.
+        fp.exp -= shared::INVALID_FP;
+        fp = slow_path::<F, FORMAT>(num, fp, direction);
+    }
+    let is_overflow = fp.exp >= F::INFINITE_POWER;
+    if direction != RoundDirection::Nearest {
+        shared::clamp_directed::<F>(&mut fp, direction, num.mantissa == 0);
+    }
+
+    // Convert to native float and return result.
+    let is_negative = num.is_negative;
+    (to_native!(F, fp, is_negative), is_overflow)
+}
+
+/// Check if the parsed float overflowed or underflowed, if these are errors.
+///
+/// Only finite digits can overflow or underflow, so special values are
+/// never checked. `is_overflow` is if the rounded digits overflowed, which
+/// is required since rounding toward zero clamps the float.
+#[inline(always)]
+#[allow(clippy::if_same_then_else)] // reason = "more idiomatic"
+pub fn check_range<F: LemireFloat>(
+    num: Number,
+    value: F,
+    is_overflow: bool,
+    options: &Options,
+    index: usize,
+) -> Result<()> {
+    if options.error_on_overflow() && is_overflow {
+        Err(Error::Overflow(index))
+    } else if options.error_on_underflow() && value == F::ZERO && num.mantissa != 0 {
+        Err(Error::Underflow(index))
+    } else if options.error_on_subnormal() && value.is_denormal() && value != F::ZERO {
+        Err(Error::Underflow(index))
+    } else {
+        Ok(())
+    }
+}
+
+/// Get the largest float below and the smallest float above the digits.
+///
+/// The digits lie exactly between the floats rounded toward and away from
+/// zero, unless both are the same float. This is rarely required, so we
+/// just parse the number in both directed rounding modes.
+#[must_use]
+#[inline(always)]
+pub fn number_to_interval<F: LemireFloat, const FORMAT: u128>(num: Number) -> (F, F) {
+    let toward = number_to_float::<F, FORMAT>(num, false, RoundDirection::TowardZero);
+    if num.mantissa == 0 || num.is_exact_fast_path::<F, FORMAT>() {
+        return (toward, toward);
+    }
+    let away = number_to_float::<F, FORMAT>(num, false, RoundDirection::AwayFromZero);
+    if num.is_negative {
+        (away, toward)
+    } else {
+        (toward, away)
+    }
+}
+
+/// Determine how the parsed float compares to the exact value of the digits.
+#[must_use]
+#[inline(always)]
+pub fn exactness<F: LemireFloat, const FORMAT: u128>(num: Number, value: F) -> Exactness {
+    if num.mantissa == 0 || num.is_exact_fast_path::<F, FORMAT>() {
+        return Exactness::Exact;
+    }
+    let (lower, upper) = number_to_interval::<F, FORMAT>(num);
+    if lower.to_bits() == upper.to_bits() {
+        Exactness::Exact
+    } else if value <= lower {
+        Exactness::RoundedDown
+    } else {
+        Exactness::RoundedUp
+    }
+}
+
 /// Wrapper for different moderate-path algorithms.
 /// A return exponent of `-1` indicates an invalid value.
 #[must_use]
@@ -399,6 +652,7 @@ pub fn fast_path_partial<F: LemireFloat, const FORMAT: u128>(
 pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
     num: &Number,
     lossy: bool,
+    direction: RoundDirection,
 ) -> ExtendedFloat80 {
     #[cfg(feature = "compact")]
     {
@@ -407,15 +661,15 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
             let format = NumberFormat::<{ FORMAT }> {};
             if is_power_two!(format.mantissa_radix()) {
                 // Implement the power-of-two backends.
-                binary::<F, FORMAT>(num, lossy)
+                binary::<F, FORMAT>(num, lossy, direction)
             } else {
-                bellerophon::<F, FORMAT>(num, lossy)
+                bellerophon::<F, FORMAT>(num, lossy, direction)
             }
         }
 
         #[cfg(not(feature = "power-of-two"))]
         {
-            bellerophon::<F, FORMAT>(num, lossy)
+            bellerophon::<F, FORMAT>(num, lossy, direction)
         }
     }
 
@@ -426,12 +680,12 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
             let format = NumberFormat::<{ FORMAT }> {};
             let radix = format.mantissa_radix();
             if radix == 10 {
-                lemire::<F>(num, lossy)
+                lemire::<F>(num, lossy, direction)
             } else if is_power_two!(radix) {
                 // Implement the power-of-two backends.
-                binary::<F, FORMAT>(num, lossy)
+                binary::<F, FORMAT>(num, lossy, direction)
             } else {
-                bellerophon::<F, FORMAT>(num, lossy)
+                bellerophon::<F, FORMAT>(num, lossy, direction)
             }
         }
 
@@ -441,16 +695,16 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
             let radix = format.mantissa_radix();
             debug_assert!(matches!(radix, 2 | 4 | 8 | 10 | 16 | 32));
             if radix == 10 {
-                lemire::<F>(num, lossy)
+                lemire::<F>(num, lossy, direction)
             } else {
                 // Implement the power-of-two backends.
-                binary::<F, FORMAT>(num, lossy)
+                binary::<F, FORMAT>(num, lossy, direction)
             }
         }
 
         #[cfg(not(feature = "power-of-two"))]
         {
-            lemire::<F>(num, lossy)
+            lemire::<F>(num, lossy, direction)
         }
     }
 }
@@ -462,19 +716,20 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
 pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
+    direction: RoundDirection,
 ) -> ExtendedFloat80 {
     #[cfg(not(feature = "power-of-two"))]
     {
-        slow_radix::<F, FORMAT>(num, fp)
+        slow_radix::<F, FORMAT>(num, fp, direction)
     }
 
     #[cfg(feature = "power-of-two")]
     {
         let format = NumberFormat::<{ FORMAT }> {};
         if is_power_two!(format.mantissa_radix()) {
-            slow_binary::<F, FORMAT>(num)
+            slow_binary::<F, FORMAT>(num, direction)
         } else {
-            slow_radix::<F, FORMAT>(num, fp)
+            slow_radix::<F, FORMAT>(num, fp, direction)
         }
     }
 }
@@ -660,6 +915,17 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
     let has_exponent = byte
         .first_is(exponent_character, format.case_sensitive_exponent() && cfg!(feature = "format"));
 
+    // SI prefixes replace the exponent. A prefix that matches the exponent
+    // character, like `E` (exa) with the default exponent, is ambiguous,
+    // so it always starts an exponent.
+    let is_si_prefix =
+        options.si_prefix() && format.mantissa_radix() == 10 && format.exponent_base() == 10;
+    let si_prefix = if is_si_prefix && !has_exponent {
+        parse_si_prefix(byte.as_slice())
+    } else {
+        None
+    };
+
     // check to see if we have any invalid leading zeros
     n_digits += n_after_dot;
     if format.required_mantissa_digits()
@@ -717,6 +983,11 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
             explicit_exponent
         };
         exponent += explicit_exponent;
+    } else if let Some((power, length)) = si_prefix {
+        // SAFETY: safe since the prefix is `length` bytes from the buffer.
+        unsafe { byte.step_by_unchecked(length) };
+        explicit_exponent = power as i64;
+        exponent += explicit_exponent;
     } else if cfg!(feature = "format") && format.required_exponent_notation() {
         return Err(Error::MissingExponent(byte.cursor()));
     }
@@ -1058,3 +1329,71 @@ where
     }
     None
 }
+
+/// Parse a partial representation of a special, non-finite float.
+///
+/// Special floats are always exact.
+#[must_use]
+#[inline(always)]
+pub fn parse_partial_special_exact<F, const FORMAT: u128>(
+    byte: Bytes<FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Option<(F, Exactness, usize)>
+where
+    F: LemireFloat,
+{
+    let (float, count) = parse_partial_special::<F, FORMAT>(byte, is_negative, options)?;
+    Some((float, Exactness::Exact, count))
+}
+
+/// Try to parse a special, non-finite float.
+///
+/// Special floats are always exact.
+#[must_use]
+#[inline(always)]
+pub fn parse_special_exact<F, const FORMAT: u128>(
+    byte: Bytes<FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Option<(F, Exactness)>
+where
+    F: LemireFloat,
+{
+    let float = parse_special::<F, FORMAT>(byte, is_negative, options)?;
+    Some((float, Exactness::Exact))
+}
+
+/// Parse a partial representation of a special, non-finite float.
+///
+/// Special floats are always exact, so both bounds are the same.
+#[must_use]
+#[inline(always)]
+pub fn parse_partial_special_interval<F, const FORMAT: u128>(
+    byte: Bytes<FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Option<(F, F, usize)>
+where
+    F: LemireFloat,
+{
+    let (float, count) = parse_partial_special::<F, FORMAT>(byte, is_negative, options)?;
+    Some((float, float, count))
+}
+
+/// Try to parse a special, non-finite float.
+///
+/// Special floats are always exact, so both bounds are the same.
+#[must_use]
+#[inline(always)]
+pub fn parse_special_interval<F, const FORMAT: u128>(
+    byte: Bytes<FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Option<(F, F)>
+where
+    F: LemireFloat,
+{
+    let float = parse_special::<F, FORMAT>(byte, is_negative, options)?;
+    Some((float, float))
+}
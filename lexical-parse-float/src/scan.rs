@@ -0,0 +1,288 @@
+//! Scan numbers into their components without converting them.
+//!
+//! This validates and classifies numbers using exactly the same rules as
+//! the float parsers for a given number format, and returns the bytes of
+//! each component of the number, such as the integer, fraction, and
+//! exponent digits. This is useful for lexers, which need to find and
+//! check numeric literals but may defer, or never need, the conversion.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_float::{scan, Options, TokenKind};
+//! use lexical_parse_float::format::STANDARD;
+//!
+//! let options = Options::new();
+//! let token = scan::scan_partial::<{ STANDARD }>(b"-12.5e+3 + x", &options).unwrap();
+//! assert_eq!(token.kind(), TokenKind::Float);
+//! assert_eq!(token.length(), 8);
+//! assert_eq!(token.sign(), b"-");
+//! assert_eq!(token.integer(), b"12");
+//! assert_eq!(token.fraction(), Some(&b"5"[..]));
+//! assert_eq!(token.exponent_sign(), b"+");
+//! assert_eq!(token.exponent(), Some(&b"3"[..]));
+//! ```
+
+use lexical_util::error::Error;
+use lexical_util::format::NumberFormat;
+use lexical_util::iterator::{AsBytes, DigitsIter, Iter};
+use lexical_util::result::Result;
+
+use crate::number::Number;
+use crate::options::Options;
+use crate::parse::{
+    parse_complete_number,
+    parse_digits,
+    parse_exponent_sign,
+    parse_mantissa_sign,
+    parse_partial_number,
+    parse_positive_special,
+};
+
+/// The kind of a scanned number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenKind {
+    /// A number without a decimal point or an exponent.
+    Integer,
+
+    /// A number with a decimal point or an exponent.
+    Float,
+
+    /// A special, non-finite number, such as `NaN` or `Infinity`.
+    Special,
+}
+
+/// A scanned number, with the bytes of each component of the number.
+///
+/// The components are the bytes from the input, so they may contain
+/// digit separators or leading zeros if the format allows them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Token<'a> {
+    /// The kind of number.
+    kind: TokenKind,
+    /// All the bytes of the number.
+    bytes: &'a [u8],
+    /// The sign of the number.
+    sign: &'a [u8],
+    /// The base prefix, such as `0x`.
+    prefix: &'a [u8],
+    /// The integer digits.
+    integer: &'a [u8],
+    /// The fraction digits, if there is a decimal point.
+    fraction: Option<&'a [u8]>,
+    /// The sign of the exponent.
+    exponent_sign: &'a [u8],
+    /// The exponent digits, if there is an exponent.
+    exponent: Option<&'a [u8]>,
+    /// The base suffix.
+    suffix: &'a [u8],
+    /// The special string.
+    special: Option<&'a [u8]>,
+}
+
+impl<'a> Token<'a> {
+    /// Get the kind of number.
+    #[inline(always)]
+    pub const fn kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    /// Get the number of bytes in the number.
+    #[inline(always)]
+    pub const fn length(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Get all the bytes of the number.
+    #[inline(always)]
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Get if the number is negative.
+    #[inline(always)]
+    pub fn is_negative(&self) -> bool {
+        self.sign == b"-"
+    }
+
+    /// Get the sign of the number, which is empty if there is no sign.
+    #[inline(always)]
+    pub const fn sign(&self) -> &'a [u8] {
+        self.sign
+    }
+
+    /// Get the base prefix, such as `0x`, which is empty if there is
+    /// no prefix.
+    #[inline(always)]
+    pub const fn prefix(&self) -> &'a [u8] {
+        self.prefix
+    }
+
+    /// Get the integer digits, which is empty for special numbers.
+    #[inline(always)]
+    pub const fn integer(&self) -> &'a [u8] {
+        self.integer
+    }
+
+    /// Get the fraction digits, or `None` if there is no decimal point.
+    #[inline(always)]
+    pub const fn fraction(&self) -> Option<&'a [u8]> {
+        self.fraction
+    }
+
+    /// Get the sign of the exponent, which is empty if there is no sign.
+    #[inline(always)]
+    pub const fn exponent_sign(&self) -> &'a [u8] {
+        self.exponent_sign
+    }
+
+    /// Get the exponent digits, or `None` if there is no exponent.
+    ///
+    /// This does not include the exponent character or sign.
+    #[inline(always)]
+    pub const fn exponent(&self) -> Option<&'a [u8]> {
+        self.exponent
+    }
+
+    /// Get the base suffix, which is empty if there is no suffix.
+    #[inline(always)]
+    pub const fn suffix(&self) -> &'a [u8] {
+        self.suffix
+    }
+
+    /// Get the special string, or `None` if the number is not special.
+    #[inline(always)]
+    pub const fn special(&self) -> Option<&'a [u8]> {
+        self.special
+    }
+}
+
+/// Scan a number from bytes, requiring the entire input to be the number.
+///
+/// # Errors
+///
+/// If the input is not a valid number, with the same error as the float
+/// parsers.
+#[inline]
+pub fn scan_complete<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<Token<'a>> {
+    scan::<FORMAT, false>(bytes, options)
+}
+
+/// Scan a number from the start of bytes, stopping at the first byte that
+/// cannot be part of the number.
+///
+/// # Errors
+///
+/// If the input does not start with a valid number, with the same error as
+/// the float parsers.
+#[inline]
+pub fn scan_partial<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<Token<'a>> {
+    scan::<FORMAT, true>(bytes, options)
+}
+
+/// Scan a number, using the complete or partial parsers.
+fn scan<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<Token<'a>> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    }
+
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    parse_mantissa_sign(&mut byte)?;
+    let sign_end = byte.cursor();
+    let mut token = Token {
+        kind: TokenKind::Integer,
+        bytes: &bytes[..sign_end],
+        sign: &bytes[..sign_end],
+        prefix: &[],
+        integer: &[],
+        fraction: None,
+        exponent_sign: &[],
+        exponent: None,
+        suffix: &[],
+        special: None,
+    };
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            token.bytes = &bytes[..byte.cursor()];
+            return Ok(token);
+        }
+    }
+
+    // Scan the number, and if it isn't valid, try a special.
+    let result = if IS_PARTIAL {
+        parse_partial_number::<FORMAT>(byte.clone(), false, options)
+    } else {
+        parse_complete_number::<FORMAT>(byte.clone(), false, options).map(|num| (num, bytes.len()))
+    };
+    let (num, end): (Number<'a>, usize) = match result {
+        Ok(result) => result,
+        Err(error) => {
+            // The value of the special is unused, we only need its length.
+            return match parse_positive_special::<f64, FORMAT>(byte, options) {
+                Some((_, end)) if IS_PARTIAL || end == bytes.len() => {
+                    token.kind = TokenKind::Special;
+                    token.bytes = &bytes[..end];
+                    token.special = Some(&bytes[sign_end..end]);
+                    Ok(token)
+                },
+                _ => Err(error),
+            };
+        },
+    };
+
+    // Find the components from the slices of the digits.
+    let integer_start = offset(bytes, num.integer);
+    let mut exponent_start = integer_start + num.integer.len();
+    if let Some(fraction) = num.fraction {
+        exponent_start = offset(bytes, fraction) + fraction.len();
+    }
+    token.bytes = &bytes[..end];
+    token.prefix = &bytes[sign_end..integer_start];
+    token.integer = num.integer;
+    token.fraction = num.fraction;
+    token.suffix = &bytes[exponent_start..end];
+
+    // Re-scan the exponent, since only the digits are stored.
+    let exponent_character = options.exponent();
+    let is_cased = format.case_sensitive_exponent() && cfg!(feature = "format");
+    let has_exponent = bytes.get(exponent_start).map_or(false, |&c| {
+        c == exponent_character || (!is_cased && c.eq_ignore_ascii_case(&exponent_character))
+    });
+    if exponent_start < end && has_exponent {
+        // SAFETY: safe, since `exponent_start < end <= bytes.len()`.
+        unsafe { byte.set_cursor(exponent_start + 1) };
+        parse_exponent_sign(&mut byte)?;
+        let exponent_sign_end = byte.cursor();
+        parse_digits(byte.exponent_iter(), format.exponent_radix(), |_| ());
+        let exponent_end = byte.cursor();
+        token.exponent_sign = &bytes[exponent_start + 1..exponent_sign_end];
+        token.exponent = Some(&bytes[exponent_sign_end..exponent_end]);
+        token.suffix = &bytes[exponent_end..end];
+    }
+    if token.fraction.is_some() || token.exponent.is_some() {
+        token.kind = TokenKind::Float;
+    }
+
+    Ok(token)
+}
+
+/// Get the index of a subslice within the bytes.
+#[inline(always)]
+fn offset(bytes: &[u8], slice: &[u8]) -> usize {
+    debug_assert!(bytes.as_ptr_range().contains(&slice.as_ptr()) || slice.is_empty());
+    slice.as_ptr() as usize - bytes.as_ptr() as usize
+}
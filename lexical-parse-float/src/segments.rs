@@ -0,0 +1,26 @@
+//! Parse floats from input split across non-contiguous segments.
+//!
+//! This supports input such as ring buffers, ropes, or scatter/gather
+//! I/O buffers, where the number may span multiple segments. Numbers
+//! contained within a single segment are parsed in place, so the optimized
+//! algorithms for contiguous input are still used, and only the bytes of a
+//! number spanning multiple segments are copied.
+
+use lexical_util::from_lexical_segments;
+use lexical_util::incremental::Syntax;
+
+use crate::api::FromLexicalWithOptions;
+use crate::options::Options;
+
+/// Get the syntax of a float with the options, to find where it ends.
+#[inline(always)]
+#[allow(clippy::extra_unused_type_parameters)] // reason="same signature as for integers"
+pub(crate) fn syntax<T, const FORMAT: u128>(options: &Options) -> Syntax {
+    Syntax::float::<FORMAT>(options.decimal_point(), options.exponent(), [
+        options.nan_string(),
+        options.inf_string(),
+        options.infinity_string(),
+    ])
+}
+
+from_lexical_segments!("lexical_parse_float", 12345.0, f64, syntax);
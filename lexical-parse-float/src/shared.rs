@@ -8,6 +8,7 @@ use lexical_util::num::AsPrimitive;
 
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::mask::{lower_n_halfway, lower_n_mask};
+use crate::options::RoundMode;
 
 // 8 DIGIT
 // -------
@@ -164,6 +165,38 @@ where
 // ROUNDING
 // --------
 
+/// The direction to round the significant digits of a float.
+///
+/// Our algorithms only operate on the magnitude of the float, so the
+/// rounding mode and the sign are combined into a single direction.
+/// Like IEEE-754 arithmetic, the magnitude still underflows to 0 and
+/// overflows to infinity, which is then clamped with [`clamp_directed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundDirection {
+    /// Round to the nearest float, tie-even.
+    Nearest,
+    /// Round the magnitude up, away from zero.
+    AwayFromZero,
+    /// Round the magnitude down, toward zero.
+    TowardZero,
+}
+
+impl RoundDirection {
+    /// Get the rounding direction from the rounding mode and the sign.
+    #[must_use]
+    #[inline(always)]
+    pub const fn new(mode: RoundMode, is_negative: bool) -> Self {
+        match mode {
+            RoundMode::NearestTieEven => Self::Nearest,
+            RoundMode::TowardZero => Self::TowardZero,
+            RoundMode::TowardPositive if is_negative => Self::TowardZero,
+            RoundMode::TowardPositive => Self::AwayFromZero,
+            RoundMode::TowardNegative if is_negative => Self::AwayFromZero,
+            RoundMode::TowardNegative => Self::TowardZero,
+        }
+    }
+}
+
 /// Round an extended-precision float to the nearest machine float.
 ///
 /// Shifts the significant digits into place, adjusts the exponent,
@@ -284,3 +317,46 @@ pub fn round_down(fp: &mut ExtendedFloat80, shift: i32) {
     };
     fp.exp += shift;
 }
+
+/// Round our significant digits into place, rounding up if any non-zero
+/// bits are truncated.
+///
+/// `is_truncated` is if any non-zero bits were already truncated from
+/// the extended-precision float.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn round_up(fp: &mut ExtendedFloat80, shift: i32, is_truncated: bool) {
+    // Ensure we've already handled denormal values that underflow.
+    debug_assert!(shift <= 64);
+
+    let mask = lower_n_mask(shift as u64);
+    let is_above = fp.mant & mask != 0;
+    fp.mant = match shift == 64 {
+        true => 0,
+        false => fp.mant >> shift,
+    };
+    fp.exp += shift;
+    fp.mant += (is_above || is_truncated) as u64;
+}
+
+/// Clamp a float rounded with a directed rounding mode to the finite range.
+///
+/// Rounding toward zero never overflows to infinity, and rounding away
+/// from zero never underflows to 0 if any significant digits are
+/// non-zero.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn clamp_directed<F: RawFloat>(
+    fp: &mut ExtendedFloat80,
+    direction: RoundDirection,
+    is_zero: bool,
+) {
+    match direction {
+        RoundDirection::TowardZero if fp.exp >= F::INFINITE_POWER => {
+            fp.mant = F::MANTISSA_MASK.as_u64();
+            fp.exp = F::INFINITE_POWER - 1;
+        },
+        RoundDirection::AwayFromZero if !is_zero && fp.mant == 0 && fp.exp == 0 => {
+            fp.mant = 1;
+        },
+        _ => (),
+    }
+}
@@ -23,7 +23,7 @@ use crate::bigint::{Bigint, Limb};
 use crate::float::{extended_to_float, ExtendedFloat80, RawFloat};
 use crate::limits::{u32_power_limit, u64_power_limit};
 use crate::number::Number;
-use crate::shared;
+use crate::shared::{self, RoundDirection};
 
 // ALGORITHM
 // ---------
@@ -44,11 +44,15 @@ use crate::shared;
 /// any value before or equal to `16777217.0` must be rounded down
 /// to `16777216.0`. These near-halfway conversions therefore may require
 /// a large number of digits to unambiguously determine how to round.
+///
+/// For the directed rounding modes, the same is true for values near,
+/// or exactly equal to, a machine float.
 #[must_use]
 #[allow(clippy::unwrap_used)] // reason = "none is a developer error"
 pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
+    direction: RoundDirection,
 ) -> ExtendedFloat80 {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
@@ -74,10 +78,10 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         if let Some(max_digits) = F::max_digits(format.radix()) {
             // Can use our finite number of digit algorithm.
-            digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
+            digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits, direction)
         } else {
             // Fallback to infinite digits.
-            byte_comp::<F, FORMAT>(num, fp, sci_exp)
+            byte_comp::<F, FORMAT>(num, fp, sci_exp, direction)
         }
     }
 
@@ -85,7 +89,7 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         // Can use our finite number of digit algorithm.
         let max_digits = F::max_digits(format.radix()).unwrap();
-        digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
+        digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits, direction)
     }
 }
 
@@ -104,14 +108,18 @@ pub fn digit_comp<F: RawFloat, const FORMAT: u128>(
     fp: ExtendedFloat80,
     sci_exp: i32,
     max_digits: usize,
+    direction: RoundDirection,
 ) -> ExtendedFloat80 {
+    // NOTE: Truncating the digits is also valid for the directed rounding
+    // modes, since a machine float never has more significant digits than
+    // a halfway point between 2 floats.
     let (bigmant, digits) = parse_mantissa::<FORMAT>(num, max_digits);
     // This can't underflow, since `digits` is at most `max_digits`.
     let exponent = sci_exp + 1 - digits as i32;
     if exponent >= 0 {
-        positive_digit_comp::<F, FORMAT>(bigmant, exponent)
+        positive_digit_comp::<F, FORMAT>(bigmant, exponent, direction)
     } else {
-        negative_digit_comp::<F, FORMAT>(bigmant, fp, exponent)
+        negative_digit_comp::<F, FORMAT>(bigmant, fp, exponent, direction)
     }
 }
 
@@ -125,6 +133,7 @@ pub fn digit_comp<F: RawFloat, const FORMAT: u128>(
 pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
     mut bigmant: Bigint,
     exponent: i32,
+    direction: RoundDirection,
 ) -> ExtendedFloat80 {
     let format = NumberFormat::<{ FORMAT }> {};
 
@@ -145,11 +154,17 @@ pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
     };
 
     // Shift the digits into position and determine if we need to round-up.
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
-            is_above || (is_halfway && is_truncated) || (is_odd && is_halfway)
-        });
-    });
+    match direction {
+        RoundDirection::Nearest => shared::round::<F, _>(&mut fp, |f, s| {
+            shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
+                is_above || (is_halfway && is_truncated) || (is_odd && is_halfway)
+            });
+        }),
+        RoundDirection::AwayFromZero => {
+            shared::round::<F, _>(&mut fp, |f, s| shared::round_up(f, s, is_truncated));
+        },
+        RoundDirection::TowardZero => shared::round::<F, _>(&mut fp, shared::round_down),
+    }
     fp
 }
 
@@ -174,6 +189,10 @@ pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
 ///
 /// This allows us to compare both floats using integers efficiently
 /// without any loss of precision.
+///
+/// For the directed rounding modes, we instead compare the real digits
+/// to `c`, the float closest to them, and step 1 ULP in the rounding
+/// direction if they are not equal.
 #[must_use]
 #[inline(always)]
 #[allow(clippy::match_bool)] // reason = "simplifies documentation"
@@ -184,6 +203,7 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     bigmant: Bigint,
     mut fp: ExtendedFloat80,
     exponent: i32,
+    direction: RoundDirection,
 ) -> ExtendedFloat80 {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
@@ -197,13 +217,8 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     let real_exp = exponent;
     debug_assert!(real_exp < 0, "algorithm only works with negative numbers");
 
-    // Round down our extended-precision float and calculate `b`.
-    let mut b = fp;
-    shared::round::<F, _>(&mut b, shared::round_down);
-    let b = extended_to_float::<F>(b);
-
-    // Get the significant digits and the binary exponent for `b+h`.
-    let theor = bh(b);
+    // Get the significant digits and the binary exponent for `b+h` or `c`.
+    let theor = theoretical::<F>(&mut fp, direction);
     let mut theor_digits = Bigint::from_u64(theor.mant);
     let theor_exp = theor.exp;
 
@@ -240,20 +255,9 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
         real_digits.pow(2, (-binary_exp) as u32).unwrap();
     }
 
-    // Compare our theoretical and real digits and round nearest, tie even.
+    // Compare our theoretical and real digits and round.
     let ord = real_digits.data.cmp(&theor_digits.data);
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
-            // Can ignore `is_halfway` and `is_above`, since those were
-            // calculates using less significant digits.
-            match ord {
-                cmp::Ordering::Greater => true,
-                cmp::Ordering::Less => false,
-                cmp::Ordering::Equal if is_odd => true,
-                cmp::Ordering::Equal => false,
-            }
-        });
-    });
+    round_from_ordering::<F>(&mut fp, ord, direction);
     fp
 }
 
@@ -595,6 +599,7 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
     number: Number,
     mut fp: ExtendedFloat80,
     sci_exp: i32,
+    direction: RoundDirection,
 ) -> ExtendedFloat80 {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
@@ -602,13 +607,8 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
 
     let format = NumberFormat::<FORMAT> {};
 
-    // Round down our extended-precision float and calculate `b`.
-    let mut b = fp;
-    shared::round::<F, _>(&mut b, shared::round_down);
-    let b = extended_to_float::<F>(b);
-
-    // Calculate `b+h` to create a ratio for our theoretical digits.
-    let theor = Bigfloat::from_float(bh::<F>(b));
+    // Calculate `b+h` or `c` to create a ratio for our theoretical digits.
+    let theor = Bigfloat::from_float(theoretical::<F>(&mut fp, direction));
 
     // Now, create a scaling factor for the digit count.
     let mut factor = Bigfloat::from_u32(1);
@@ -663,20 +663,9 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
         }
     }
 
-    // Compare our theoretical and real digits and round nearest, tie even.
+    // Compare our theoretical and real digits and round.
     let ord = compare_bytes::<FORMAT>(number, num, den);
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
-            // Can ignore `is_halfway` and `is_above`, since those were
-            // calculates using less significant digits.
-            match ord {
-                cmp::Ordering::Greater => true,
-                cmp::Ordering::Less => false,
-                cmp::Ordering::Equal if is_odd => true,
-                cmp::Ordering::Equal => false,
-            }
-        });
-    });
+    round_from_ordering::<F>(&mut fp, ord, direction);
     fp
 }
 
@@ -782,6 +771,75 @@ pub fn bh<F: RawFloat>(float: F) -> ExtendedFloat80 {
     }
 }
 
+/// Calculate the theoretical digits to compare the real digits to.
+///
+/// For round-nearest, this is `b+h`, where `b` is the extended-precision
+/// float rounded-down, and the rounding will occur later. For the directed
+/// rounding modes, this rounds `fp` in place to `c`, the closest float,
+/// and uses the exact value of `c`.
+#[must_use]
+#[inline(always)]
+pub fn theoretical<F: RawFloat>(
+    fp: &mut ExtendedFloat80,
+    direction: RoundDirection,
+) -> ExtendedFloat80 {
+    if direction == RoundDirection::Nearest {
+        let mut b = *fp;
+        shared::round::<F, _>(&mut b, shared::round_down);
+        bh(extended_to_float::<F>(b))
+    } else {
+        shared::round::<F, _>(fp, |f, s| {
+            shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
+                is_above || (is_odd && is_halfway)
+            });
+        });
+        b(extended_to_float::<F>(*fp))
+    }
+}
+
+/// Round the float from the ordering of the real digits to the theoretical
+/// digits.
+///
+/// For round-nearest, `fp` is the extended-precision float and the
+/// theoretical digits are `b+h`. For the directed rounding modes, `fp`
+/// has already been rounded to `c`, the theoretical digits, and the
+/// correctly rounded float is at most 1 ULP away.
+#[inline(always)]
+pub fn round_from_ordering<F: RawFloat>(
+    fp: &mut ExtendedFloat80,
+    ord: cmp::Ordering,
+    direction: RoundDirection,
+) {
+    if direction == RoundDirection::Nearest {
+        shared::round::<F, _>(fp, |f, s| {
+            shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
+                // Can ignore `is_halfway` and `is_above`, since those were
+                // calculates using less significant digits.
+                match ord {
+                    cmp::Ordering::Greater => true,
+                    cmp::Ordering::Less => false,
+                    cmp::Ordering::Equal if is_odd => true,
+                    cmp::Ordering::Equal => false,
+                }
+            });
+        });
+        return;
+    }
+
+    // Step the bits of the float, which carries into the exponent.
+    // Never step past infinity, since the next value would be a NaN.
+    let bits = fp.mant | ((fp.exp as u64) << F::MANTISSA_SIZE);
+    let bits = match (ord, direction) {
+        (cmp::Ordering::Greater, RoundDirection::AwayFromZero) if fp.exp < F::INFINITE_POWER => {
+            bits + 1
+        },
+        (cmp::Ordering::Less, RoundDirection::TowardZero) => bits - 1,
+        _ => bits,
+    };
+    fp.mant = bits & F::MANTISSA_MASK.as_u64();
+    fp.exp = (bits >> F::MANTISSA_SIZE) as i32;
+}
+
 // NOTE: There will never be binary factors here.
 
 /// Calculate the integral ceiling of the binary factor from a basen number.
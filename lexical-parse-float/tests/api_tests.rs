@@ -1,7 +1,19 @@
+#![allow(clippy::excessive_precision)]
+
 #[cfg(feature = "format")]
 use core::num;
 
-use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
+#[cfg(feature = "f16")]
+use lexical_parse_float::{bf16, f16};
+use lexical_parse_float::{
+    Exactness,
+    FromLexical,
+    FromLexicalExact,
+    FromLexicalInterval,
+    FromLexicalWithOptions,
+    Options,
+    RoundMode,
+};
 use lexical_util::error::Error;
 #[cfg(feature = "format")]
 use lexical_util::format;
@@ -172,6 +184,78 @@ fn f32_radix_test() {
     );
 }
 
+#[test]
+#[cfg(feature = "f16")]
+fn f16_decimal_test() {
+    let parse = |x| f16::from_lexical(x).map(f16::to_bits);
+
+    // Simple cases.
+    assert_eq!(Ok(0x0000), parse(b"0"));
+    assert_eq!(Ok(0x3C00), parse(b"1"));
+    assert_eq!(Ok(0xBC00), parse(b"-1"));
+    assert_eq!(Ok(0x3555), parse(b"0.333333333333"));
+    assert_eq!(Ok(0x7BFF), parse(b"65504"));
+
+    // Halfway cases, round to even.
+    assert_eq!(Ok(0x6800), parse(b"2049"));
+    assert_eq!(Ok(0x6802), parse(b"2051"));
+    assert_eq!(Ok(0x3C00), parse(b"1.00048828125"));
+    assert_eq!(Ok(0x3C02), parse(b"1.00146484375"));
+
+    // Near-halfway cases.
+    assert_eq!(Ok(0x3C00), parse(b"1.00048828124999999999"));
+    assert_eq!(Ok(0x3C01), parse(b"1.00048828125000000001"));
+    assert_eq!(Ok(0x6801), parse(b"2049.00000000000000000000001"));
+
+    // Subnormals.
+    assert_eq!(Ok(0x0001), parse(b"5.9604644775390625e-8"));
+    assert_eq!(Ok(0x0000), parse(b"2.98023223876953125e-8"));
+    assert_eq!(Ok(0x0001), parse(b"2.98023223876953126e-8"));
+    assert_eq!(Ok(0x0002), parse(b"1.4901161193847656e-7"));
+    assert_eq!(Ok(0x03FF), parse(b"6.097555160522461e-5"));
+
+    // Overflow.
+    assert_eq!(Ok(0x7BFF), parse(b"65519.99999999"));
+    assert_eq!(Ok(0x7C00), parse(b"65520"));
+    assert_eq!(Ok(0x7C00), parse(b"1e5"));
+    assert_eq!(Ok(0x0000), parse(b"1e-8"));
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn bf16_decimal_test() {
+    let parse = |x| bf16::from_lexical(x).map(bf16::to_bits);
+
+    // Simple cases.
+    assert_eq!(Ok(0x0000), parse(b"0"));
+    assert_eq!(Ok(0x3F80), parse(b"1"));
+    assert_eq!(Ok(0xBF80), parse(b"-1"));
+    assert_eq!(Ok(0x3EAB), parse(b"0.333333333333"));
+    assert_eq!(Ok(0x7F7F), parse(b"3.3895313892515355e38"));
+
+    // Halfway cases, round to even.
+    assert_eq!(Ok(0x4380), parse(b"257"));
+    assert_eq!(Ok(0x4382), parse(b"259"));
+    assert_eq!(Ok(0x3F80), parse(b"1.00390625"));
+    assert_eq!(Ok(0x3F82), parse(b"1.01171875"));
+
+    // Near-halfway cases, which would double-round through `f32`.
+    assert_eq!(Ok(0x3F80), parse(b"1.00390624999999999"));
+    assert_eq!(Ok(0x3F81), parse(b"1.00390625000000001"));
+    assert_eq!(Ok(0x4381), parse(b"257.000000000000000000001"));
+
+    // Subnormals.
+    assert_eq!(Ok(0x0001), parse(b"9.18354961579912115600575419704879435795832466228193376178712270530013483949005603790283203125e-41"));
+    assert_eq!(Ok(0x0000), parse(b"4.591774807899560578002877098524397178979162331140966880893561352650067419745028018951416015625e-41"));
+    assert_eq!(Ok(0x0001), parse(b"4.591774807899560578002877098524397178979162331140966880893561352650067419745028018951416015626e-41"));
+
+    // Overflow.
+    assert_eq!(Ok(0x7F7F), parse(b"339617752923046005526922703901628039167"));
+    assert_eq!(Ok(0x7F80), parse(b"339617752923046005526922703901628039168"));
+    assert_eq!(Ok(0x7F80), parse(b"1e39"));
+    assert_eq!(Ok(0x0000), parse(b"1e-42"));
+}
+
 #[test]
 fn parse_f32_test() {
     let parse = move |x| f32::from_lexical_partial(x);
@@ -668,6 +752,382 @@ fn parse_f64_lossy_test() {
     assert_eq!(Ok((1.2345e10, 9)), parse(b"1.2345e10"));
 }
 
+#[test]
+fn parse_f32_round_mode_test() {
+    const FORMAT: u128 = STANDARD;
+    const NEAREST: Options = Options::new();
+    const UP: Options = Options::builder().round_mode(RoundMode::TowardPositive).build_strict();
+    const DOWN: Options = Options::builder().round_mode(RoundMode::TowardNegative).build_strict();
+    const ZERO: Options = Options::builder().round_mode(RoundMode::TowardZero).build_strict();
+    let parse = move |x, o| f32::from_lexical_with_options::<FORMAT>(x, o).map(f32::to_bits);
+
+    // Exact values are unaffected by the rounding mode.
+    assert_eq!(Ok(0x3FC00000), parse(b"1.5", &ZERO));
+    assert_eq!(Ok(0x3FC00000), parse(b"1.5", &UP));
+
+    assert_eq!(Ok(0x3DCCCCCD), parse(b"0.1", &NEAREST));
+    assert_eq!(Ok(0x3DCCCCCD), parse(b"0.1", &UP));
+    assert_eq!(Ok(0x3DCCCCCC), parse(b"0.1", &DOWN));
+    assert_eq!(Ok(0x3DCCCCCC), parse(b"0.1", &ZERO));
+    assert_eq!(Ok(0xBDCCCCCC), parse(b"-0.1", &UP));
+    assert_eq!(Ok(0xBDCCCCCD), parse(b"-0.1", &DOWN));
+    assert_eq!(Ok(0xBDCCCCCC), parse(b"-0.1", &ZERO));
+
+    // Halfway cases.
+    assert_eq!(Ok(0x4B800000), parse(b"16777217", &NEAREST));
+    assert_eq!(Ok(0x4B800001), parse(b"16777217", &UP));
+    assert_eq!(Ok(0x4B800000), parse(b"16777217", &ZERO));
+    assert_eq!(Ok(0x1), parse(b"7.0064923216240861e-46", &NEAREST));
+    assert_eq!(Ok(0x1), parse(b"7.0064923216240861e-46", &UP));
+    assert_eq!(Ok(0x0), parse(b"7.0064923216240861e-46", &ZERO));
+
+    // Overflow and underflow.
+    assert_eq!(Ok(0x7F800000), parse(b"3.5e38", &NEAREST));
+    assert_eq!(Ok(0x7F800000), parse(b"3.5e38", &UP));
+    assert_eq!(Ok(0x7F7FFFFF), parse(b"3.5e38", &DOWN));
+    assert_eq!(Ok(0x7F7FFFFF), parse(b"3.5e38", &ZERO));
+    assert_eq!(Ok(0xFF800000), parse(b"-3.5e38", &DOWN));
+    assert_eq!(Ok(0xFF7FFFFF), parse(b"-3.5e38", &UP));
+    assert_eq!(Ok(0x0), parse(b"1e-46", &NEAREST));
+    assert_eq!(Ok(0x1), parse(b"1e-46", &UP));
+    assert_eq!(Ok(0x0), parse(b"1e-46", &ZERO));
+    assert_eq!(Ok(0x80000001), parse(b"-1e-46", &DOWN));
+    assert_eq!(Ok(0x80000000), parse(b"-1e-46", &UP));
+
+    // Zero is never rounded away from zero.
+    assert_eq!(Ok(0x0), parse(b"0.000", &UP));
+    assert_eq!(Ok(0x80000000), parse(b"-0e10", &DOWN));
+}
+
+#[test]
+fn parse_f64_round_mode_test() {
+    const FORMAT: u128 = STANDARD;
+    const NEAREST: Options = Options::new();
+    const UP: Options = Options::builder().round_mode(RoundMode::TowardPositive).build_strict();
+    const DOWN: Options = Options::builder().round_mode(RoundMode::TowardNegative).build_strict();
+    const ZERO: Options = Options::builder().round_mode(RoundMode::TowardZero).build_strict();
+    let parse = move |x, o| f64::from_lexical_with_options::<FORMAT>(x, o);
+
+    assert_eq!(Ok(1.5), parse(b"1.5", &DOWN));
+    assert_eq!(Ok(0.1), parse(b"0.1", &UP));
+    assert_eq!(Ok(0.09999999999999999), parse(b"0.1", &DOWN));
+    assert_eq!(Ok(0.09999999999999999), parse(b"0.1", &ZERO));
+    assert_eq!(Ok(-0.1), parse(b"-0.1", &DOWN));
+    assert_eq!(Ok(-0.09999999999999999), parse(b"-0.1", &UP));
+    assert_eq!(Ok(9007199254740994.0), parse(b"9007199254740993", &UP));
+    assert_eq!(Ok(9007199254740992.0), parse(b"9007199254740993", &DOWN));
+
+    // Overflow and underflow.
+    assert_eq!(Ok(f64::INFINITY), parse(b"1e400", &UP));
+    assert_eq!(Ok(f64::MAX), parse(b"1e400", &ZERO));
+    assert_eq!(Ok(f64::MAX), parse(b"1.7976931348623158e308", &DOWN));
+    assert_eq!(Ok(f64::INFINITY), parse(b"1.7976931348623158e308", &UP));
+    assert_eq!(Ok(5e-324), parse(b"1e-400", &UP));
+    assert_eq!(Ok(0.0), parse(b"1e-400", &DOWN));
+    assert_eq!(Ok(-5e-324), parse(b"-1e-400", &DOWN));
+
+    // Exactly halfway between 0 and the smallest denormal, requiring the slow path.
+    let halfway = b"2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324";
+    assert_eq!(Ok(0.0), parse(halfway, &NEAREST));
+    assert_eq!(Ok(5e-324), parse(halfway, &UP));
+    assert_eq!(Ok(0.0), parse(halfway, &ZERO));
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn parse_f16_round_mode_test() {
+    const FORMAT: u128 = STANDARD;
+    const UP: Options = Options::builder().round_mode(RoundMode::TowardPositive).build_strict();
+    const ZERO: Options = Options::builder().round_mode(RoundMode::TowardZero).build_strict();
+    let parse = move |x, o| f16::from_lexical_with_options::<FORMAT>(x, o).map(f16::to_bits);
+
+    assert_eq!(Ok(0x2E67), parse(b"0.1", &UP));
+    assert_eq!(Ok(0x2E66), parse(b"0.1", &ZERO));
+    assert_eq!(Ok(0x6801), parse(b"2049", &UP));
+    assert_eq!(Ok(0x6800), parse(b"2049", &ZERO));
+    assert_eq!(Ok(0x7C00), parse(b"65520", &UP));
+    assert_eq!(Ok(0x7BFF), parse(b"65520", &ZERO));
+    assert_eq!(Ok(0x1), parse(b"1e-8", &UP));
+    assert_eq!(Ok(0x0), parse(b"1e-8", &ZERO));
+}
+
+#[test]
+fn parse_f64_exact_test() {
+    let parse = f64::from_lexical_exact;
+    assert_eq!(Ok((0.0, Exactness::Exact)), parse(b"0"));
+    assert_eq!(Ok((0.0, Exactness::Exact)), parse(b"0.000e-500"));
+    assert_eq!(Ok((1.5, Exactness::Exact)), parse(b"1.5"));
+    assert_eq!(Ok((-0.125, Exactness::Exact)), parse(b"-0.125"));
+    assert_eq!(Ok((1e22, Exactness::Exact)), parse(b"1e22"));
+    assert_eq!(Ok((9007199254740992.0, Exactness::Exact)), parse(b"9007199254740992"));
+    assert_eq!(
+        Ok((5e-324, Exactness::RoundedUp)),
+        parse(b"4.9406564584124654417656879286822137236505980e-324")
+    );
+    assert_eq!(Ok((0.1, Exactness::RoundedUp)), parse(b"0.1"));
+    assert_eq!(Ok((-0.1, Exactness::RoundedDown)), parse(b"-0.1"));
+    assert_eq!(Ok((0.3, Exactness::RoundedDown)), parse(b"0.3"));
+    assert_eq!(Ok((1e23, Exactness::RoundedDown)), parse(b"1e23"));
+    assert_eq!(Ok((9007199254740992.0, Exactness::RoundedDown)), parse(b"9007199254740993"));
+    assert_eq!(Ok((f64::INFINITY, Exactness::RoundedUp)), parse(b"1e400"));
+    assert_eq!(Ok((0.0, Exactness::RoundedDown)), parse(b"1e-400"));
+    assert_eq!(Ok((-0.0, Exactness::RoundedUp)), parse(b"-1e-400"));
+    assert_eq!(Ok((1.0, Exactness::RoundedUp)), parse(b"0.99999999999999999999999999999999999999"));
+    assert_eq!(
+        Ok((1.0, Exactness::RoundedDown)),
+        parse(b"1.00000000000000000000000000000000000001")
+    );
+    assert!(matches!(parse(b"NaN"), Ok((x, Exactness::Exact)) if x.is_nan()));
+    assert_eq!(Ok((f64::INFINITY, Exactness::Exact)), parse(b"inf"));
+
+    assert_eq!(Ok((0.1, Exactness::RoundedUp, 3)), f64::from_lexical_partial_exact(b"0.1 "));
+    assert_eq!(Ok((0.5, Exactness::Exact, 3)), f64::from_lexical_partial_exact(b"0.5,"));
+
+    // Directed rounding modes are also reported.
+    const FORMAT: u128 = STANDARD;
+    const ZERO: Options = Options::builder().round_mode(RoundMode::TowardZero).build_strict();
+    let parse = move |x| f64::from_lexical_exact_with_options::<FORMAT>(x, &ZERO);
+    assert_eq!(Ok((0.09999999999999999, Exactness::RoundedDown)), parse(b"0.1"));
+    assert_eq!(Ok((-0.09999999999999999, Exactness::RoundedUp)), parse(b"-0.1"));
+    assert_eq!(Ok((f64::MAX, Exactness::RoundedDown)), parse(b"1e400"));
+    assert_eq!(Ok((2.5, Exactness::Exact)), parse(b"2.5"));
+}
+
+#[test]
+fn parse_f32_exact_test() {
+    let parse = f32::from_lexical_exact;
+    assert_eq!(Ok((16777216.0, Exactness::Exact)), parse(b"16777216"));
+    assert_eq!(Ok((16777216.0, Exactness::RoundedDown)), parse(b"16777217"));
+    assert_eq!(Ok((16777220.0, Exactness::RoundedUp)), parse(b"16777219"));
+    assert_eq!(Ok((0.1, Exactness::RoundedUp)), parse(b"0.1"));
+    assert_eq!(Ok((0.3, Exactness::RoundedUp)), parse(b"0.3"));
+    assert_eq!(Ok((0.1, Exactness::Exact)), parse(b"0.100000001490116119384765625"));
+    assert_eq!(Ok((1e-45, Exactness::RoundedDown)), parse(b"1.5e-45"));
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn parse_f16_exact_test() {
+    let parse = f16::from_lexical_exact;
+    assert_eq!(Ok((f16::from_f32(2048.0), Exactness::Exact)), parse(b"2048"));
+    assert_eq!(Ok((f16::from_f32(2048.0), Exactness::RoundedDown)), parse(b"2049"));
+    assert_eq!(Ok((f16::from_f32(65504.0), Exactness::Exact)), parse(b"65504"));
+    assert_eq!(Ok((f16::INFINITY, Exactness::RoundedUp)), parse(b"65520"));
+}
+
+#[test]
+fn parse_range_error_test() {
+    const FORMAT: u128 = STANDARD;
+    const OVERFLOW: Options = Options::builder().error_on_overflow(true).build_strict();
+    const UNDERFLOW: Options = Options::builder().error_on_underflow(true).build_strict();
+    const SUBNORMAL: Options = Options::builder().error_on_subnormal(true).build_strict();
+
+    let parse = move |x| f64::from_lexical_with_options::<FORMAT>(x, &OVERFLOW);
+    assert_eq!(Ok(f64::MAX), parse(b"1.7976931348623157e308"));
+    assert_eq!(Err(Error::Overflow(22)), parse(b"1.7976931348623159e308"));
+    assert_eq!(Err(Error::Overflow(6)), parse(b"-1e400"));
+    assert_eq!(Ok(f64::INFINITY), parse(b"inf"));
+    assert_eq!(Ok(0.0), parse(b"1e-400"));
+
+    let parse = move |x| f32::from_lexical_partial_with_options::<FORMAT>(x, &OVERFLOW);
+    assert_eq!(Err(Error::Overflow(4)), parse(b"1e39,1"));
+    assert_eq!(Ok((1e38, 4)), parse(b"1e38,1"));
+
+    let parse = move |x| f64::from_lexical_with_options::<FORMAT>(x, &UNDERFLOW);
+    assert_eq!(Ok(0.0), parse(b"0.0e-400"));
+    assert_eq!(Ok(-0.0), parse(b"-0"));
+    assert_eq!(Ok(5e-324), parse(b"3e-324"));
+    assert_eq!(Err(Error::Underflow(6)), parse(b"1e-400"));
+    assert_eq!(Err(Error::Underflow(7)), parse(b"-2e-324"));
+    assert_eq!(Err(Error::Underflow(43)), parse(b"0.000000000000000000000000000000000001e-300"));
+    assert_eq!(Ok(f64::INFINITY), parse(b"1e400"));
+
+    let parse = move |x| f64::from_lexical_with_options::<FORMAT>(x, &SUBNORMAL);
+    assert_eq!(Ok(2.2250738585072014e-308), parse(b"2.2250738585072014e-308"));
+    assert_eq!(Err(Error::Underflow(23)), parse(b"2.2250738585072009e-308"));
+    assert_eq!(Err(Error::Underflow(6)), parse(b"3e-324"));
+    assert_eq!(Ok(0.0), parse(b"1e-400"));
+
+    // Directed rounding still overflows, even if clamped to the largest float.
+    const DIRECTED: Options = Options::builder()
+        .round_mode(RoundMode::TowardZero)
+        .error_on_overflow(true)
+        .error_on_underflow(true)
+        .build_strict();
+    let parse = move |x| f64::from_lexical_with_options::<FORMAT>(x, &DIRECTED);
+    assert_eq!(Err(Error::Overflow(5)), parse(b"1e400"));
+    assert_eq!(Err(Error::Overflow(6)), parse(b"-1e400"));
+    assert_eq!(Ok(f64::MAX), parse(b"1.7976931348623159e308"));
+    assert_eq!(Ok(f64::MAX), parse(b"1.797693134862315907e308"));
+    assert_eq!(Err(Error::Overflow(24)), parse(b"1.797693134862315908e308"));
+    assert_eq!(Err(Error::Underflow(6)), parse(b"1e-400"));
+
+    const UPWARD: Options = Options::builder()
+        .round_mode(RoundMode::TowardPositive)
+        .error_on_overflow(true)
+        .build_strict();
+    let parse = move |x| f64::from_lexical_with_options::<FORMAT>(x, &UPWARD);
+    assert_eq!(Err(Error::Overflow(22)), parse(b"1.7976931348623159e308"));
+    assert_eq!(Err(Error::Overflow(6)), parse(b"-1e400"));
+
+    let parse = move |x| f64::from_lexical_exact_with_options::<FORMAT>(x, &UNDERFLOW);
+    assert_eq!(Err(Error::Underflow(6)), parse(b"1e-400"));
+}
+
+#[test]
+fn parse_si_prefix_test() {
+    const FORMAT: u128 = STANDARD;
+    const OPTIONS: Options = Options::builder().si_prefix(true).build_strict();
+
+    let parse = move |x: &str| f64::from_lexical_with_options::<FORMAT>(x.as_bytes(), &OPTIONS);
+    assert_eq!(Ok(12.5e3), parse("12.5k"));
+    assert_eq!(Ok(3.3e-6), parse("3.3\u{b5}"));
+    assert_eq!(Ok(3.3e-6), parse("3.3\u{3bc}"));
+    assert_eq!(Ok(3.3e-6), parse("3.3u"));
+    assert_eq!(Ok(47e-9), parse("47n"));
+    assert_eq!(Ok(-1e-3), parse("-1m"));
+    assert_eq!(Ok(1e6), parse("1M"));
+    assert_eq!(Ok(2e18), parse("2e18"));
+    assert_eq!(Ok(2e18), parse("2E18"));
+    assert_eq!(Ok(1e21), parse("1Z"));
+    assert_eq!(Ok(1e-30), parse("1q"));
+    assert_eq!(Ok(1e30), parse("1Q"));
+    assert_eq!(Ok(0.1), parse("100m"));
+    assert_eq!(Ok(1.5), parse("1.5"));
+    assert_eq!(Err(Error::InvalidDigit(3)), parse("1.5x"));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse("1kk"));
+    assert_eq!(Err(Error::InvalidDigit(3)), parse("1e3k"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse("k"));
+
+    // Exa is the same as the exponent character, so is an exponent.
+    assert_eq!(Err(Error::EmptyExponent(2)), parse("2E"));
+    assert_eq!(Err(Error::EmptyExponent(4)), parse("1.5e"));
+    const EXPONENT: Options = OPTIONS.rebuild().exponent(b'^').build_strict();
+    let parse = move |x: &str| f64::from_lexical_with_options::<FORMAT>(x.as_bytes(), &EXPONENT);
+    assert_eq!(Ok(2e18), parse("2E"));
+    assert_eq!(Ok(2e18), parse("2^18"));
+
+    // With a case-sensitive exponent, only `e` starts an exponent.
+    #[cfg(feature = "format")]
+    {
+        const CASE_SENSITIVE: u128 =
+            NumberFormatBuilder::rebuild(FORMAT).case_sensitive_exponent(true).build_strict();
+        let parse =
+            move |x: &str| f64::from_lexical_with_options::<CASE_SENSITIVE>(x.as_bytes(), &OPTIONS);
+        assert_eq!(Ok(2e18), parse("2E"));
+        assert_eq!(Ok(2e18), parse("2e18"));
+        assert_eq!(Err(Error::EmptyExponent(2)), parse("2e"));
+    }
+
+    let parse =
+        move |x: &str| f64::from_lexical_partial_with_options::<FORMAT>(x.as_bytes(), &OPTIONS);
+    assert_eq!(Ok((4.7e3, 4)), parse("4.7k ohm"));
+    assert_eq!(Ok((1e-6, 3)), parse("1\u{b5}F"));
+
+    let parse =
+        move |x: &str| f64::from_lexical_with_options::<FORMAT>(x.as_bytes(), &Options::new());
+    assert_eq!(Err(Error::InvalidDigit(4)), parse("12.5k"));
+}
+
+#[test]
+fn parse_f64_interval_test() {
+    let parse = f64::from_lexical_interval;
+    assert_eq!(Ok((0.0, 0.0)), parse(b"0"));
+    assert_eq!(Ok((-0.0, -0.0)), parse(b"-0.0"));
+    assert_eq!(Ok((1.5, 1.5)), parse(b"1.5"));
+    assert_eq!(Ok((1e22, 1e22)), parse(b"1e22"));
+    assert_eq!(Ok((0.09999999999999999, 0.1)), parse(b"0.1"));
+    assert_eq!(Ok((-0.1, -0.09999999999999999)), parse(b"-0.1"));
+    assert_eq!(Ok((99999999999999991611392.0, 1.0000000000000000838e23)), parse(b"1e23"));
+    assert_eq!(Ok((9007199254740992.0, 9007199254740994.0)), parse(b"9007199254740993"));
+    assert_eq!(Ok((f64::MAX, f64::INFINITY)), parse(b"1e400"));
+    assert_eq!(Ok((f64::NEG_INFINITY, f64::MIN)), parse(b"-1e400"));
+    assert_eq!(Ok((0.0, 5e-324)), parse(b"1e-400"));
+    assert_eq!(Ok((-5e-324, -0.0)), parse(b"-1e-400"));
+    assert_eq!(Ok((f64::INFINITY, f64::INFINITY)), parse(b"inf"));
+    assert!(matches!(parse(b"NaN"), Ok((x, y)) if x.is_nan() && y.is_nan()));
+    assert_eq!(
+        Ok((0.9999999999999999, 1.0)),
+        parse(b"0.99999999999999999999999999999999999999999999999999999999999999999999")
+    );
+    assert_eq!(Ok((0.5, 0.5, 3)), f64::from_lexical_partial_interval(b"0.5,"));
+
+    // The rounding mode and lossy algorithms are ignored.
+    const FORMAT: u128 = STANDARD;
+    const OPTIONS: Options =
+        Options::builder().lossy(true).round_mode(RoundMode::TowardZero).build_strict();
+    let parse = move |x| f64::from_lexical_interval_with_options::<FORMAT>(x, &OPTIONS);
+    assert_eq!(Ok((0.09999999999999999, 0.1)), parse(b"0.1"));
+    assert_eq!(Ok((f64::MAX, f64::INFINITY)), parse(b"1.7976931348623158e308"));
+
+    #[cfg(feature = "power-of-two")]
+    {
+        const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+        const OPTIONS: Options = Options::from_radix(2);
+        let parse = move |x| f64::from_lexical_interval_with_options::<BINARY>(x, &OPTIONS);
+        assert_eq!(Ok((0.5, 0.5)), parse(b"0.1"));
+        assert_eq!(
+            Ok((1.0, 1.0000000000000002)),
+            parse(b"1.00000000000000000000000000000000000000000000000000001")
+        );
+    }
+
+    #[cfg(feature = "radix")]
+    {
+        const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+        const OPTIONS: Options = Options::from_radix(3);
+        let parse = move |x| f64::from_lexical_interval_with_options::<BASE3>(x, &OPTIONS);
+        assert_eq!(Ok((0.3333333333333333, 0.33333333333333337)), parse(b"0.1"));
+        assert_eq!(Ok((3.0, 3.0)), parse(b"10"));
+    }
+}
+
+#[test]
+fn parse_f32_interval_test() {
+    let parse = move |x| f32::from_lexical_interval(x).map(|(x, y)| (x.to_bits(), y.to_bits()));
+    assert_eq!(Ok((0x3DCCCCCC, 0x3DCCCCCD)), parse(b"0.1"));
+    assert_eq!(Ok((0x4B800000, 0x4B800001)), parse(b"16777217"));
+    assert_eq!(Ok((0x4B800000, 0x4B800000)), parse(b"16777216"));
+    assert_eq!(Ok((0x7F7FFFFF, 0x7F800000)), parse(b"3.5e38"));
+    assert_eq!(Ok((0x80000001, 0x80000000)), parse(b"-1e-46"));
+
+    #[cfg(feature = "radix")]
+    {
+        const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+        const OPTIONS: Options = Options::from_radix(3);
+        let parse = move |x| {
+            f32::from_lexical_interval_with_options::<BASE3>(x, &OPTIONS)
+                .map(|(x, y)| (x.to_bits(), y.to_bits()))
+        };
+        assert_eq!(Ok((0x3EAAAAAA, 0x3EAAAAAB)), parse(b"0.1"));
+    }
+}
+
+#[test]
+fn parse_require_exact_test() {
+    const FORMAT: u128 = STANDARD;
+    const OPTIONS: Options = Options::builder().require_exact(true).build_strict();
+
+    let parse = move |x| f64::from_lexical_with_options::<FORMAT>(x, &OPTIONS);
+    assert_eq!(Ok(0.125), parse(b"0.125"));
+    assert_eq!(Ok(-1e22), parse(b"-1e22"));
+    assert_eq!(Err(Error::InexactFloat(3)), parse(b"0.1"));
+    assert_eq!(Err(Error::InexactFloat(5)), parse(b"1e400"));
+    assert!(parse(b"NaN").unwrap().is_nan());
+
+    let parse = move |x| f32::from_lexical_partial_with_options::<FORMAT>(x, &OPTIONS);
+    assert_eq!(Ok((0.5, 3)), parse(b"0.5,0.1"));
+    assert_eq!(Err(Error::InexactFloat(3)), parse(b"0.1,0.5"));
+
+    let parse = move |x| f64::from_lexical_exact_with_options::<FORMAT>(x, &OPTIONS);
+    assert_eq!(Ok((0.75, Exactness::Exact)), parse(b"0.75"));
+    assert_eq!(Err(Error::InexactFloat(4)), parse(b"1e23"));
+
+    let parse = move |x| f64::from_lexical_interval_with_options::<FORMAT>(x, &OPTIONS);
+    assert_eq!(Ok((0.75, 0.75)), parse(b"0.75"));
+    assert_eq!(Err(Error::InexactFloat(3)), parse(b"0.1"));
+}
+
 #[test]
 fn f32_lossy_decimal_test() {
     const FORMAT: u128 = STANDARD;
@@ -680,7 +1140,7 @@ fn f32_lossy_decimal_test() {
     assert_eq!(Err(Error::Empty(0)), f32::from_lexical_with_options::<FORMAT>(b"", &OPTIONS));
     assert_eq!(Ok(0.0), f32::from_lexical_with_options::<FORMAT>(b"0.0", &OPTIONS));
     assert_eq!(
-        Err((Error::InvalidDigit(1)).into()),
+        Err(Error::InvalidDigit(1)),
         f32::from_lexical_with_options::<FORMAT>(b"1a", &OPTIONS)
     );
 
@@ -703,7 +1163,7 @@ fn f64_lossy_decimal_test() {
     assert_eq!(Err(Error::Empty(0)), f64::from_lexical_with_options::<FORMAT>(b"", &OPTIONS));
     assert_eq!(Ok(0.0), f64::from_lexical_with_options::<FORMAT>(b"0.0", &OPTIONS));
     assert_eq!(
-        Err((Error::InvalidDigit(1)).into()),
+        Err(Error::InvalidDigit(1)),
         f64::from_lexical_with_options::<FORMAT>(b"1a", &OPTIONS)
     );
 
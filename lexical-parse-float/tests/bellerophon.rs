@@ -4,6 +4,7 @@
 use lexical_parse_float::bellerophon::bellerophon;
 use lexical_parse_float::float::{extended_to_float, ExtendedFloat80, RawFloat};
 use lexical_parse_float::number::Number;
+use lexical_parse_float::shared::RoundDirection;
 use lexical_util::format::STANDARD;
 
 pub fn bellerophon_test<F: RawFloat, const FORMAT: u128>(
@@ -21,7 +22,7 @@ pub fn bellerophon_test<F: RawFloat, const FORMAT: u128>(
         integer: &[],
         fraction: None,
     };
-    let xfp = bellerophon::<F, FORMAT>(&num, false);
+    let xfp = bellerophon::<F, FORMAT>(&num, false, RoundDirection::Nearest);
     let yfp = ExtendedFloat80 {
         mant: ymant,
         exp: yexp,
@@ -50,7 +51,7 @@ pub fn compute_float32(q: i64, w: u64) -> (i32, u64) {
         integer: &[],
         fraction: None,
     };
-    let fp = bellerophon::<f32, { STANDARD }>(&num, false);
+    let fp = bellerophon::<f32, { STANDARD }>(&num, false, RoundDirection::Nearest);
     (fp.exp, fp.mant)
 }
 
@@ -63,6 +64,6 @@ pub fn compute_float64(q: i64, w: u64) -> (i32, u64) {
         integer: &[],
         fraction: None,
     };
-    let fp = bellerophon::<f64, { STANDARD }>(&num, false);
+    let fp = bellerophon::<f64, { STANDARD }>(&num, false, RoundDirection::Nearest);
     (fp.exp, fp.mant)
 }
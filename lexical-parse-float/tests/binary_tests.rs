@@ -2,6 +2,7 @@
 
 use lexical_parse_float::binary::{binary, slow_binary};
 use lexical_parse_float::number::Number;
+use lexical_parse_float::shared::RoundDirection;
 use lexical_util::format::NumberFormatBuilder;
 
 const BINARY: u128 = NumberFormatBuilder::from_radix(2);
@@ -19,7 +20,7 @@ fn compute_float32<const FORMAT: u128>(q: i64, w: u64, many_digits: bool) -> (i3
         integer: &[],
         fraction: None,
     };
-    let fp = binary::<f32, FORMAT>(&num, false);
+    let fp = binary::<f32, FORMAT>(&num, false, RoundDirection::Nearest);
     (fp.exp, fp.mant)
 }
 
@@ -32,7 +33,7 @@ fn compute_float64<const FORMAT: u128>(q: i64, w: u64, many_digits: bool) -> (i3
         integer: &[],
         fraction: None,
     };
-    let fp = binary::<f64, FORMAT>(&num, false);
+    let fp = binary::<f64, FORMAT>(&num, false, RoundDirection::Nearest);
     (fp.exp, fp.mant)
 }
 
@@ -141,7 +142,7 @@ fn compute_float64_slow<const FORMAT: u128>(
         integer,
         fraction,
     };
-    let fp = slow_binary::<f64, FORMAT>(num);
+    let fp = slow_binary::<f64, FORMAT>(num, RoundDirection::Nearest);
     (fp.exp, fp.mant)
 }
 
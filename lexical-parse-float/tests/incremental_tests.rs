@@ -0,0 +1,112 @@
+#![cfg(feature = "std")]
+
+#[cfg(feature = "format")]
+use lexical_parse_float::Options;
+use lexical_parse_float::{FromLexical, IncrementalParser, Progress};
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+
+#[test]
+fn push_test() {
+    let mut parser = IncrementalParser::<f64>::new();
+    assert_eq!(parser.push(b"-1"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"."), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"25"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"e"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"+"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"2"), Ok(Progress::Incomplete));
+    assert_eq!(parser.buffered(), b"-1.25e+2");
+    assert_eq!(parser.push(b" 3"), Ok(Progress::Complete(-125.0, 8)));
+    assert_eq!(parser.buffered(), b"");
+
+    assert_eq!(parser.push(b"."), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"5,"), Ok(Progress::Complete(0.5, 2)));
+
+    assert_eq!(parser.push(b"1e"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b","), Err(Error::EmptyExponent(2)));
+    assert_eq!(parser.push(b".,"), Err(Error::EmptyMantissa(1)));
+}
+
+#[test]
+fn special_test() {
+    let mut parser = IncrementalParser::<f64>::new();
+    assert_eq!(parser.push(b"-i"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"nf"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"ini"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"ty,"), Ok(Progress::Complete(f64::NEG_INFINITY, 9)));
+
+    assert_eq!(parser.push(b"inf"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"i"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b","), Ok(Progress::Complete(f64::INFINITY, 3)));
+
+    assert_eq!(parser.push(b"N"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"a"), Ok(Progress::Incomplete));
+    match parser.push(b"N ") {
+        Ok(Progress::Complete(value, 3)) => assert!(value.is_nan()),
+        progress => panic!("unexpected progress: {:?}", progress),
+    }
+
+    assert_eq!(parser.push(b"inf"), Ok(Progress::Incomplete));
+    assert_eq!(parser.finish(), Ok(f64::INFINITY));
+    assert_eq!(parser.push(b"in"), Ok(Progress::Incomplete));
+    assert_eq!(parser.finish(), Err(Error::InvalidDigit(0)));
+}
+
+#[test]
+fn chunked_test() {
+    let inputs: [&[u8]; 6] = [
+        b"0",
+        b"-1.5",
+        b"2.2250738585072014e-308",
+        b"9007199254740993",
+        b"179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497792",
+        b"infinity",
+    ];
+    for input in inputs.iter() {
+        let expected = f64::from_lexical(input);
+        for split in 0..=input.len() {
+            let mut parser = IncrementalParser::<f64>::new();
+            assert_eq!(parser.push(&input[..split]), Ok(Progress::Incomplete));
+            assert_eq!(parser.push(&input[split..]), Ok(Progress::Incomplete));
+            assert_eq!(parser.finish(), expected);
+        }
+    }
+}
+
+#[test]
+fn bytewise_test() {
+    let inputs: [&[u8]; 5] = [b"-12.5e+3,", b"0.000001 ", b"+Infinity;", b"1e5e", b"1.2.3"];
+    for input in inputs.iter() {
+        let expected = f64::from_lexical_partial(input);
+        let mut parser = IncrementalParser::<f64>::new();
+        let mut progress = Ok(Progress::Incomplete);
+        for index in 0..input.len() {
+            progress = parser.push(&input[index..index + 1]);
+            if progress != Ok(Progress::Incomplete) {
+                break;
+            }
+        }
+        assert_eq!(progress, expected.map(|(value, count)| Progress::Complete(value, count)));
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn special_format_test() {
+    const CASE_SENSITIVE: u128 =
+        NumberFormatBuilder::new().case_sensitive_special(true).build_strict();
+    const NO_SPECIAL: u128 = NumberFormatBuilder::new().no_special(true).build_strict();
+    let options = Options::new();
+
+    let mut parser = IncrementalParser::<f64, CASE_SENSITIVE>::with_options(&options);
+    assert_eq!(parser.push(b"in"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"f "), Ok(Progress::Complete(f64::INFINITY, 3)));
+    assert_eq!(parser.push(b"In"), Err(Error::EmptyMantissa(0)));
+    assert_eq!(parser.buffered(), b"");
+
+    let mut parser = IncrementalParser::<f64, NO_SPECIAL>::with_options(&options);
+    assert_eq!(parser.push(b"i"), Err(Error::EmptyMantissa(0)));
+    assert_eq!(parser.push(b"1"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"0,"), Ok(Progress::Complete(10.0, 2)));
+}
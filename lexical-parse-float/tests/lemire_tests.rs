@@ -3,7 +3,7 @@
 #![cfg(not(feature = "compact"))]
 
 use lexical_parse_float::lemire;
-use lexical_parse_float::shared::INVALID_FP;
+use lexical_parse_float::shared::{RoundDirection, INVALID_FP};
 
 fn compute_error32(q: i64, w: u64) -> (i32, u64) {
     let fp = lemire::compute_error::<f32>(q, w);
@@ -26,12 +26,12 @@ fn compute_error_scaled64(q: i64, w: u64, lz: i32) -> (i32, u64) {
 }
 
 fn compute_float32(q: i64, w: u64) -> (i32, u64) {
-    let fp = lemire::compute_float::<f32>(q, w, false);
+    let fp = lemire::compute_float::<f32>(q, w, false, RoundDirection::Nearest);
     (fp.exp, fp.mant)
 }
 
 fn compute_float64(q: i64, w: u64) -> (i32, u64) {
-    let fp = lemire::compute_float::<f64>(q, w, false);
+    let fp = lemire::compute_float::<f64>(q, w, false, RoundDirection::Nearest);
     (fp.exp, fp.mant)
 }
 
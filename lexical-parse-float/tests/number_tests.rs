@@ -11,45 +11,77 @@ fn is_fast_path_test() {
         integer: &[],
         fraction: None,
     };
-    assert_eq!(number.is_fast_path::<f32, { STANDARD }>(), true);
-    assert_eq!(number.is_fast_path::<f64, { STANDARD }>(), true);
+    assert!(number.is_fast_path::<f32, { STANDARD }>());
+    assert!(number.is_fast_path::<f64, { STANDARD }>());
 
     number.exponent = -15;
-    assert_eq!(number.is_fast_path::<f32, { STANDARD }>(), false);
-    assert_eq!(number.is_fast_path::<f64, { STANDARD }>(), true);
+    assert!(!number.is_fast_path::<f32, { STANDARD }>());
+    assert!(number.is_fast_path::<f64, { STANDARD }>());
 
     number.exponent = -25;
-    assert_eq!(number.is_fast_path::<f32, { STANDARD }>(), false);
-    assert_eq!(number.is_fast_path::<f64, { STANDARD }>(), false);
+    assert!(!number.is_fast_path::<f32, { STANDARD }>());
+    assert!(!number.is_fast_path::<f64, { STANDARD }>());
 
     number.exponent = 25;
-    assert_eq!(number.is_fast_path::<f32, { STANDARD }>(), false);
-    assert_eq!(number.is_fast_path::<f64, { STANDARD }>(), true);
+    assert!(!number.is_fast_path::<f32, { STANDARD }>());
+    assert!(number.is_fast_path::<f64, { STANDARD }>());
 
     number.exponent = 36;
-    assert_eq!(number.is_fast_path::<f32, { STANDARD }>(), false);
-    assert_eq!(number.is_fast_path::<f64, { STANDARD }>(), true);
+    assert!(!number.is_fast_path::<f32, { STANDARD }>());
+    assert!(number.is_fast_path::<f64, { STANDARD }>());
 
     number.exponent = 38;
-    assert_eq!(number.is_fast_path::<f32, { STANDARD }>(), false);
-    assert_eq!(number.is_fast_path::<f64, { STANDARD }>(), false);
+    assert!(!number.is_fast_path::<f32, { STANDARD }>());
+    assert!(!number.is_fast_path::<f64, { STANDARD }>());
 
     number.mantissa = 1 << 25;
     number.exponent = 0;
-    assert_eq!(number.is_fast_path::<f32, { STANDARD }>(), false);
-    assert_eq!(number.is_fast_path::<f64, { STANDARD }>(), true);
+    assert!(!number.is_fast_path::<f32, { STANDARD }>());
+    assert!(number.is_fast_path::<f64, { STANDARD }>());
 
     number.mantissa = 1 << 54;
-    assert_eq!(number.is_fast_path::<f32, { STANDARD }>(), false);
-    assert_eq!(number.is_fast_path::<f64, { STANDARD }>(), false);
+    assert!(!number.is_fast_path::<f32, { STANDARD }>());
+    assert!(!number.is_fast_path::<f64, { STANDARD }>());
 
     number.mantissa = 1 << 52;
-    assert_eq!(number.is_fast_path::<f32, { STANDARD }>(), false);
-    assert_eq!(number.is_fast_path::<f64, { STANDARD }>(), true);
+    assert!(!number.is_fast_path::<f32, { STANDARD }>());
+    assert!(number.is_fast_path::<f64, { STANDARD }>());
 
     number.many_digits = true;
-    assert_eq!(number.is_fast_path::<f32, { STANDARD }>(), false);
-    assert_eq!(number.is_fast_path::<f64, { STANDARD }>(), false);
+    assert!(!number.is_fast_path::<f32, { STANDARD }>());
+    assert!(!number.is_fast_path::<f64, { STANDARD }>());
+}
+
+#[test]
+fn is_exact_fast_path_test() {
+    let mut number = Number {
+        exponent: -3,
+        mantissa: 125,
+        is_negative: false,
+        many_digits: false,
+        integer: &[],
+        fraction: None,
+    };
+    assert!(number.is_exact_fast_path::<f32, { STANDARD }>());
+    assert!(number.is_exact_fast_path::<f64, { STANDARD }>());
+
+    number.mantissa = 1;
+    number.exponent = -1;
+    assert!(!number.is_exact_fast_path::<f32, { STANDARD }>());
+    assert!(!number.is_exact_fast_path::<f64, { STANDARD }>());
+
+    number.exponent = 10;
+    assert!(!number.is_exact_fast_path::<f32, { STANDARD }>());
+    assert!(number.is_exact_fast_path::<f64, { STANDARD }>());
+
+    number.mantissa = 81866983698856825;
+    number.exponent = -1;
+    assert!(!number.is_exact_fast_path::<f64, { STANDARD }>());
+
+    number.mantissa = 125;
+    number.exponent = -3;
+    number.many_digits = true;
+    assert!(!number.is_exact_fast_path::<f64, { STANDARD }>());
 }
 
 #[test]
@@ -1,4 +1,4 @@
-use lexical_parse_float::options::{Options, OptionsBuilder};
+use lexical_parse_float::options::{Options, OptionsBuilder, RoundMode};
 
 #[test]
 fn invalid_exponent_test() {
@@ -98,13 +98,25 @@ fn builder_test() {
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
     builder = builder.infinity_string(Some(b"Infiniiiiiity"));
+    builder = builder.round_mode(RoundMode::TowardZero);
+    builder = builder.require_exact(true);
+    builder = builder.error_on_overflow(true);
+    builder = builder.error_on_underflow(true);
+    builder = builder.error_on_subnormal(true);
+    builder = builder.si_prefix(true);
 
-    assert_eq!(builder.get_lossy(), true);
+    assert!(builder.get_lossy());
     assert_eq!(builder.get_exponent(), b'^');
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
     assert_eq!(builder.get_infinity_string(), Some("Infiniiiiiity".as_bytes()));
+    assert_eq!(builder.get_round_mode(), RoundMode::TowardZero);
+    assert!(builder.get_require_exact());
+    assert!(builder.get_error_on_overflow());
+    assert!(builder.get_error_on_underflow());
+    assert!(builder.get_error_on_subnormal());
+    assert!(builder.get_si_prefix());
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(builder.build_unchecked()));
@@ -122,7 +134,7 @@ fn options_test() {
     opts.set_inf_string(Some(b"Infinity"));
     opts.set_infinity_string(Some(b"Infiniiiiiity"));
 
-    assert_eq!(opts.lossy(), true);
+    assert!(opts.lossy());
     assert_eq!(opts.exponent(), b'^');
     assert_eq!(opts.decimal_point(), b',');
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
@@ -83,7 +83,7 @@ fn parse_number_test() {
     let num = result.unwrap();
     assert_eq!(num.mantissa, 12345);
     assert_eq!(num.exponent, 6);
-    assert_eq!(num.many_digits, false);
+    assert!(!num.many_digits);
 
     let string = b"1.2345e";
     let byte = string.bytes::<{ FORMAT }>();
@@ -107,7 +107,7 @@ fn parse_partial_number_test() {
     let (num, count) = result.unwrap();
     assert_eq!(num.mantissa, 12345);
     assert_eq!(num.exponent, 6);
-    assert_eq!(num.many_digits, false);
+    assert!(!num.many_digits);
     assert_eq!(count, 9);
 
     let string = b"1.2345e";
@@ -122,7 +122,7 @@ fn parse_partial_number_test() {
     let (num, count) = result.unwrap();
     assert_eq!(num.mantissa, 12345);
     assert_eq!(num.exponent, -4);
-    assert_eq!(num.many_digits, false);
+    assert!(!num.many_digits);
     assert_eq!(count, 6);
 
     // Leading zeros
@@ -133,7 +133,7 @@ fn parse_partial_number_test() {
     let (num, count) = result.unwrap();
     assert_eq!(num.mantissa, 12345);
     assert_eq!(num.exponent, -4);
-    assert_eq!(num.many_digits, false);
+    assert!(!num.many_digits);
     assert_eq!(count, 28);
 
     // Leading zeros
@@ -144,7 +144,7 @@ fn parse_partial_number_test() {
     let (num, count) = result.unwrap();
     assert_eq!(num.mantissa, 12345);
     assert_eq!(num.exponent, -26);
-    assert_eq!(num.many_digits, false);
+    assert!(!num.many_digits);
     assert_eq!(count, 28);
 }
 
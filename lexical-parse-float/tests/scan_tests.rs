@@ -0,0 +1,169 @@
+use lexical_parse_float::format::STANDARD;
+use lexical_parse_float::scan::{scan_complete, scan_partial};
+use lexical_parse_float::{FromLexicalWithOptions, Options, TokenKind};
+use lexical_util::error::Error;
+
+const OPTIONS: Options = Options::new();
+
+/// Check the scanner finds the same number of bytes as the parser.
+fn check_count<const FORMAT: u128>(bytes: &[u8]) {
+    let parsed = f64::from_lexical_partial_with_options::<FORMAT>(bytes, &OPTIONS);
+    let scanned = scan_partial::<FORMAT>(bytes, &OPTIONS);
+    assert_eq!(parsed.map(|x| x.1), scanned.map(|x| x.length()));
+
+    let parsed = f64::from_lexical_with_options::<FORMAT>(bytes, &OPTIONS);
+    let scanned = scan_complete::<FORMAT>(bytes, &OPTIONS);
+    assert_eq!(parsed.map(|_| bytes.len()), scanned.map(|x| x.length()));
+}
+
+#[test]
+fn scan_integer_test() {
+    let token = scan_partial::<STANDARD>(b"1234,", &OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Integer);
+    assert_eq!(token.as_bytes(), b"1234");
+    assert_eq!(token.sign(), b"");
+    assert!(!token.is_negative());
+    assert_eq!(token.integer(), b"1234");
+    assert_eq!(token.fraction(), None);
+    assert_eq!(token.exponent(), None);
+    assert_eq!(token.special(), None);
+
+    let token = scan_complete::<STANDARD>(b"+0012", &OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Integer);
+    assert_eq!(token.sign(), b"+");
+    assert_eq!(token.integer(), b"0012");
+}
+
+#[test]
+fn scan_float_test() {
+    let token = scan_complete::<STANDARD>(b"-1.", &OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Float);
+    assert!(token.is_negative());
+    assert_eq!(token.integer(), b"1");
+    assert_eq!(token.fraction(), Some(&b""[..]));
+    assert_eq!(token.exponent(), None);
+
+    let token = scan_complete::<STANDARD>(b".5E-07", &OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Float);
+    assert_eq!(token.integer(), b"");
+    assert_eq!(token.fraction(), Some(&b"5"[..]));
+    assert_eq!(token.exponent_sign(), b"-");
+    assert_eq!(token.exponent(), Some(&b"07"[..]));
+    assert_eq!(token.suffix(), b"");
+
+    let token = scan_partial::<STANDARD>(b"1e5.3", &OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Float);
+    assert_eq!(token.length(), 3);
+    assert_eq!(token.exponent_sign(), b"");
+    assert_eq!(token.exponent(), Some(&b"5"[..]));
+
+    let digits = b"1.2345678901234567890123456789e300";
+    let token = scan_complete::<STANDARD>(digits, &OPTIONS).unwrap();
+    assert_eq!(token.integer(), b"1");
+    assert_eq!(token.fraction(), Some(&b"2345678901234567890123456789"[..]));
+    assert_eq!(token.exponent(), Some(&b"300"[..]));
+}
+
+#[test]
+fn scan_special_test() {
+    let token = scan_partial::<STANDARD>(b"-Infinity!", &OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Special);
+    assert_eq!(token.length(), 9);
+    assert!(token.is_negative());
+    assert_eq!(token.integer(), b"");
+    assert_eq!(token.special(), Some(&b"Infinity"[..]));
+
+    let token = scan_complete::<STANDARD>(b"nan", &OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Special);
+    assert_eq!(token.special(), Some(&b"nan"[..]));
+    assert_eq!(scan_complete::<STANDARD>(b"nan1", &OPTIONS), Err(Error::InvalidDigit(0)));
+}
+
+#[test]
+fn scan_error_test() {
+    assert_eq!(scan_partial::<STANDARD>(b"", &OPTIONS), Err(Error::Empty(0)));
+    assert_eq!(scan_partial::<STANDARD>(b"-", &OPTIONS), Err(Error::Empty(1)));
+    assert_eq!(scan_partial::<STANDARD>(b"1e", &OPTIONS), Err(Error::EmptyExponent(2)));
+    assert_eq!(scan_partial::<STANDARD>(b".", &OPTIONS), Err(Error::EmptyMantissa(1)));
+    assert_eq!(scan_complete::<STANDARD>(b"1.2x", &OPTIONS), Err(Error::InvalidDigit(3)));
+}
+
+#[test]
+fn scan_count_test() {
+    let inputs: [&[u8]; 16] = [
+        b"",
+        b"+",
+        b"1",
+        b"1.",
+        b".1",
+        b"1e",
+        b"1e+",
+        b"1e+5",
+        b"1.2e-3x",
+        b"inf",
+        b"infinity",
+        b"infx",
+        b"nan",
+        b"-.e3",
+        b"12345678901234567890123",
+        b"1_2",
+    ];
+    for input in inputs.iter() {
+        check_count::<STANDARD>(input);
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn scan_rust_literal_test() {
+    use lexical_util::format::RUST_LITERAL;
+
+    let token = scan_partial::<RUST_LITERAL>(b"1_000.0_5e1_0;", &OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Float);
+    assert_eq!(token.as_bytes(), b"1_000.0_5e1_0");
+    assert_eq!(token.integer(), b"1_000");
+    assert_eq!(token.fraction(), Some(&b"0_5"[..]));
+    assert_eq!(token.exponent(), Some(&b"1_0"[..]));
+
+    let token = scan_complete::<RUST_LITERAL>(b"1__", &OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Integer);
+    assert_eq!(token.integer(), b"1__");
+
+    let inputs: [&[u8]; 12] = [
+        b"1", b"+1", b"-1", b"1.", b".1", b"1.0", b"1e5", b"_1", b"1_.0", b"1.0e_5", b"inf", b"NaN",
+    ];
+    for input in inputs.iter() {
+        check_count::<RUST_LITERAL>(input);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn scan_prefix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(10))
+        .base_prefix(core::num::NonZeroU8::new(b'x'))
+        .base_suffix(core::num::NonZeroU8::new(b'h'))
+        .build_strict();
+    const HEX_OPTIONS: Options = Options::builder().exponent(b'p').build_strict();
+
+    let token = scan_complete::<FORMAT>(b"-0x1A.8p-3h", &HEX_OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Float);
+    assert_eq!(token.sign(), b"-");
+    assert_eq!(token.prefix(), b"0x");
+    assert_eq!(token.integer(), b"1A");
+    assert_eq!(token.fraction(), Some(&b"8"[..]));
+    assert_eq!(token.exponent_sign(), b"-");
+    assert_eq!(token.exponent(), Some(&b"3"[..]));
+    assert_eq!(token.suffix(), b"h");
+
+    let token = scan_complete::<FORMAT>(b"0x1Fh", &HEX_OPTIONS).unwrap();
+    assert_eq!(token.kind(), TokenKind::Integer);
+    assert_eq!(token.prefix(), b"0x");
+    assert_eq!(token.integer(), b"1F");
+    assert_eq!(token.suffix(), b"h");
+}
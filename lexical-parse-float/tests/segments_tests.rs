@@ -0,0 +1,67 @@
+use lexical_parse_float::{FromLexical, FromLexicalSegments};
+
+/// Check every way to split the input into 3 segments.
+fn check_segments(input: &[u8]) {
+    let expected = f64::from_lexical(input);
+    let expected_partial = f64::from_lexical_partial(input);
+    for i in 0..=input.len() {
+        for j in i..=input.len() {
+            let segments = [&input[..i], &input[i..j], &input[j..]];
+            assert_eq!(f64::from_lexical_segments(&segments), expected);
+            assert_eq!(f64::from_lexical_partial_segments(&segments), expected_partial);
+        }
+    }
+}
+
+#[test]
+fn segments_test() {
+    check_segments(b"");
+    check_segments(b"0");
+    check_segments(b"-");
+    check_segments(b"-1.5");
+    check_segments(b"1.25e+2 3");
+    check_segments(b"1e");
+    check_segments(b".e1");
+    check_segments(b"2.2250738585072014e-308");
+    check_segments(b"9007199254740993");
+    check_segments(b"1.00000000000000011102230246251565404236316680908203125");
+    check_segments(b"-infinity");
+    check_segments(b"infi");
+}
+
+#[test]
+fn empty_segments_test() {
+    assert_eq!(f64::from_lexical_partial_segments(&[]), Err(lexical_parse_float::Error::Empty(0)));
+    assert_eq!(f64::from_lexical_segments(&[b"", b"1.", b"", b"5"]), Ok(1.5));
+    assert_eq!(f64::from_lexical_partial_segments(&[b"", b"1.", b"", b"5 "]), Ok((1.5, 3)));
+}
+
+#[test]
+fn nan_segments_test() {
+    let result = f64::from_lexical_partial_segments(&[b"N", b"a", b"N,"]);
+    assert!(matches!(result, Ok((value, 3)) if value.is_nan()));
+    let result = f64::from_lexical_segments(&[b"na", b"n"]);
+    assert!(matches!(result, Ok(value) if value.is_nan()));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn long_segments_test() {
+    // Longer than the stack buffer for numbers spanning segments.
+    let mut input = b"1.".to_vec();
+    input.resize(2000, b'0');
+    input.extend_from_slice(b"1e-5 ");
+    let expected = f64::from_lexical_partial(&input);
+    for split in [1, 500, 1500, 2003] {
+        let segments = [&input[..split], &input[split..]];
+        assert_eq!(f64::from_lexical_partial_segments(&segments), expected);
+    }
+}
+
+#[test]
+fn long_tail_segments_test() {
+    // Only the bytes needed to end the number are buffered.
+    let tail = [b' '; 4096];
+    let segments: [&[u8]; 3] = [b"1.", b"5", &tail];
+    assert_eq!(f64::from_lexical_partial_segments(&segments), Ok((1.5, 3)));
+}
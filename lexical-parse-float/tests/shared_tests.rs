@@ -33,18 +33,18 @@ fn log2_test() {
 
 #[test]
 fn starts_with_test() {
-    assert_eq!(shared::starts_with(b"NaN".iter(), b"nAN".iter()), false);
-    assert_eq!(shared::starts_with(b"nAN".iter(), b"nAN".iter()), true);
-    assert_eq!(shared::starts_with(b"nAN1".iter(), b"nAN".iter()), true);
-    assert_eq!(shared::starts_with(b"nAN1".iter(), b"nAN12".iter()), false);
+    assert!(!shared::starts_with(b"NaN".iter(), b"nAN".iter()));
+    assert!(shared::starts_with(b"nAN".iter(), b"nAN".iter()));
+    assert!(shared::starts_with(b"nAN1".iter(), b"nAN".iter()));
+    assert!(!shared::starts_with(b"nAN1".iter(), b"nAN12".iter()));
 }
 
 #[test]
 fn starts_with_uncased_test() {
-    assert_eq!(shared::starts_with_uncased(b"NaN".iter(), b"nAN".iter()), true);
-    assert_eq!(shared::starts_with_uncased(b"nAN".iter(), b"nAN".iter()), true);
-    assert_eq!(shared::starts_with_uncased(b"nAN1".iter(), b"nAN".iter()), true);
-    assert_eq!(shared::starts_with_uncased(b"nAN1".iter(), b"nAN12".iter()), false);
+    assert!(shared::starts_with_uncased(b"NaN".iter(), b"nAN".iter()));
+    assert!(shared::starts_with_uncased(b"nAN".iter(), b"nAN".iter()));
+    assert!(shared::starts_with_uncased(b"nAN1".iter(), b"nAN".iter()));
+    assert!(!shared::starts_with_uncased(b"nAN1".iter(), b"nAN12".iter()));
 }
 
 #[test]
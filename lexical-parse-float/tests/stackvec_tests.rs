@@ -13,7 +13,7 @@ fn simple_test() {
     // Test the simple properties of the stack vector.
     let mut x = VecType::from_u32(1);
     assert_eq!(x.len(), 1);
-    assert_eq!(x.is_empty(), false);
+    assert!(!x.is_empty());
     assert_eq!(x.capacity(), SIZE);
     x.try_push(5).unwrap();
     assert_eq!(x.len(), 2);
@@ -28,11 +28,11 @@ fn simple_test() {
     assert_eq!(&*x, &[1, 2, 3, 4, 0, 0]);
     x.try_resize(0, 0).unwrap();
     assert_eq!(x.len(), 0);
-    assert_eq!(x.is_empty(), true);
+    assert!(x.is_empty());
 
     let x = VecType::try_from(&[5, 1]).unwrap();
     assert_eq!(x.len(), 2);
-    assert_eq!(x.is_empty(), false);
+    assert!(!x.is_empty());
     assert_eq!(x.hi16(), (0x8000, true));
     if Limb::BITS == 32 {
         assert_eq!(x.hi32(), (0x80000002, true));
@@ -112,13 +112,13 @@ fn cmp_test() {
 #[test]
 fn math_test() {
     let mut x = VecType::try_from(&[0, 1, 9]).unwrap();
-    assert_eq!(x.is_normalized(), true);
+    assert!(x.is_normalized());
     x.try_push(0).unwrap();
     assert_eq!(&*x, &[0, 1, 9, 0]);
-    assert_eq!(x.is_normalized(), false);
+    assert!(!x.is_normalized());
     x.normalize();
     assert_eq!(&*x, &[0, 1, 9]);
-    assert_eq!(x.is_normalized(), true);
+    assert!(x.is_normalized());
 
     x.add_small(1);
     assert_eq!(&*x, &[1, 1, 9]);
@@ -159,8 +159,8 @@ fn math_test() {
 
 #[test]
 fn hi_test() {
-    assert_eq!(unsafe { bigint::nonzero(&[0, 0, 0], 0) }, false);
-    assert_eq!(unsafe { bigint::nonzero(&[1, 0, 0], 0) }, true);
+    assert!(!unsafe { bigint::nonzero(&[0, 0, 0], 0) });
+    assert!(unsafe { bigint::nonzero(&[1, 0, 0], 0) });
 
     assert_eq!(bigint::u32_to_hi16_1(1), (0x8000, false));
     assert_eq!(bigint::u32_to_hi16_2(1, 4), (0x8000, true));
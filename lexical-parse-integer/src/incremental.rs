@@ -0,0 +1,141 @@
+//! Incremental parser for integers split across multiple chunks of input.
+//!
+//! The partial parsers require the entire number in a single contiguous
+//! slice, and cannot tell if the number is complete when the input ends.
+//! This buffers the number until a byte that may end the number is found,
+//! or the input is finished, and then parses it using the partial or
+//! complete parsers, so the result is always identical to [`parse_partial`]
+//! or [`parse`] on the concatenated input. The syntax of the number is
+//! tracked between chunks, so the number is not reparsed for every chunk.
+//!
+//! [`parse_partial`]: crate::FromLexicalWithOptions::from_lexical_partial_with_options
+//! [`parse`]: crate::FromLexicalWithOptions::from_lexical_with_options
+
+#![cfg(feature = "std")]
+
+use core::marker::PhantomData;
+
+use lexical_util::format::STANDARD;
+use lexical_util::incremental::Incremental;
+use lexical_util::result::{Progress, Result};
+
+use crate::api::FromLexicalWithOptions;
+use crate::options::Options;
+use crate::segments::syntax;
+
+/// Parser for an integer that may be split across multiple chunks of input.
+///
+/// Each chunk is appended to the number parsed so far, and the parser
+/// returns [`Progress::Incomplete`] if more input could extend the number.
+/// Once a byte that cannot be part of the number is found, this returns
+/// [`Progress::Complete`] with the value and the number of bytes in the
+/// number, counted from the start of the first chunk. At the end of the
+/// input, use [`finish`] to parse the buffered number.
+///
+/// After a number is complete or an error occurs, the parser is reset, so
+/// the bytes after the number must be provided again to parse the next
+/// number.
+///
+/// [`finish`]: Self::finish
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{IncrementalParser, Progress};
+///
+/// let mut parser = IncrementalParser::<u32>::new();
+/// assert_eq!(parser.push(b"12"), Ok(Progress::Incomplete));
+/// assert_eq!(parser.push(b"34,5"), Ok(Progress::Complete(1234, 4)));
+///
+/// assert_eq!(parser.push(b"5"), Ok(Progress::Incomplete));
+/// assert_eq!(parser.finish(), Ok(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncrementalParser<T, const FORMAT: u128 = STANDARD>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    /// The bytes of the number received so far, and their syntax.
+    buffer: Incremental<Vec<u8>>,
+    /// Options to dictate number parsing.
+    options: Options,
+    /// Marker for the parsed type.
+    marker: PhantomData<T>,
+}
+
+impl<T> IncrementalParser<T>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    /// Create a new parser using the standard format and default options.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::with_options(&Options::new())
+    }
+}
+
+impl<T, const FORMAT: u128> IncrementalParser<T, FORMAT>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    /// Create a new parser using custom options.
+    #[inline(always)]
+    pub fn with_options(options: &Options) -> Self {
+        Self {
+            buffer: Incremental::new(syntax::<T, FORMAT>(options)),
+            options: options.clone(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Get the bytes of the incomplete number received so far.
+    #[inline(always)]
+    pub fn buffered(&self) -> &[u8] {
+        self.buffer.buffered()
+    }
+
+    /// Discard the bytes of the incomplete number received so far.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.buffer.reset();
+    }
+
+    /// Provide the next chunk of input.
+    ///
+    /// Returns [`Progress::Incomplete`] if the number could be extended
+    /// by more input, otherwise, the value and the number of bytes in the
+    /// number, counted from the start of the first chunk.
+    ///
+    /// # Errors
+    ///
+    /// If the number is invalid, even with more input, with the same error
+    /// as the partial parser on the concatenated input.
+    #[inline]
+    pub fn push(&mut self, bytes: &[u8]) -> Result<Progress<T>> {
+        let options = &self.options;
+        self.buffer
+            .push(bytes, |bytes| T::from_lexical_partial_with_options::<FORMAT>(bytes, options))
+    }
+
+    /// Parse the number at the end of the input, and reset the parser.
+    ///
+    /// # Errors
+    ///
+    /// If the number is invalid, with the same error as the complete
+    /// parser on the concatenated input.
+    #[inline]
+    pub fn finish(&mut self) -> Result<T> {
+        let options = &self.options;
+        self.buffer.finish(|bytes| T::from_lexical_with_options::<FORMAT>(bytes, options))
+    }
+}
+
+impl<T> Default for IncrementalParser<T>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -263,8 +263,7 @@
     clippy::unnecessary_safety_comment,
     clippy::semicolon_if_nothing_returned,
     clippy::unwrap_used,
-    clippy::as_underscore,
-    clippy::doc_markdown
+    clippy::as_underscore
 )]
 #![allow(
     // used when concepts are logically separate
@@ -284,8 +283,10 @@
 )]
 
 pub mod algorithm;
+pub mod incremental;
 pub mod options;
 pub mod parse;
+pub mod segments;
 
 mod api;
 
@@ -293,8 +294,11 @@ mod api;
 pub use lexical_util::error::Error;
 pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
 pub use lexical_util::options::ParseOptions;
-pub use lexical_util::result::Result;
+pub use lexical_util::result::{Progress, Result};
 
 pub use self::api::{FromLexical, FromLexicalWithOptions};
+#[cfg(feature = "std")]
+pub use self::incremental::IncrementalParser;
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder};
+pub use self::segments::FromLexicalSegments;
@@ -0,0 +1,24 @@
+//! Parse integers from input split across non-contiguous segments.
+//!
+//! This supports input such as ring buffers, ropes, or scatter/gather
+//! I/O buffers, where the number may span multiple segments. Numbers
+//! contained within a single segment are parsed in place, so the optimized
+//! algorithms for contiguous input are still used, and only the bytes of a
+//! number spanning multiple segments are copied.
+
+use core::mem;
+
+use lexical_util::from_lexical_segments;
+use lexical_util::incremental::Syntax;
+use lexical_util::num::Number;
+
+use crate::api::FromLexicalWithOptions;
+use crate::options::Options;
+
+/// Get the syntax of an integer, to find where it ends.
+#[inline(always)]
+pub(crate) fn syntax<T: Number, const FORMAT: u128>(_: &Options) -> Syntax {
+    Syntax::integer::<FORMAT>(mem::size_of::<T>() * 8, T::IS_SIGNED)
+}
+
+from_lexical_segments!("lexical_parse_integer", 12345, u64, syntax);
@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+use lexical_parse_integer::{FromLexical, IncrementalParser, Progress};
+use lexical_util::error::Error;
+
+#[test]
+fn push_test() {
+    let mut parser = IncrementalParser::<u32>::new();
+    assert_eq!(parser.push(b""), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"1"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"23"), Ok(Progress::Incomplete));
+    assert_eq!(parser.buffered(), b"123");
+    assert_eq!(parser.push(b"4 5"), Ok(Progress::Complete(1234, 4)));
+    assert_eq!(parser.buffered(), b"");
+
+    let mut parser = IncrementalParser::<i32>::new();
+    assert_eq!(parser.push(b"-"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"12"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b","), Ok(Progress::Complete(-12, 3)));
+
+    assert_eq!(parser.push(b"-"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"x"), Ok(Progress::Complete(0, 1)));
+    assert_eq!(parser.push(b"x"), Ok(Progress::Complete(0, 0)));
+
+    let mut parser = IncrementalParser::<u8>::new();
+    assert_eq!(parser.push(b"25"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"6"), Err(Error::Overflow(2)));
+}
+
+#[test]
+fn finish_test() {
+    let mut parser = IncrementalParser::<u32>::new();
+    assert_eq!(parser.push(b"12"), Ok(Progress::Incomplete));
+    assert_eq!(parser.push(b"34"), Ok(Progress::Incomplete));
+    assert_eq!(parser.finish(), Ok(1234));
+    assert_eq!(parser.buffered(), b"");
+    assert_eq!(parser.finish(), Err(Error::Empty(0)));
+
+    let mut parser = IncrementalParser::<i32>::new();
+    assert_eq!(parser.push(b"-"), Ok(Progress::Incomplete));
+    assert_eq!(parser.finish(), Err(Error::Empty(1)));
+}
+
+#[test]
+fn chunked_test() {
+    let inputs: [&[u8]; 5] = [b"0", b"12345", b"-9876543210", b"+42", b"4294967295"];
+    for input in inputs.iter() {
+        let expected = i64::from_lexical(input);
+        for split in 0..=input.len() {
+            let mut parser = IncrementalParser::<i64>::new();
+            assert_eq!(parser.push(&input[..split]), Ok(Progress::Incomplete));
+            assert_eq!(parser.push(&input[split..]), Ok(Progress::Incomplete));
+            assert_eq!(parser.finish(), expected);
+        }
+    }
+}
+
+#[test]
+fn bytewise_test() {
+    let inputs: [&[u8]; 4] = [b"0000000000000000000255,", b"-128 ", b"256", b"+12-3"];
+    for input in inputs.iter() {
+        let expected = u8::from_lexical_partial(input);
+        let mut parser = IncrementalParser::<u8>::new();
+        let mut progress = Ok(Progress::Incomplete);
+        for index in 0..input.len() {
+            progress = parser.push(&input[index..index + 1]);
+            if progress != Ok(Progress::Incomplete) {
+                break;
+            }
+        }
+        assert_eq!(progress, expected.map(|(value, count)| Progress::Complete(value, count)));
+    }
+}
@@ -0,0 +1,37 @@
+use lexical_parse_integer::{FromLexical, FromLexicalSegments};
+
+/// Check every way to split the input into 3 segments.
+fn check_segments(input: &[u8]) {
+    let expected = i64::from_lexical(input);
+    let expected_partial = i64::from_lexical_partial(input);
+    for i in 0..=input.len() {
+        for j in i..=input.len() {
+            let segments = [&input[..i], &input[i..j], &input[j..]];
+            assert_eq!(i64::from_lexical_segments(&segments), expected);
+            assert_eq!(i64::from_lexical_partial_segments(&segments), expected_partial);
+        }
+    }
+}
+
+#[test]
+fn segments_test() {
+    check_segments(b"");
+    check_segments(b"0");
+    check_segments(b"-");
+    check_segments(b"1234567890123");
+    check_segments(b"-9223372036854775808");
+    check_segments(b"9223372036854775808");
+    check_segments(b"+42 17");
+    check_segments(b"12a4");
+    check_segments(b"x");
+}
+
+#[test]
+fn empty_segments_test() {
+    assert_eq!(
+        u32::from_lexical_partial_segments(&[]),
+        Err(lexical_parse_integer::Error::Empty(0))
+    );
+    assert_eq!(u32::from_lexical_segments(&[b"", b"12", b"", b"3"]), Ok(123));
+    assert_eq!(u32::from_lexical_partial_segments(&[b"", b"12", b"", b"3 "]), Ok((123, 3)));
+}
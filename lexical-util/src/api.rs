@@ -188,6 +188,122 @@ macro_rules! from_lexical_with_options {
     };
 }
 
+/// Define the [`FromLexicalSegments`] trait.
+///
+/// * `name`: The name of the crate calling the function.
+/// * `value`: The value of `12345` for the example.
+/// * `t`: The type of the number for the example.
+/// * `syntax`: A function to get the syntax of the number from the options.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// from_lexical_segments!("lexical_core", 12345, u64, syntax);
+/// ```
+///
+/// [`FromLexicalSegments`]: https://docs.rs/lexical-core/latest/lexical_core/trait.FromLexicalSegments.html
+#[macro_export]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+macro_rules! from_lexical_segments {
+    ($name:literal, $value:literal, $t:ty, $syntax:ident $(, #[$attr:meta])? $(,)?) => {
+        /// Trait for numerical types that can be parsed from non-contiguous input.
+        ///
+        /// The input is a sequence of byte slices, such as a ring buffer
+        /// or rope, which are parsed as if they were concatenated, and the
+        /// results and error indexes are the same as the contiguous parsers
+        /// on the concatenated input.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        #[doc = concat!("use ", $name, "::{Error, FromLexicalSegments};")]
+        ///
+        /// let segments: [&[u8]; 3] = [b"12", b"34", b"5,6"];
+        #[doc = concat!("let parsed = ", stringify!($t), "::from_lexical_segments(&segments);")]
+        /// assert_eq!(parsed, Err(Error::InvalidDigit(5)));
+        #[doc = concat!("let parsed = ", stringify!($t), "::from_lexical_partial_segments(&segments);")]
+        #[doc = concat!("assert_eq!(parsed, Ok((", stringify!($value), ", 5)));")]
+        /// ```
+        $(#[$attr])?
+        pub trait FromLexicalSegments: FromLexicalWithOptions<Options = Options> {
+            /// Checked parser for a string-to-number conversion from segments.
+            ///
+            /// This method parses the entire input, returning an error if any
+            /// invalid digits are found during parsing.
+            ///
+            /// * `segments` - Byte slices which, when concatenated, contain the
+            ///   numeric string.
+            #[inline]
+            fn from_lexical_segments(segments: &[&[u8]]) -> lexical_util::result::Result<Self> {
+                Self::from_lexical_segments_with_options::<{ lexical_util::format::STANDARD }>(
+                    segments,
+                    &Options::new(),
+                )
+            }
+
+            /// Checked parser for a string-to-number conversion from segments.
+            ///
+            /// This method parses until an invalid digit is found (or the end of
+            /// the input), returning the number of processed digits and the parsed
+            /// value until that point.
+            ///
+            /// * `segments` - Byte slices which, when concatenated, contain the
+            ///   numeric string.
+            #[inline]
+            fn from_lexical_partial_segments(
+                segments: &[&[u8]],
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                Self::from_lexical_partial_segments_with_options::<{ lexical_util::format::STANDARD }>(
+                    segments,
+                    &Options::new(),
+                )
+            }
+
+            /// Checked parser for a string-to-number conversion from segments
+            /// with custom options.
+            ///
+            /// This method parses the entire input, returning an error if any
+            /// invalid digits are found during parsing.
+            ///
+            /// * `segments` - Byte slices which, when concatenated, contain the
+            ///   numeric string.
+            /// * `options` - Options to dictate number parsing.
+            #[inline]
+            fn from_lexical_segments_with_options<const FORMAT: u128>(
+                segments: &[&[u8]],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self> {
+                lexical_util::segments::parse_complete(segments, |bytes| {
+                    Self::from_lexical_with_options::<FORMAT>(bytes, options)
+                })
+            }
+
+            /// Checked parser for a string-to-number conversion from segments
+            /// with custom options.
+            ///
+            /// This method parses until an invalid digit is found (or the end of
+            /// the input), returning the number of processed digits and the parsed
+            /// value until that point.
+            ///
+            /// * `segments` - Byte slices which, when concatenated, contain the
+            ///   numeric string.
+            /// * `options` - Options to dictate number parsing.
+            #[inline]
+            fn from_lexical_partial_segments_with_options<const FORMAT: u128>(
+                segments: &[&[u8]],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                let syntax = $syntax::<Self, FORMAT>(options);
+                lexical_util::segments::parse_partial(segments, syntax, |bytes| {
+                    Self::from_lexical_partial_with_options::<FORMAT>(bytes, options)
+                })
+            }
+        }
+
+        impl<T: FromLexicalWithOptions<Options = Options>> FromLexicalSegments for T {}
+    };
+}
+
 // TO LEXICAL
 
 /// Define the [`ToLexical`] trait.
@@ -255,6 +371,41 @@ macro_rules! to_lexical {
             ///
             /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
             fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8];
+
+            /// Checked serializer for a number-to-string conversion.
+            ///
+            /// Returns a subslice of the input buffer containing the written
+            /// bytes, identical to [`to_lexical`][Self::to_lexical], or an
+            /// error if the buffer is too small. This never panics, so any
+            /// buffer may be provided.
+            ///
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::BufferTooSmall`] with the number of bytes
+            /// required if the number does not fit in the buffer.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// use core::str;
+            ///
+            #[doc = concat!("use ", $name, "::{Error, ToLexical};")]
+            ///
+            #[doc = concat!("let value: ", stringify!($t), " = ", stringify!($value), ";")]
+            /// let mut buffer = [0u8; 16];
+            /// let digits = value.try_to_lexical(&mut buffer);
+            #[doc = concat!("assert_eq!(digits.map(|x| str::from_utf8(x)), Ok(Ok(\"", stringify!($value), "\")));")]
+            ///
+            /// let mut buffer = [0u8; 2];
+            /// let digits = value.try_to_lexical(&mut buffer);
+            #[doc = concat!("assert_eq!(digits, Err(Error::BufferTooSmall(\"", stringify!($value), "\".len())));")]
+            /// ```
+            ///
+            /// [`Error::BufferTooSmall`]: lexical_util::error::Error::BufferTooSmall
+            fn try_to_lexical<'a>(self, bytes: &'a mut [u8]) -> lexical_util::result::Result<&'a mut [u8]>;
         }
     };
 }
@@ -379,6 +530,11 @@ macro_rules! to_lexical_with_options {
             /// Panics as well if `the` NaN or `Inf` string provided to the writer
             /// is disabled, but the value provided is `NaN` or `Inf`, respectively.
             ///
+            /// **Integers Only**
+            ///
+            /// Panics if zero padding is used with a number format that does
+            /// not allow integer leading zeros.
+            ///
             #[doc = concat!(
                 "[`Options::buffer_size_const`]: crate::",
                 stringify!($ops_t),
@@ -391,6 +547,85 @@ macro_rules! to_lexical_with_options {
                 bytes: &'a mut [u8],
                 options: &Self::Options,
             ) -> &'a mut [u8];
+
+            /// Checked serializer for a number-to-string conversion.
+            ///
+            /// Returns a subslice of the input buffer containing the written
+            /// bytes, identical to [`to_lexical_with_options`], or an error
+            /// if the number cannot be written. This never panics, so any
+            /// buffer, format, and options may be provided.
+            ///
+            /// * `FORMAT`  - Flags and characters designating the number grammar.
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            /// * `options` - Options for number formatting.
+            ///
+            /// If the buffer has fewer than [`Options::buffer_size_const`]
+            /// elements, the number is first written to a scratch buffer on
+            /// the stack, so the exact length can be checked. The number is
+            /// written if the buffer is at least the exact length, which is
+            /// the length reported otherwise.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::BufferTooSmall`] with the number of bytes
+            /// required if the number does not fit in the buffer, the format
+            /// error if the number format is invalid, and the options error
+            /// if the options are invalid.
+            ///
+            /// **Floats Only**
+            ///
+            /// Returns [`Error::InvalidExponentBase`] if the mantissa radix
+            /// is not equal to the exponent base and the combination is not
+            /// supported, and [`Error::UnsupportedSpecial`] if the value is
+            /// `NaN` or `Inf`, but it is disabled by the format or options.
+            ///
+            /// **Integers Only**
+            ///
+            /// Returns [`Error::UnsupportedPadding`] if zero padding is used
+            /// with a number format that does not allow integer leading zeros.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// use core::str;
+            ///
+            #[doc = concat!(
+                "use ",
+                $name,
+                "::{format, Error, ",
+                stringify!($ops_t),
+                ", ToLexicalWithOptions};"
+            )]
+            ///
+            /// const FORMAT: u128 = format::STANDARD;
+            #[doc = concat!("const OPTIONS: ", stringify!($ops_t), " = ", stringify!($ops_t), "::new();")]
+            ///
+            #[doc = concat!("let value: ", stringify!($t), " = ", stringify!($value), ";")]
+            /// let mut buffer = [0u8; 16];
+            /// let digits = value.try_to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS);
+            #[doc = concat!("assert_eq!(digits.map(|x| str::from_utf8(x)), Ok(Ok(\"", stringify!($value), "\")));")]
+            ///
+            /// let mut buffer = [0u8; 2];
+            /// let digits = value.try_to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS);
+            #[doc = concat!("assert_eq!(digits, Err(Error::BufferTooSmall(\"", stringify!($value), "\".len())));")]
+            /// ```
+            ///
+            /// [`to_lexical_with_options`]: Self::to_lexical_with_options
+            #[doc = concat!(
+                "[`Options::buffer_size_const`]: crate::",
+                stringify!($ops_t),
+                "::buffer_size_const"
+            )]
+            /// [`Error::BufferTooSmall`]: lexical_util::error::Error::BufferTooSmall
+            /// [`Error::InvalidExponentBase`]: lexical_util::error::Error::InvalidExponentBase
+            /// [`Error::UnsupportedSpecial`]: lexical_util::error::Error::UnsupportedSpecial
+            /// [`Error::UnsupportedPadding`]: lexical_util::error::Error::UnsupportedPadding
+            fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<&'a mut [u8]>;
         }
     };
 }
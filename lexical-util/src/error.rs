@@ -52,6 +52,8 @@ pub enum Error {
     InvalidPositiveSign(usize),
     /// Invalid negative sign for an unsigned type was found.
     InvalidNegativeSign(usize),
+    /// Float could not be exactly represented and exact parsing was required.
+    InexactFloat(usize),
 
     // NUMBER FORMAT ERRORS
     /// Invalid radix for the mantissa (significant) digits.
@@ -115,6 +117,15 @@ pub enum Error {
     /// Invalid positive exponent break: break is below 0.
     InvalidPositiveExponentBreak,
 
+    // WRITE ERRORS
+    /// Buffer is too small to hold the written number, with the required
+    /// length.
+    BufferTooSmall(usize),
+    /// Special float cannot be written by the number format or options.
+    UnsupportedSpecial,
+    /// Zero padding cannot be written by the number format.
+    UnsupportedPadding,
+
     // NOT AN ERROR
     /// An error did not actually occur, and the result was successful.
     Success,
@@ -159,6 +170,7 @@ impl Error {
             Self::MissingSign(_) => "'missing required `+/-` sign for integer'",
             Self::InvalidPositiveSign(_) => "'invalid `+` sign for an integer was found'",
             Self::InvalidNegativeSign(_) => "'invalid `-` sign for an unsigned type was found'",
+            Self::InexactFloat(_) => "'float cannot be exactly represented'",
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => "'invalid radix for mantissa digits'",
@@ -193,6 +205,11 @@ impl Error {
             Self::InvalidNegativeExponentBreak => "'invalid negative exponent break: value is above 0'",
             Self::InvalidPositiveExponentBreak => "'invalid positive exponent break: value is below 0'",
 
+            // WRITE ERRORS
+            Self::BufferTooSmall(_) => "'buffer is too small to hold the number'",
+            Self::UnsupportedSpecial => "'special float is disabled by the format or options'",
+            Self::UnsupportedPadding => "'zero padding is disabled by the format'",
+
             // NOT AN ERROR
             Self::Success => "'not actually an error'",
         }
@@ -222,6 +239,7 @@ impl Error {
             Self::MissingSign(index) => Some(index),
             Self::InvalidPositiveSign(index) => Some(index),
             Self::InvalidNegativeSign(index) => Some(index),
+            Self::InexactFloat(index) => Some(index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => None,
@@ -256,6 +274,11 @@ impl Error {
             Self::InvalidNegativeExponentBreak => None,
             Self::InvalidPositiveExponentBreak => None,
 
+            // WRITE ERRORS
+            Self::BufferTooSmall(_) => None,
+            Self::UnsupportedSpecial => None,
+            Self::UnsupportedPadding => None,
+
             // NOT AN ERROR
             Self::Success => None,
         }
@@ -280,6 +303,7 @@ impl Error {
     is_error_type!(is_missing_sign, MissingSign(_));
     is_error_type!(is_invalid_positive_sign, InvalidPositiveSign(_));
     is_error_type!(is_invalid_negative_sign, InvalidNegativeSign(_));
+    is_error_type!(is_inexact_float, InexactFloat(_));
     is_error_type!(is_invalid_mantissa_radix, InvalidMantissaRadix);
     is_error_type!(is_invalid_exponent_base, InvalidExponentBase);
     is_error_type!(is_invalid_exponent_radix, InvalidExponentRadix);
@@ -318,6 +342,9 @@ impl Error {
     is_error_type!(is_invalid_float_precision, InvalidFloatPrecision);
     is_error_type!(is_invalid_negative_exponent_break, InvalidNegativeExponentBreak);
     is_error_type!(is_invalid_positive_exponent_break, InvalidPositiveExponentBreak);
+    is_error_type!(is_buffer_too_small, BufferTooSmall(_));
+    is_error_type!(is_unsupported_special, UnsupportedSpecial);
+    is_error_type!(is_unsupported_padding, UnsupportedPadding);
     is_error_type!(is_success, Success);
 }
 
@@ -342,6 +369,13 @@ macro_rules! options_message {
     };
 }
 
+/// Add an error message for write errors.
+macro_rules! write_message {
+    ($formatter:ident, $message:expr) => {
+        write!($formatter, "lexical write error: {}", $message)
+    };
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         let description = self.description();
@@ -372,6 +406,7 @@ impl fmt::Display for Error {
             Self::MissingSign(index) => write_parse_error!(formatter, description, index),
             Self::InvalidPositiveSign(index) => write_parse_error!(formatter, description, index),
             Self::InvalidNegativeSign(index) => write_parse_error!(formatter, description, index),
+            Self::InexactFloat(index) => write_parse_error!(formatter, description, index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => format_message!(formatter, description),
@@ -412,6 +447,17 @@ impl fmt::Display for Error {
             Self::InvalidNegativeExponentBreak => options_message!(formatter, description),
             Self::InvalidPositiveExponentBreak => options_message!(formatter, description),
 
+            // WRITE ERRORS
+            Self::BufferTooSmall(length) => {
+                write!(
+                    formatter,
+                    "lexical write error: {} with {} bytes required",
+                    description, length
+                )
+            },
+            Self::UnsupportedSpecial => write_message!(formatter, description),
+            Self::UnsupportedPadding => write_message!(formatter, description),
+
             // NOT AN ERROR
             Self::Success => write!(formatter, "{description}"),
         }
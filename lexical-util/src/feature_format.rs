@@ -253,6 +253,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// - Parse Float
     /// - Parse Integer
     /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn no_positive_mantissa_sign(&self) -> bool {
         Self::NO_POSITIVE_MANTISSA_SIGN
@@ -281,6 +282,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// - Parse Float
     /// - Parse Integer
     /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn required_mantissa_sign(&self) -> bool {
         Self::REQUIRED_MANTISSA_SIGN
@@ -390,6 +392,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     #[inline(always)]
     pub const fn no_exponent_without_fraction(&self) -> bool {
         Self::NO_EXPONENT_WITHOUT_FRACTION
@@ -417,6 +420,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     #[inline(always)]
     pub const fn no_special(&self) -> bool {
         Self::NO_SPECIAL
@@ -462,6 +466,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// # Used For
     ///
     /// - Parse Integer
+    /// - Write Integer
     #[inline(always)]
     pub const fn no_integer_leading_zeros(&self) -> bool {
         Self::NO_INTEGER_LEADING_ZEROS
@@ -620,6 +625,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn integer_internal_digit_separator(&self) -> bool {
         Self::INTEGER_INTERNAL_DIGIT_SEPARATOR
@@ -656,6 +663,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     #[inline(always)]
     pub const fn fraction_internal_digit_separator(&self) -> bool {
         Self::FRACTION_INTERNAL_DIGIT_SEPARATOR
@@ -1108,6 +1116,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn digit_separator(&self) -> u8 {
         Self::DIGIT_SEPARATOR
@@ -1148,6 +1158,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn base_prefix(&self) -> u8 {
         Self::BASE_PREFIX
@@ -1186,6 +1198,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn base_suffix(&self) -> u8 {
         Self::BASE_SUFFIX
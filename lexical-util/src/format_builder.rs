@@ -156,7 +156,20 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 ///
 /// # Write Integer Fields
 ///
-/// No fields are used for writing integers.
+/// These fields are used for writing integers:
+///
+/// - [`digit_separator`]: Character to separate digits.
+/// - [`mantissa_radix`]: Radix for mantissa digits.
+/// - [`base_prefix`]: Optional character for the base prefix.
+/// - [`base_suffix`]: Optional character for the base suffix.
+/// - [`no_positive_mantissa_sign`]: If positive sign before the mantissa is not
+///   allowed.
+/// - [`required_mantissa_sign`]: If positive sign before the mantissa is
+///   required.
+/// - [`no_integer_leading_zeros`]: If leading zeros before an integer are not
+///   allowed.
+/// - [`integer_internal_digit_separator`]: If digit separators are allowed
+///   between integer digits.
 ///
 /// # Parse Integer Fields
 ///
@@ -196,6 +209,9 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// - [`no_positive_exponent_sign`]: If positive sign before the exponent is not
 ///   allowed.
 /// - [`required_exponent_sign`]: If sign before the exponent is required.
+/// - [`no_exponent_without_fraction`]: If exponent without fraction is not
+///   allowed.
+/// - [`no_special`]: If special (non-finite) values are not allowed.
 /// - [`required_exponent_notation`]: If exponent notation is required.
 ///
 /// # Parse Float Fields
@@ -824,6 +840,7 @@ impl NumberFormatBuilder {
     /// - Parse Float
     /// - Parse Integer
     /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn get_no_positive_mantissa_sign(&self) -> bool {
         self.no_positive_mantissa_sign
@@ -847,6 +864,7 @@ impl NumberFormatBuilder {
     /// - Parse Float
     /// - Parse Integer
     /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn get_required_mantissa_sign(&self) -> bool {
         self.required_mantissa_sign
@@ -936,6 +954,7 @@ impl NumberFormatBuilder {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     #[inline(always)]
     pub const fn get_no_exponent_without_fraction(&self) -> bool {
         self.no_exponent_without_fraction
@@ -958,6 +977,7 @@ impl NumberFormatBuilder {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     #[inline(always)]
     pub const fn get_no_special(&self) -> bool {
         self.no_special
@@ -993,6 +1013,7 @@ impl NumberFormatBuilder {
     /// # Used For
     ///
     /// - Parse Integer
+    /// - Write Integer
     #[inline(always)]
     pub const fn get_no_integer_leading_zeros(&self) -> bool {
         self.no_integer_leading_zeros
@@ -1419,6 +1440,8 @@ impl NumberFormatBuilder {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     ///
     /// <!-- TEST
     /// ```rust
@@ -1635,6 +1658,8 @@ impl NumberFormatBuilder {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     ///
     /// <!-- TEST
     /// ```rust
@@ -1682,6 +1707,8 @@ impl NumberFormatBuilder {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     ///
     /// <!-- TEST
     /// ```rust
@@ -1924,6 +1951,7 @@ impl NumberFormatBuilder {
     /// - Parse Float
     /// - Parse Integer
     /// - Write Float
+    /// - Write Integer
     ///
     /// <!-- TEST
     /// ```rust
@@ -2128,6 +2156,7 @@ impl NumberFormatBuilder {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     ///
     /// <!-- TEST
     /// ```rust
@@ -2164,6 +2193,7 @@ impl NumberFormatBuilder {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     ///
     /// <!-- TEST
     /// ```rust
@@ -2246,6 +2276,7 @@ impl NumberFormatBuilder {
     /// # Used For
     ///
     /// - Parse Integer
+    /// - Write Integer
     ///
     /// <!-- TEST
     /// ```rust
@@ -2504,6 +2535,8 @@ impl NumberFormatBuilder {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     ///
     /// <!-- TEST
     /// ```rust
@@ -2552,6 +2585,7 @@ impl NumberFormatBuilder {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     ///
     /// <!-- TEST
     /// ```rust
@@ -0,0 +1,404 @@
+//! Shared buffering for incremental parsers of chunked input.
+//!
+//! The partial parsers require the entire number in a single contiguous
+//! slice, and cannot tell if the number is complete when the input ends.
+//! Reparsing the buffered number after every chunk is quadratic in the
+//! number of chunks, so this tracks the syntax of the number across chunks
+//! using a small state machine, and only parses the number once a byte
+//! that may end it is found, or the input is finished.
+//!
+//! The state machine only determines when to parse the number: the result
+//! is always from the partial or complete parsers on the buffered input, so
+//! it is identical to parsing the concatenated input.
+
+#![cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::digit::char_is_digit_const;
+use crate::error::Error;
+use crate::format::NumberFormat;
+use crate::result::{Progress, Result};
+
+/// The syntax of a number, to determine which bytes may extend it.
+///
+/// Formats with digit separators or base prefixes and suffixes are not
+/// tracked, and the number is reparsed after each chunk, except for
+/// special values.
+#[derive(Debug, Clone)]
+pub struct Syntax {
+    /// If the digits, signs, decimal point and exponent are tracked.
+    is_tracked: bool,
+    /// The radix of the mantissa digits.
+    mantissa_radix: u32,
+    /// The radix of the exponent digits.
+    exponent_radix: u32,
+    /// The maximum number of significant digits that cannot overflow.
+    max_digits: usize,
+    /// If a leading `-` sign is valid.
+    negative_sign: bool,
+    /// If a leading `+` sign is valid.
+    positive_sign: bool,
+    /// If a leading sign is required.
+    required_sign: bool,
+    /// The decimal point, or [`None`] for integers.
+    decimal_point: Option<u8>,
+    /// The exponent character, or [`None`] without exponent notation.
+    exponent: Option<u8>,
+    /// If the exponent character is case-sensitive.
+    case_sensitive_exponent: bool,
+    /// The valid special values, such as `NaN` and `inf`.
+    specials: [Option<&'static [u8]>; 3],
+    /// If the special values are case-sensitive.
+    case_sensitive_special: bool,
+}
+
+impl Syntax {
+    /// Create the syntax for an integer with `bits` and signedness.
+    #[inline(always)]
+    pub const fn integer<const FORMAT: u128>(bits: usize, is_signed: bool) -> Self {
+        let format = NumberFormat::<FORMAT> {};
+        let radix = format.mantissa_radix();
+        Self {
+            is_tracked: is_tracked::<FORMAT>(),
+            mantissa_radix: radix,
+            exponent_radix: radix,
+            max_digits: crate::step::min_step(radix, bits, is_signed),
+            negative_sign: is_signed,
+            positive_sign: !format.no_positive_mantissa_sign(),
+            required_sign: format.required_mantissa_sign(),
+            decimal_point: None,
+            exponent: None,
+            case_sensitive_exponent: false,
+            specials: [None; 3],
+            case_sensitive_special: false,
+        }
+    }
+
+    /// Create the syntax for a float with the decimal point, exponent
+    /// character and special values.
+    #[inline(always)]
+    pub const fn float<const FORMAT: u128>(
+        decimal_point: u8,
+        exponent: u8,
+        specials: [Option<&'static [u8]>; 3],
+    ) -> Self {
+        let format = NumberFormat::<FORMAT> {};
+        Self {
+            is_tracked: is_tracked::<FORMAT>(),
+            mantissa_radix: format.mantissa_radix(),
+            exponent_radix: format.exponent_radix(),
+            max_digits: usize::MAX,
+            negative_sign: true,
+            positive_sign: !format.no_positive_mantissa_sign(),
+            required_sign: format.required_mantissa_sign(),
+            decimal_point: Some(decimal_point),
+            exponent: if format.no_exponent_notation() {
+                None
+            } else {
+                Some(exponent)
+            },
+            case_sensitive_exponent: format.case_sensitive_exponent(),
+            specials: if format.no_special() {
+                [None; 3]
+            } else {
+                specials
+            },
+            case_sensitive_special: format.case_sensitive_special(),
+        }
+    }
+
+    /// Determine if the byte is the exponent character.
+    #[inline(always)]
+    fn is_exponent(&self, byte: u8) -> bool {
+        match self.exponent {
+            Some(exponent) if self.case_sensitive_exponent => byte == exponent,
+            Some(exponent) => byte.eq_ignore_ascii_case(&exponent),
+            None => false,
+        }
+    }
+
+    /// Get the special values that continue with `byte` at `index`.
+    #[inline(always)]
+    fn specials(&self, candidates: u8, index: usize, byte: u8) -> u8 {
+        let mut matches = 0;
+        for (i, special) in self.specials.iter().enumerate() {
+            let is_match = match special {
+                Some(special) if candidates & (1 << i) != 0 && index < special.len() => {
+                    if self.case_sensitive_special {
+                        special[index] == byte
+                    } else {
+                        special[index].eq_ignore_ascii_case(&byte)
+                    }
+                },
+                _ => false,
+            };
+            if is_match {
+                matches |= 1 << i;
+            }
+        }
+        matches
+    }
+}
+
+/// Determine if the syntax of numbers in the format can be tracked.
+#[inline(always)]
+const fn is_tracked<const FORMAT: u128>() -> bool {
+    let format = NumberFormat::<FORMAT> {};
+    format.digit_separator() == 0 && format.base_prefix() == 0 && format.base_suffix() == 0
+}
+
+/// The component of the number the last byte was part of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// No bytes have been received.
+    Start,
+    /// After the mantissa sign.
+    Sign,
+    /// The integer digits, with the number of significant digits.
+    Integer(usize),
+    /// After the decimal point.
+    Fraction,
+    /// After the exponent character.
+    Exponent,
+    /// After the exponent sign.
+    ExponentSign,
+    /// The exponent digits.
+    ExponentDigits,
+    /// A prefix of the special values in the bitmask, with its length.
+    Special(u8, usize),
+    /// The number cannot be tracked, and must be reparsed.
+    Unknown,
+}
+
+impl State {
+    /// Advance the state by a byte, returning if the byte extends the number.
+    ///
+    /// If this returns `false`, the number may end before the byte, and
+    /// the number must be parsed to determine its end.
+    #[inline(always)]
+    fn advance(&mut self, byte: u8, syntax: &Syntax) -> bool {
+        let is_digit = |radix| char_is_digit_const(byte, radix);
+        let next = match *self {
+            Self::Start | Self::Sign => {
+                let specials = syntax.specials(0b111, 0, byte);
+                if specials != 0 {
+                    Self::Special(specials, 1)
+                } else if *self == Self::Start && (byte == b'+' || byte == b'-') {
+                    let is_valid = match byte {
+                        b'+' => syntax.positive_sign,
+                        _ => syntax.negative_sign,
+                    };
+                    if !is_valid {
+                        return false;
+                    }
+                    Self::Sign
+                } else if !syntax.is_tracked || (*self == Self::Start && syntax.required_sign) {
+                    return false;
+                } else if is_digit(syntax.mantissa_radix) {
+                    Self::Integer((byte != b'0') as usize)
+                } else if Some(byte) == syntax.decimal_point {
+                    Self::Fraction
+                } else {
+                    return false;
+                }
+            },
+            Self::Integer(digits) if is_digit(syntax.mantissa_radix) => {
+                let digits = digits + (digits != 0 || byte != b'0') as usize;
+                if digits > syntax.max_digits {
+                    return false;
+                }
+                Self::Integer(digits)
+            },
+            Self::Integer(_) if Some(byte) == syntax.decimal_point => Self::Fraction,
+            Self::Fraction if is_digit(syntax.mantissa_radix) => Self::Fraction,
+            Self::Integer(_) | Self::Fraction if syntax.is_exponent(byte) => Self::Exponent,
+            Self::Exponent if byte == b'+' || byte == b'-' => Self::ExponentSign,
+            Self::Exponent | Self::ExponentSign | Self::ExponentDigits
+                if is_digit(syntax.exponent_radix) =>
+            {
+                Self::ExponentDigits
+            },
+            Self::Special(candidates, index) => match syntax.specials(candidates, index, byte) {
+                0 => return false,
+                specials => Self::Special(specials, index + 1),
+            },
+            _ => return false,
+        };
+        *self = next;
+        true
+    }
+}
+
+/// Storage for the bytes of a number split across multiple chunks.
+pub trait Buffer: Default {
+    /// Get the stored bytes.
+    fn as_slice(&self) -> &[u8];
+
+    /// Remove all stored bytes.
+    fn clear(&mut self);
+
+    /// Append bytes, returning `false` if they cannot be stored.
+    fn extend_from_slice(&mut self, bytes: &[u8]) -> bool;
+}
+
+#[cfg(feature = "std")]
+impl Buffer for Vec<u8> {
+    #[inline(always)]
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+
+    #[inline(always)]
+    fn clear(&mut self) {
+        Vec::clear(self);
+    }
+
+    #[inline(always)]
+    fn extend_from_slice(&mut self, bytes: &[u8]) -> bool {
+        Vec::extend_from_slice(self, bytes);
+        true
+    }
+}
+
+/// Buffer for a number that may be split across multiple chunks of input.
+///
+/// This is shared by the incremental and segment parsers for each numeric
+/// type, which provide the partial and complete parsers for the number.
+#[derive(Debug, Clone)]
+pub struct Incremental<B: Buffer> {
+    /// The bytes of the number received so far.
+    buffer: B,
+    /// The state of the number after the buffered bytes.
+    state: State,
+    /// The syntax of the number.
+    syntax: Syntax,
+}
+
+impl<B: Buffer> Incremental<B> {
+    /// Create an empty buffer for numbers with the syntax.
+    #[inline(always)]
+    pub fn new(syntax: Syntax) -> Self {
+        Self {
+            buffer: B::default(),
+            state: State::Start,
+            syntax,
+        }
+    }
+
+    /// Get the bytes of the incomplete number received so far.
+    #[inline(always)]
+    pub fn buffered(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+
+    /// Discard the bytes of the incomplete number received so far.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.state = State::Start;
+    }
+
+    /// Append bytes to the buffered number.
+    ///
+    /// If the bytes cannot be stored, the buffer is reset and this returns
+    /// [`Error::BufferTooSmall`] with the length of the number.
+    #[inline(always)]
+    fn extend(&mut self, bytes: &[u8]) -> Result<()> {
+        if self.buffer.extend_from_slice(bytes) {
+            Ok(())
+        } else {
+            let length = self.buffered().len() + bytes.len();
+            self.reset();
+            Err(Error::BufferTooSmall(length))
+        }
+    }
+
+    /// Provide the next chunk of input, parsing the number with `parse`
+    /// if the chunk may end it.
+    ///
+    /// `parse` is the partial parser, returning the value and the number
+    /// of bytes processed. Only the bytes of the chunk required to end the
+    /// number are buffered.
+    ///
+    /// # Errors
+    ///
+    /// If the number is invalid, even with more input, with the same error
+    /// as the partial parser on the concatenated input. If the number
+    /// cannot be buffered, returns [`Error::BufferTooSmall`].
+    #[inline]
+    pub fn push<T, F>(&mut self, bytes: &[u8], parse: F) -> Result<Progress<T>>
+    where
+        F: Fn(&[u8]) -> Result<(T, usize)>,
+    {
+        let syntax = &self.syntax;
+        let state = &mut self.state;
+        let stop = match bytes.iter().position(|&byte| !state.advance(byte, syntax)) {
+            Some(stop) => stop,
+            None => {
+                self.extend(bytes)?;
+                return Ok(Progress::Incomplete);
+            },
+        };
+
+        // Avoid copying the chunk if it contains the entire number.
+        let progress = if self.buffered().is_empty() {
+            progress(bytes, &parse)
+        } else {
+            self.extend(&bytes[..=stop])?;
+            match progress(self.buffered(), &parse) {
+                Ok(Progress::Incomplete) if stop + 1 < bytes.len() => {
+                    self.extend(&bytes[stop + 1..])?;
+                    progress(self.buffered(), &parse)
+                },
+                progress => progress,
+            }
+        };
+        match progress {
+            Ok(Progress::Incomplete) => {
+                if self.buffered().is_empty() {
+                    self.extend(bytes)?;
+                }
+                // The byte extended the number, so the syntax is unknown.
+                self.state = State::Unknown;
+            },
+            _ => self.reset(),
+        }
+        progress
+    }
+
+    /// Parse the number at the end of the input with `parse`, and reset
+    /// the buffer.
+    ///
+    /// # Errors
+    ///
+    /// If the number is invalid, with the same error as `parse` on the
+    /// concatenated input.
+    #[inline]
+    pub fn finish<T, F>(&mut self, parse: F) -> Result<T>
+    where
+        F: FnOnce(&[u8]) -> Result<T>,
+    {
+        let result = parse(self.buffered());
+        self.reset();
+        result
+    }
+}
+
+/// Determine the progress of the number contained in `bytes`.
+#[inline(always)]
+fn progress<T, F>(bytes: &[u8], parse: &F) -> Result<Progress<T>>
+where
+    F: Fn(&[u8]) -> Result<(T, usize)>,
+{
+    let length = bytes.len();
+    match parse(bytes) {
+        Ok((value, count)) if count < length => Ok(Progress::Complete(value, count)),
+        // The number consumed all our input, so it could be extended.
+        Ok(_) => Ok(Progress::Incomplete),
+        // The number ended prematurely, such as only a sign.
+        Err(error) if error.index() == Some(&length) => Ok(Progress::Incomplete),
+        Err(error) => Err(error),
+    }
+}
@@ -86,8 +86,7 @@
     clippy::unnecessary_safety_comment,
     clippy::semicolon_if_nothing_returned,
     clippy::unwrap_used,
-    clippy::as_underscore,
-    clippy::doc_markdown
+    clippy::as_underscore
 )]
 #![allow(
     // used when concepts are logically separate
@@ -117,11 +116,13 @@ pub mod error;
 pub mod extended_float;
 pub mod f16;
 pub mod format;
+pub mod incremental;
 pub mod iterator;
 pub mod mul;
 pub mod num;
 pub mod options;
 pub mod result;
+pub mod segments;
 pub mod step;
 
 mod api;
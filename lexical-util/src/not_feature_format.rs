@@ -257,6 +257,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// - Parse Float
     /// - Parse Integer
     /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn no_positive_mantissa_sign(&self) -> bool {
         Self::NO_POSITIVE_MANTISSA_SIGN
@@ -285,6 +286,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// - Parse Float
     /// - Parse Integer
     /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn required_mantissa_sign(&self) -> bool {
         Self::REQUIRED_MANTISSA_SIGN
@@ -394,6 +396,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     #[inline(always)]
     pub const fn no_exponent_without_fraction(&self) -> bool {
         Self::NO_EXPONENT_WITHOUT_FRACTION
@@ -421,6 +424,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     #[inline(always)]
     pub const fn no_special(&self) -> bool {
         Self::NO_SPECIAL
@@ -466,6 +470,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// # Used For
     ///
     /// - Parse Integer
+    /// - Write Integer
     #[inline(always)]
     pub const fn no_integer_leading_zeros(&self) -> bool {
         Self::NO_INTEGER_LEADING_ZEROS
@@ -624,6 +629,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn integer_internal_digit_separator(&self) -> bool {
         Self::INTEGER_INTERNAL_DIGIT_SEPARATOR
@@ -660,6 +667,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     /// # Used For
     ///
     /// - Parse Float
+    /// - Write Float
     #[inline(always)]
     pub const fn fraction_internal_digit_separator(&self) -> bool {
         Self::FRACTION_INTERNAL_DIGIT_SEPARATOR
@@ -1113,6 +1121,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn digit_separator(&self) -> u8 {
         Self::DIGIT_SEPARATOR
@@ -1153,6 +1163,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn base_prefix(&self) -> u8 {
         Self::BASE_PREFIX
@@ -1191,6 +1203,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     ///
     /// - Parse Float
     /// - Parse Integer
+    /// - Write Float
+    /// - Write Integer
     #[inline(always)]
     pub const fn base_suffix(&self) -> u8 {
         Self::BASE_SUFFIX
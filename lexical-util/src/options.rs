@@ -94,6 +94,188 @@ pub trait ParseOptions: Default {
     fn is_valid(&self) -> bool;
 }
 
+// GROUPING
+// --------
+
+/// Sizes of the groups of digits between digit separators when writing
+/// numbers.
+///
+/// The primary group is the group closest to the decimal point, and the
+/// secondary group size is used for every group after it. For example,
+/// [`THOUSANDS`][Self::THOUSANDS] writes `1234567` as `1,234,567`, while
+/// [`INDIAN`][Self::INDIAN] writes it as `12,34,567`. Fractional digits
+/// are always grouped using the primary size, starting from the decimal
+/// point.
+///
+/// Digit separators are only written if the number format has a
+/// [`digit_separator`] and the internal digit separator flag for that
+/// component enabled.
+///
+/// [`digit_separator`]: crate::format::NumberFormatBuilder::digit_separator
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DigitGrouping {
+    /// Number of digits in the group nearest the decimal point.
+    primary: u8,
+
+    /// Number of digits in all remaining groups.
+    secondary: u8,
+}
+
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+impl DigitGrouping {
+    /// Do not group digits.
+    pub const NONE: Self = Self::new(0);
+
+    /// Group digits in groups of 3, such as `1,234,567`.
+    pub const THOUSANDS: Self = Self::new(3);
+
+    /// Group digits using the Indian numbering system, such as `12,34,567`.
+    pub const INDIAN: Self = Self::with_secondary(3, 2);
+
+    /// Create a grouping where every group has the same size.
+    ///
+    /// A size of `0` disables grouping.
+    #[inline(always)]
+    pub const fn new(size: u8) -> Self {
+        Self {
+            primary: size,
+            secondary: size,
+        }
+    }
+
+    /// Create a grouping with a different size for the first group.
+    ///
+    /// A `primary` size of `0` disables grouping, and a `secondary` size
+    /// of `0` uses the primary size for all groups.
+    #[inline(always)]
+    pub const fn with_secondary(primary: u8, secondary: u8) -> Self {
+        if primary == 0 || secondary == 0 {
+            Self::new(primary)
+        } else {
+            Self {
+                primary,
+                secondary,
+            }
+        }
+    }
+
+    /// Get the number of digits in the group nearest the decimal point.
+    #[inline(always)]
+    pub const fn primary(&self) -> u8 {
+        self.primary
+    }
+
+    /// Get the number of digits in all remaining groups.
+    #[inline(always)]
+    pub const fn secondary(&self) -> u8 {
+        self.secondary
+    }
+
+    /// Get if digits are grouped.
+    #[inline(always)]
+    pub const fn is_enabled(&self) -> bool {
+        self.primary != 0
+    }
+
+    /// Get the number of separators required to group `count` integer digits.
+    #[inline(always)]
+    pub const fn integer_separators(&self, count: usize) -> usize {
+        let primary = self.primary as usize;
+        let secondary = self.secondary as usize;
+        if primary == 0 || count <= primary {
+            0
+        } else {
+            1 + (count - primary - 1) / secondary
+        }
+    }
+
+    /// Get the number of separators required to group `count` fraction
+    /// digits.
+    #[inline(always)]
+    pub const fn fraction_separators(&self, count: usize) -> usize {
+        let primary = self.primary as usize;
+        if primary == 0 || count == 0 {
+            0
+        } else {
+            (count - 1) / primary
+        }
+    }
+}
+
+// SI PREFIXES
+// -----------
+
+/// The SI prefixes for powers of 10 that are multiples of 3.
+///
+/// This starts with quecto (`q`, `1e-30`) and ends with quetta (`Q`,
+/// `1e30`), where the empty prefix at index [`SI_PREFIX_OFFSET`] is for
+/// `1e0`. Micro is written as `µ` (U+00B5 MICRO SIGN).
+#[cfg(any(feature = "parse-floats", feature = "write-floats"))]
+pub const SI_PREFIXES: [&[u8]; 21] = [
+    b"q",
+    b"r",
+    b"y",
+    b"z",
+    b"a",
+    b"f",
+    b"p",
+    b"n",
+    "\u{b5}".as_bytes(),
+    b"m",
+    b"",
+    b"k",
+    b"M",
+    b"G",
+    b"T",
+    b"P",
+    b"E",
+    b"Z",
+    b"Y",
+    b"R",
+    b"Q",
+];
+
+/// The index of the empty prefix in [`SI_PREFIXES`].
+#[cfg(any(feature = "parse-floats", feature = "write-floats"))]
+pub const SI_PREFIX_OFFSET: usize = 10;
+
+/// Get the SI prefix for a power of 10.
+///
+/// Returns [`None`] if the exponent is not a multiple of 3, or is outside
+/// of `[-30, 30]`.
+#[cfg(any(feature = "parse-floats", feature = "write-floats"))]
+#[inline(always)]
+pub const fn si_prefix(exponent: i32) -> Option<&'static [u8]> {
+    if exponent % 3 != 0 || exponent < -30 || exponent > 30 {
+        return None;
+    }
+    let index = SI_PREFIX_OFFSET as i32 + exponent / 3;
+    Some(SI_PREFIXES[index as usize])
+}
+
+/// Parse a non-empty SI prefix from the start of the bytes.
+///
+/// Returns the power of 10 for the prefix and the number of bytes it
+/// uses. Micro is also accepted as `μ` (U+03BC GREEK SMALL LETTER MU)
+/// and `u`.
+#[cfg(any(feature = "parse-floats", feature = "write-floats"))]
+#[inline(always)]
+pub fn parse_si_prefix(bytes: &[u8]) -> Option<(i32, usize)> {
+    if bytes.starts_with("\u{3bc}".as_bytes()) {
+        return Some((-6, 2));
+    } else if bytes.first() == Some(&b'u') {
+        return Some((-6, 1));
+    }
+    SI_PREFIXES.iter().enumerate().find_map(|(index, &prefix)| {
+        if !prefix.is_empty() && bytes.starts_with(prefix) {
+            Some((3 * (index as i32 - SI_PREFIX_OFFSET as i32), prefix.len()))
+        } else {
+            None
+        }
+    })
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
@@ -118,6 +300,7 @@ macro_rules! literal {
 literal!(RUST_LITERAL, None, "A Rust literal number (uses default options).");
 // RUST_STRING
 literal!(PYTHON_LITERAL, None, "A Python literal number (uses default options).");
+literal!(PYTHON_REPR_NAN, b"nan", "A Python `repr` NaN (`nan`).");
 // PYTHON_STRING
 literal!(CXX_LITERAL_NAN, b"NAN", "A C++ literal NaN (`NAN`).");
 literal!(CXX_LITERAL_INF, b"INFINITY", "A C++ literal short infinity (`INFINITY`).");
@@ -126,6 +309,7 @@ literal!(CXX_LITERAL_INFINITY, b"INFINITY", "A C++ literal long infinity (`INFIN
 literal!(C_LITERAL_NAN, b"NAN", "A C literal NaN (`NAN`).");
 literal!(C_LITERAL_INF, b"INFINITY", "A C literal short infinity (`INFINITY`).");
 literal!(C_LITERAL_INFINITY, b"INFINITY", "A C literal long infinity (`INFINITY`).");
+literal!(C_PRINTF_NAN, b"nan", "A C `printf` NaN (`nan`).");
 // RUBY_LITERAL
 literal!(RUBY_LITERAL_NAN, b"NaN", "A Ruby literal NaN (`NaN`).");
 literal!(RUBY_LITERAL_INF, b"Infinity", "A C literal short infinity (`Infinity`).");
@@ -134,6 +318,7 @@ literal!(RUBY_STRING_NONE, None, "A Ruby string (uses default options).");
 literal!(SWIFT_LITERAL, None, "A Swift literal number (uses default options).");
 // SWIFT_STRING
 literal!(GO_LITERAL, None, "A Golang literal number (uses default options).");
+literal!(GO_FORMAT_FLOAT_INF, b"+Inf", "A Golang `FormatFloat` infinity (`+Inf`).");
 // GO_STRING
 literal!(HASKELL_LITERAL, None, "A Haskell literal number (uses default options).");
 literal!(HASKELL_STRING_INF, b"Infinity", "A Haskell string short infinity (`Infinity`).");
@@ -196,25 +381,29 @@ literal!(OCAML_LITERAL_INF, b"infinity", "An OCAML literal short infinity (`infi
 literal!(OCAML_LITERAL_INFINITY, b"infinity", "An OCAML literal long infinity (`infinity`).");
 // OCAML_STRING
 literal!(OBJECTIVEC, None, "An Objective-C number (uses default options).");
-literal!(REASONML_LITERAL_NAN, b"nan", "A ReasonML literal NaN (`nan`).");
-literal!(REASONML_LITERAL_INF, b"infinity", "A ReasonML literal short infinity (`infinity`).");
-literal!(REASONML_LITERAL_INFINITY, b"infinity", "A ReasonML literal long infinity (`infinity`).");
+literal!(REASONML_LITERAL_NAN, b"nan", "A `ReasonML` literal NaN (`nan`).");
+literal!(REASONML_LITERAL_INF, b"infinity", "A `ReasonML` literal short infinity (`infinity`).");
+literal!(
+    REASONML_LITERAL_INFINITY,
+    b"infinity",
+    "A `ReasonML` literal long infinity (`infinity`)."
+);
 // REASONML_STRING
 literal!(MATLAB_LITERAL_INF, b"inf", "A MATLAB literal short infinity (`inf`).");
 literal!(MATLAB_LITERAL_INFINITY, b"Inf", "A MATLAB literal long infinity (`Inf`).");
 // MATLAB_STRING
 literal!(ZIG_LITERAL, None, "A Zig literal number (uses default options).");
 // ZIG_STRING
-literal!(SAGE_LITERAL_INF, b"infinity", "A SageMath literal short infinity (`infinity`).");
-literal!(SAGE_LITERAL_INFINITY, b"Infinity", "A SageMath literal long infinity (`Infinity`).");
+literal!(SAGE_LITERAL_INF, b"infinity", "A `SageMath` literal short infinity (`infinity`).");
+literal!(SAGE_LITERAL_INFINITY, b"Infinity", "A `SageMath` literal long infinity (`Infinity`).");
 // SAGE_STRING
 literal!(JSON, None, "A JSON number (uses default options).");
 literal!(TOML, None, "A TOML number (uses default options).");
 literal!(YAML, None, "A YAML number (uses default options).");
 literal!(XML_INF, None, "An XML short infinity (uses default options).");
 literal!(XML_INFINITY, None, "An XML short infinity (uses default options).");
-literal!(SQLITE, None, "A SQLite number (uses default options).");
-literal!(POSTGRESQL, None, "A PostgreSQL number (uses default options).");
-literal!(MYSQL, None, "A MySQL number (uses default options).");
-literal!(MONGODB_INF, b"Infinity", "A MongoDB short infinity (`Infinity`).");
-literal!(MONGODB_INFINITY, b"Infinity", "A MongoDB long infinity (`Infinity`).");
+literal!(SQLITE, None, "A `SQLite` number (uses default options).");
+literal!(POSTGRESQL, None, "A `PostgreSQL` number (uses default options).");
+literal!(MYSQL, None, "A `MySQL` number (uses default options).");
+literal!(MONGODB_INF, b"Infinity", "A `MongoDB` short infinity (`Infinity`).");
+literal!(MONGODB_INFINITY, b"Infinity", "A `MongoDB` long infinity (`Infinity`).");
@@ -2152,6 +2152,78 @@ pub const MONGODB: u128 = NumberFormatBuilder::new()
     .no_float_leading_zeros(true)
     .build_strict();
 
+// LANGUAGE FORMATTERS
+
+// These describe the output of each language's default float formatter,
+// and should be paired with the write options of the same name.
+
+// JAVASCRIPT TO STRING
+/// Number format of [`Number.prototype.toString`] in JavaScript.
+///
+/// [`Number.prototype.toString`]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toString
+#[rustfmt::skip]
+pub const JAVASCRIPT_TO_STRING: u128 = NumberFormatBuilder::new()
+    .required_exponent_sign(true)
+    .case_sensitive_special(true)
+    .build_strict();
+
+// PYTHON REPR
+/// Number format of [`repr`] for a float in Python.
+///
+/// [`repr`]: https://docs.python.org/3/library/functions.html#repr
+#[rustfmt::skip]
+pub const PYTHON_REPR: u128 = NumberFormatBuilder::new()
+    .required_exponent_sign(true)
+    .case_sensitive_special(true)
+    .build_strict();
+
+// GO FORMAT FLOAT
+/// Number format of [`strconv.FormatFloat`] in Go.
+///
+/// [`strconv.FormatFloat`]: https://pkg.go.dev/strconv#FormatFloat
+#[rustfmt::skip]
+pub const GO_FORMAT_FLOAT: u128 = NumberFormatBuilder::new()
+    .required_exponent_sign(true)
+    .case_sensitive_special(true)
+    .build_strict();
+
+// JAVA TO STRING
+/// Number format of [`Double.toString`] in Java.
+///
+/// [`Double.toString`]: https://docs.oracle.com/en/java/javase/21/docs/api/java.base/java/lang/Double.html#toString(double)
+#[rustfmt::skip]
+pub const JAVA_TO_STRING: u128 = NumberFormatBuilder::new()
+    .required_digits(true)
+    .case_sensitive_special(true)
+    .build_strict();
+
+// C PRINTF G
+/// Number format of the [`%g`] conversion of `printf` in C.
+///
+/// [`%g`]: https://en.cppreference.com/w/c/io/fprintf
+#[rustfmt::skip]
+pub const C_PRINTF_G: u128 = NumberFormatBuilder::new()
+    .required_exponent_sign(true)
+    .case_sensitive_special(true)
+    .build_strict();
+
+// C PRINTF E
+/// Number format of the [`%e`] conversion of `printf` in C.
+///
+/// [`%e`]: https://en.cppreference.com/w/c/io/fprintf
+#[rustfmt::skip]
+pub const C_PRINTF_E: u128 = NumberFormatBuilder::new()
+    .required_exponent_notation(true)
+    .required_exponent_sign(true)
+    .case_sensitive_special(true)
+    .build_strict();
+
+// C PRINTF G17
+/// Number format of the [`%.17g`] conversion of `printf` in C.
+///
+/// [`%.17g`]: https://en.cppreference.com/w/c/io/fprintf
+pub const C_PRINTF_G17: u128 = C_PRINTF_G;
+
 // HIDDEN DEFAULTS AND INTERFACES
 
 /// Number format when no flags are set.
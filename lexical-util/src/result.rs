@@ -6,3 +6,14 @@ use crate::error;
 
 /// A specialized [`Result`][`result::Result`] type for lexical operations.
 pub type Result<T> = result::Result<T, error::Error>;
+
+/// The progress of an incremental parser after receiving more input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Progress<T> {
+    /// More input could extend the number, so it cannot be parsed yet.
+    Incomplete,
+
+    /// The number is complete, with the parsed value and the number of
+    /// bytes in the number, counted from the start of the first chunk.
+    Complete(T, usize),
+}
@@ -0,0 +1,160 @@
+//! Shared parsers for input split across non-contiguous segments.
+//!
+//! This supports input such as ring buffers, ropes, or scatter/gather
+//! I/O buffers, where the number may span multiple segments. The segments
+//! are scanned without concatenating them, and numbers contained within a
+//! single segment are parsed in place, so the optimized algorithms for
+//! contiguous input, such as parsing multiple digits at a time, are still
+//! used. Only the bytes of a number that spans multiple segments are
+//! copied, into a buffer on the stack unless the number is long.
+
+#![cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::error::Error;
+use crate::incremental::{Buffer, Incremental, Syntax};
+use crate::result::{Progress, Result};
+
+/// The number of bytes of a number spanning segments stored on the stack.
+///
+/// This is enough for the significant digits of any `f64`. Without the
+/// `std` feature, numbers spanning segments must fit in this many bytes.
+pub const SEGMENT_BUFFER_SIZE: usize = 1024;
+
+/// Storage for a number spanning multiple segments.
+///
+/// Numbers are stored on the stack, and with the `std` feature, are moved
+/// to the heap if they are longer than [`SEGMENT_BUFFER_SIZE`].
+#[derive(Clone)]
+struct SegmentBuffer {
+    /// The bytes stored on the stack.
+    stack: [u8; SEGMENT_BUFFER_SIZE],
+    /// The number of bytes stored on the stack.
+    length: usize,
+    /// The bytes stored on the heap, if they do not fit on the stack.
+    #[cfg(feature = "std")]
+    heap: Vec<u8>,
+}
+
+impl Default for SegmentBuffer {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            stack: [0; SEGMENT_BUFFER_SIZE],
+            length: 0,
+            #[cfg(feature = "std")]
+            heap: Vec::new(),
+        }
+    }
+}
+
+impl Buffer for SegmentBuffer {
+    #[inline(always)]
+    fn as_slice(&self) -> &[u8] {
+        #[cfg(feature = "std")]
+        if !self.heap.is_empty() {
+            return &self.heap;
+        }
+        &self.stack[..self.length]
+    }
+
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.length = 0;
+        #[cfg(feature = "std")]
+        self.heap.clear();
+    }
+
+    #[inline(always)]
+    fn extend_from_slice(&mut self, bytes: &[u8]) -> bool {
+        #[cfg(feature = "std")]
+        if !self.heap.is_empty() {
+            self.heap.extend_from_slice(bytes);
+            return true;
+        }
+        let length = self.length + bytes.len();
+        if length <= SEGMENT_BUFFER_SIZE {
+            self.stack[self.length..length].copy_from_slice(bytes);
+            self.length = length;
+            return true;
+        }
+
+        #[cfg(feature = "std")]
+        {
+            self.heap.reserve(length);
+            self.heap.extend_from_slice(&self.stack[..self.length]);
+            self.heap.extend_from_slice(bytes);
+            true
+        }
+        #[cfg(not(feature = "std"))]
+        false
+    }
+}
+
+/// Parse the number at the start of the segments with the partial parser.
+///
+/// The segments are parsed as if they were concatenated, with the same
+/// result as `parse` on the concatenated input.
+///
+/// * `segments` - Byte slices which, when concatenated, contain the number.
+/// * `syntax` - The syntax of the number, to find where it ends.
+/// * `parse` - The partial parser for contiguous input.
+///
+/// # Errors
+///
+/// If the number is invalid, with the same error as `parse` on the
+/// concatenated input. Without the `std` feature, if the number spans
+/// multiple segments and is longer than [`SEGMENT_BUFFER_SIZE`], returns
+/// [`Error::BufferTooSmall`].
+#[inline]
+pub fn parse_partial<T, F>(segments: &[&[u8]], syntax: Syntax, parse: F) -> Result<(T, usize)>
+where
+    F: Fn(&[u8]) -> Result<(T, usize)>,
+{
+    let mut buffer = Incremental::<SegmentBuffer>::new(syntax);
+    for segment in segments {
+        if let Progress::Complete(value, count) = buffer.push(segment, &parse)? {
+            return Ok((value, count));
+        }
+    }
+    // The number ends with the input, so parse any buffered bytes.
+    parse(buffer.buffered())
+}
+
+/// Parse the segments with the complete parser.
+///
+/// The segments are parsed as if they were concatenated, with the same
+/// result as `parse` on the concatenated input.
+///
+/// * `segments` - Byte slices which, when concatenated, contain the number.
+/// * `parse` - The complete parser for contiguous input.
+///
+/// # Errors
+///
+/// If the number is invalid, with the same error as `parse` on the
+/// concatenated input. Without the `std` feature, if the input spans
+/// multiple segments and is longer than [`SEGMENT_BUFFER_SIZE`], returns
+/// [`Error::BufferTooSmall`].
+#[inline]
+pub fn parse_complete<T, F>(segments: &[&[u8]], parse: F) -> Result<T>
+where
+    F: FnOnce(&[u8]) -> Result<T>,
+{
+    let mut nonempty = segments.iter().filter(|segment| !segment.is_empty());
+    match (nonempty.next(), nonempty.next()) {
+        (None, _) => parse(&[]),
+        (Some(segment), None) => parse(segment),
+        _ => {
+            let mut buffer = SegmentBuffer::default();
+            for segment in segments {
+                if !buffer.extend_from_slice(segment) {
+                    let length = segments.iter().map(|segment| segment.len()).sum();
+                    return Err(Error::BufferTooSmall(length));
+                }
+            }
+            parse(buffer.as_slice())
+        },
+    }
+}
@@ -21,12 +21,12 @@ fn is_valid_digit_separator(digit_separator: u8) -> bool {
 #[test]
 #[cfg(feature = "format")]
 fn test_is_valid_digit_separator() {
-    assert_eq!(is_valid_digit_separator(b'_'), true);
-    assert_eq!(is_valid_digit_separator(b'\''), true);
-    assert_eq!(is_valid_digit_separator(b'.'), true);
-    assert_eq!(is_valid_digit_separator(b'e'), true);
-    assert_eq!(is_valid_digit_separator(b'0'), false);
-    assert_eq!(is_valid_digit_separator(128), false);
+    assert!(is_valid_digit_separator(b'_'));
+    assert!(is_valid_digit_separator(b'\''));
+    assert!(is_valid_digit_separator(b'.'));
+    assert!(is_valid_digit_separator(b'e'));
+    assert!(!is_valid_digit_separator(b'0'));
+    assert!(!is_valid_digit_separator(128));
 
     // Try with a custom radix.
     #[cfg(feature = "radix")]
@@ -34,7 +34,7 @@ fn test_is_valid_digit_separator() {
         let format = format::NumberFormat::<{ from_digit_separator(b'e') }>::rebuild()
             .radix(16)
             .build_unchecked();
-        assert_eq!(format::is_valid_digit_separator(format), false);
+        assert!(!format::is_valid_digit_separator(format));
     }
 }
 
@@ -52,9 +52,9 @@ fn is_valid_punctuation(digit_separator: u8, base_prefix: u8, base_suffix: u8) -
 #[test]
 #[cfg(all(feature = "power-of-two", feature = "format"))]
 fn test_is_valid_punctuation() {
-    assert_eq!(is_valid_punctuation(b'_', b'h', 0), true);
-    assert_eq!(is_valid_punctuation(b'e', b'h', 0), true);
-    assert_eq!(is_valid_punctuation(b'^', b'h', 0), true);
-    assert_eq!(is_valid_punctuation(b'\'', b'h', 0), true);
-    assert_eq!(is_valid_punctuation(b'\'', b'h', b'h'), false);
+    assert!(is_valid_punctuation(b'_', b'h', 0));
+    assert!(is_valid_punctuation(b'e', b'h', 0));
+    assert!(is_valid_punctuation(b'^', b'h', 0));
+    assert!(is_valid_punctuation(b'\'', b'h', 0));
+    assert!(!is_valid_punctuation(b'\'', b'h', b'h'));
 }
@@ -8,7 +8,7 @@ fn digits_iterator_test() {
     use lexical_util::format::STANDARD;
 
     type Digits<'a> = Bytes<'a, { STANDARD }>;
-    assert!(Digits::IS_CONTIGUOUS);
+    const _: () = assert!(Digits::IS_CONTIGUOUS);
 
     let digits = b"12345";
     let mut byte1 = Digits::new(digits);
@@ -19,8 +19,8 @@ fn digits_iterator_test() {
     let mut iter = byte.integer_iter();
     assert_eq!(iter.as_slice(), &digits[..]);
     assert_eq!(iter.as_ptr(), digits.as_ptr());
-    assert_eq!(iter.is_consumed(), false);
-    assert_eq!(iter.is_buffer_empty(), false);
+    assert!(!iter.is_consumed());
+    assert!(!iter.is_buffer_empty());
     assert_eq!(u32::from_le(iter.peek_u32().unwrap()), 0x34333231);
     assert_eq!(iter.buffer_length(), 5);
     assert_eq!(iter.cursor(), 0);
@@ -69,7 +69,7 @@ fn skip_iterator_test() {
         .build_strict();
 
     type Digits<'a> = Bytes<'a, { FORMAT }>;
-    assert!(!Digits::IS_CONTIGUOUS);
+    const _: () = assert!(!Digits::IS_CONTIGUOUS);
 
     let digits = b"123_45";
     let mut byte1 = Digits::new(digits);
@@ -80,8 +80,8 @@ fn skip_iterator_test() {
     let mut iter = byte.integer_iter();
     assert_eq!(iter.as_slice(), &digits[..]);
     assert_eq!(iter.as_ptr(), digits.as_ptr());
-    assert_eq!(iter.is_consumed(), false);
-    assert_eq!(iter.is_buffer_empty(), false);
+    assert!(!iter.is_consumed());
+    assert!(!iter.is_buffer_empty());
     assert_eq!(iter.buffer_length(), 6);
     assert_eq!(iter.cursor(), 0);
     assert_eq!(iter.current_count(), 0);
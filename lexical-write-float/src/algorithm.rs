@@ -34,6 +34,7 @@ use lexical_util::format::NumberFormat;
 use lexical_util::num::{AsPrimitive, Float};
 use lexical_write_integer::decimal::{Decimal, DecimalCount};
 
+use crate::exact;
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::options::{Options, RoundMode};
 use crate::shared;
@@ -51,6 +52,11 @@ pub fn write_float<F: RawFloat, const FORMAT: u128>(
 
     let fp = to_decimal(float);
     let digit_count = F::digit_count(fp.mant);
+    if float != F::ZERO {
+        if let Some(options) = shared::min_shortest_options(digit_count, options) {
+            return exact::write_float::<_, FORMAT>(float, bytes, &options);
+        }
+    }
     let sci_exp = fp.exp + digit_count as i32 - 1;
 
     // Note that for performance reasons, we write the significant digits
@@ -102,7 +108,10 @@ pub fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
     let mut cursor: usize;
     bytes[0] = bytes[1];
     bytes[1] = decimal_point;
-    if !format.no_exponent_without_fraction() && digit_count == 1 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && digit_count == 1
+        && (options.trim_floats() || options.trim_scientific())
+    {
         cursor = 1;
     } else if digit_count < exact_count {
         // Adjust the number of digits written, by appending zeros.
@@ -119,7 +128,7 @@ pub fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // Won't panic since bytes must be large enough to store all digits.
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent());
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options);
 
     cursor
 }
@@ -980,8 +989,9 @@ impl RoundMode {
     #[inline(always)]
     pub const fn prefer_round_down(&self, significand: u64) -> bool {
         match self {
-            RoundMode::Round => significand % 2 != 0,
-            RoundMode::Truncate => true,
+            RoundMode::Round | RoundMode::HalfEven => significand % 2 != 0,
+            RoundMode::HalfAwayFromZero | RoundMode::Ceiling => false,
+            RoundMode::HalfTowardZero | RoundMode::Truncate | RoundMode::Floor => true,
         }
     }
 }
@@ -1084,15 +1094,13 @@ pub const fn low(pow5: &(u64, u64)) -> u64 {
 /// ROR instruction for 32-bit type.
 #[inline(always)]
 pub const fn rotr32(n: u32, r: u32) -> u32 {
-    let r = r & 31;
-    (n >> r) | (n << (32 - r))
+    n.rotate_right(r)
 }
 
 /// ROR instruction for 64-bit type.
 #[inline(always)]
 pub const fn rotr64(n: u64, r: u64) -> u64 {
-    let r = r & 63;
-    (n >> r) | (n << (64 - r))
+    n.rotate_right(r as u32)
 }
 
 /// Magic numbers for division by a power of 10.
@@ -1262,7 +1270,7 @@ impl DragonboxFloat for f32 {
 
         let r = umul96_lower64(two_f, *pow5);
         let parity = (r >> (64 - beta)) & 1;
-        let is_integer = r >> (32 - beta);
+        let is_integer = (r >> (32 - beta)) as u32;
         (parity != 0, is_integer == 0)
     }
 
@@ -1466,87 +1474,104 @@ impl DragonboxFloat for f64 {
     }
 }
 
+// NOTE: The half-precision floats use the same 64-bit cache and `KAPPA` as
+// `f32`, which has more than enough precision for their smaller mantissas.
+// However, `bf16` has a larger exponent range than `f32`, so we derive the
+// 64-bit powers from the 128-bit `f64` table: the `f32` table is the
+// upper 64 bits, rounded up if any of the lower bits are set.
+//
+// The left endpoint of the interval can be an integer for a much wider range
+// of exponents than for `f32`, since `2fc - 1` is small enough that it can
+// be divisible by `5^-k` for small `k`. The cache is precise enough that the
+// integer checks are exact, so we use them up to the last exponent where
+// `5^-k < 2^(p + 1)`.
 #[cfg(feature = "f16")]
-macro_rules! dragonbox_unimpl {
-    ($($t:ident)*) => ($(
+macro_rules! dragonbox_half_impl {
+    ($($t:ident $digits:literal $div_by_5:literal ;)*) => ($(
         impl DragonboxFloat for $t {
-            const KAPPA: u32 = 0;
-            const DECIMAL_DIGITS: usize = 0;
+            const KAPPA: u32 = 1;
+            const DECIMAL_DIGITS: usize = $digits;
+            const DIV_BY_5_THRESHOLD: i32 = $div_by_5;
 
             type Power = u64;
 
             #[inline(always)]
-            fn digit_count(_: u64) -> usize {
-                unimplemented!()
+            fn digit_count(mantissa: u64) -> usize {
+                f32::digit_count(mantissa)
             }
 
             #[inline(always)]
-            fn write_digits(_: &mut [u8], _: u64) -> usize {
-                unimplemented!()
+            fn write_digits(bytes: &mut [u8], mantissa: u64) -> usize {
+                f32::write_digits(bytes, mantissa)
             }
 
             #[inline(always)]
-            unsafe fn dragonbox_power(_: i32) -> Self::Power {
-                unimplemented!()
+            unsafe fn dragonbox_power(exponent: i32) -> Self::Power {
+                // SAFETY: safe if the exponent is in the correct range.
+                let pow5 = unsafe { f64::dragonbox_power(exponent) };
+                high(&pow5) + (low(&pow5) != 0) as u64
             }
 
             #[inline(always)]
-            fn compute_left_endpoint(_: &Self::Power, _: i32) -> u64 {
-                unimplemented!()
+            fn compute_left_endpoint(pow5: &Self::Power, beta_minus_1: i32) -> u64 {
+                compute_left_endpoint_u64::<Self>(*pow5, beta_minus_1)
             }
 
             #[inline(always)]
-            fn compute_right_endpoint(_: &Self::Power, _: i32) -> u64 {
-                unimplemented!()
+            fn compute_right_endpoint(pow5: &Self::Power, beta_minus_1: i32) -> u64 {
+                compute_right_endpoint_u64::<Self>(*pow5, beta_minus_1)
             }
 
             #[inline(always)]
-            fn compute_round_up(_: &Self::Power, _: i32) -> u64 {
-                unimplemented!()
+            fn compute_round_up(pow5: &Self::Power, beta_minus_1: i32) -> u64 {
+                compute_round_up_u64::<Self>(*pow5, beta_minus_1)
             }
 
             #[inline(always)]
-            fn compute_mul(_: u64, _: &Self::Power) -> (u64, bool) {
-                unimplemented!()
+            fn compute_mul(u: u64, pow5: &Self::Power) -> (u64, bool) {
+                f32::compute_mul(u, pow5)
             }
 
             #[inline(always)]
-            fn compute_mul_parity(_: u64, _: &Self::Power, _: i32) -> (bool, bool) {
-                unimplemented!()
+            fn compute_mul_parity(two_f: u64, pow5: &Self::Power, beta: i32) -> (bool, bool) {
+                f32::compute_mul_parity(two_f, pow5, beta)
             }
 
             #[inline(always)]
-            fn compute_delta(_: &Self::Power, _: i32) -> u32 {
-                unimplemented!()
+            fn compute_delta(pow5: &Self::Power, beta: i32) -> u32 {
+                f32::compute_delta(pow5, beta)
             }
 
             #[inline(always)]
-            fn process_trailing_zeros(_: u64, _: i32) -> (u64, i32) {
-                unimplemented!()
+            fn process_trailing_zeros(mantissa: u64, exponent: i32) -> (u64, i32) {
+                f32::process_trailing_zeros(mantissa, exponent)
             }
 
             #[inline(always)]
-            fn remove_trailing_zeros(_: u64) -> (u64, i32) {
-                unimplemented!()
+            fn remove_trailing_zeros(mantissa: u64) -> (u64, i32) {
+                f32::remove_trailing_zeros(mantissa)
             }
 
             #[inline(always)]
-            fn check_div_pow10(_: u32) -> (u32, bool) {
-                unimplemented!()
+            fn check_div_pow10(n: u32) -> (u32, bool) {
+                f32::check_div_pow10(n)
             }
 
             #[inline(always)]
-            fn div_pow10(_: u32) -> u32 {
-                unimplemented!()
+            fn div_pow10(n: u32) -> u32 {
+                f32::div_pow10(n)
             }
 
             #[inline(always)]
-            fn divide_by_pow10(_: u64, _: u32, _: u64) -> u64 {
-                unimplemented!()
+            fn divide_by_pow10(n: u64, exp: u32, n_max: u64) -> u64 {
+                f32::divide_by_pow10(n, exp, n_max)
             }
         }
     )*);
 }
 
 #[cfg(feature = "f16")]
-dragonbox_unimpl! { bf16 f16 }
+dragonbox_half_impl! {
+    f16 5 22 ;
+    bf16 4 16 ;
+}
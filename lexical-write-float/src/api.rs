@@ -4,18 +4,74 @@
 
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+use lexical_util::constants::FormattedSize;
+use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::STANDARD;
+use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::result::Result;
 use lexical_util::{to_lexical, to_lexical_with_options};
+use lexical_write_integer::write::WriteInteger;
 
 use crate::options::Options;
-use crate::write::WriteFloat;
+use crate::write::{write_float_exact, WriteFloat};
 
 // API
 
 const DEFAULT_OPTIONS: Options = Options::new();
 
+/// Write the float, returning an error rather than panicking.
+///
+/// If the buffer may be too small, the float is first written to a
+/// scratch buffer with [`write_float_exact`], so the exact number of
+/// bytes required can be reported.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn try_write_float<T, const FORMAT: u128>(
+    value: T,
+    bytes: &mut [u8],
+    options: &Options,
+) -> Result<usize>
+where
+    T: WriteFloat,
+    T::Unsigned: FormattedSize + WriteInteger,
+{
+    let format = NumberFormat::<FORMAT> {};
+    let error = format.error();
+    if !error.is_success() {
+        return Err(error);
+    }
+    options.rebuild().build()?;
+
+    #[cfg(feature = "power-of-two")]
+    {
+        let radix = format.radix();
+        let exponent_base = format.exponent_base();
+        let is_valid =
+            matches!((radix, exponent_base), (4, 2) | (8, 2) | (16, 2) | (32, 2) | (16, 4));
+        if radix != exponent_base && !is_valid {
+            return Err(Error::InvalidExponentBase);
+        }
+    }
+
+    if value.is_special() {
+        let special = if value.is_nan() {
+            options.nan_string()
+        } else {
+            options.inf_string()
+        };
+        if special.is_none() || (cfg!(feature = "format") && format.no_special()) {
+            return Err(Error::UnsupportedSpecial);
+        }
+    }
+
+    let size = options.buffer_size_const::<T, FORMAT>();
+    if bytes.len() >= size {
+        Ok(value.write_float::<FORMAT>(bytes, options))
+    } else {
+        write_float_exact::<_, FORMAT>(value, bytes, options)
+    }
+}
+
 // Implement `ToLexical` for numeric type.
 macro_rules! float_to_lexical {
     ($($t:tt ; )*) => ($(
@@ -27,6 +83,14 @@ macro_rules! float_to_lexical {
                 let count = self.write_float::<{ STANDARD }>(bytes, &DEFAULT_OPTIONS);
                 &mut bytes[..count]
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn try_to_lexical(self, bytes: &mut [u8])
+                -> Result<&mut [u8]>
+            {
+                let count = try_write_float::<_, { STANDARD }>(self, bytes, &DEFAULT_OPTIONS)?;
+                Ok(&mut bytes[..count])
+            }
         }
 
         impl ToLexicalWithOptions for $t {
@@ -41,6 +105,17 @@ macro_rules! float_to_lexical {
                 let count = self.write_float::<{ FORMAT }>(bytes, &options);
                 &mut bytes[..count]
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> Result<&'a mut [u8]>
+            {
+                let count = try_write_float::<_, FORMAT>(self, bytes, options)?;
+                Ok(&mut bytes[..count])
+            }
         }
     )*)
 }
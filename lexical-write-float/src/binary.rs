@@ -147,7 +147,10 @@ where
     let exact_count = shared::min_exact_digits(digit_count, options);
 
     // Write any trailing digits to the output.
-    if !format.no_exponent_without_fraction() && cursor == 2 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && cursor == 2
+        && (options.trim_floats() || options.trim_scientific())
+    {
         // Need to trim floats from trailing zeros, and we have only a decimal.
         cursor -= 1;
     } else if exact_count < 2 {
@@ -165,7 +168,7 @@ where
 
     // Now, write our scientific notation.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit);
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent());
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options);
 
     cursor
 }
@@ -739,23 +742,25 @@ where
         let shr = (mantissa_bits - max_bits) as i32;
         shifted_mantissa = mantissa >> shr;
 
-        // We need to round-nearest, tie-even, so we need to handle
-        // the truncation **here**. If the representation is above
-        // halfway at all, we need to round up, even if 1 bit.
-        if options.round_mode() == RoundMode::Round {
+        // We need to handle the truncation **here**. If the representation
+        // is above halfway at all, it is above the halfway point, even if
+        // by 1 bit.
+        if options.round_mode() != RoundMode::Truncate {
             let mask = (M::ONE << shr) - M::ONE;
             let halfway = M::ONE << (shr - 1);
-            let above_halfway = (mantissa & mask) > halfway;
-            let is_halfway = (mantissa & mask) == halfway;
+            let truncated = mantissa & mask;
+            let half = truncated.cmp(&halfway);
+            let is_inexact = truncated != M::ZERO;
             let is_odd = shifted_mantissa & M::ONE == M::ONE;
+            let round_up = shared::should_round_up(options.round_mode(), half, is_inexact, is_odd);
 
             // Round-up and calculate if we carry over 1-bit.
             // The built-in ctlz is very fast, so use that.
             // Add 1 to the mantissa bits if we carry.
             let initial_bits = shifted_mantissa.leading_zeros();
-            shifted_mantissa += as_cast((above_halfway || (is_odd & is_halfway)) as u32);
+            shifted_mantissa += as_cast(round_up as u32);
             let final_bits = shifted_mantissa.leading_zeros();
-            mantissa_bits += (final_bits - initial_bits) as usize;
+            mantissa_bits += (initial_bits - final_bits) as usize;
         }
     }
 
@@ -31,6 +31,7 @@ use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
 use lexical_util::num::{AsPrimitive, Float};
 
+use crate::exact;
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::options::Options;
 use crate::shared;
@@ -66,6 +67,9 @@ pub fn write_float<F: RawFloat, const FORMAT: u128>(
         (1, 0, false)
     } else {
         let (start, k) = grisu(float, &mut digits);
+        if let Some(options) = shared::min_shortest_options(start, options) {
+            return exact::write_float::<_, FORMAT>(float, bytes, &options);
+        }
         let (end, carried) = shared::truncate_and_round_decimal(&mut digits, start, options);
         (end, k + start as i32 - end as i32, carried)
     };
@@ -108,7 +112,10 @@ pub fn write_float_scientific<const FORMAT: u128>(
     let mut cursor: usize;
     bytes[0] = digits[0];
     bytes[1] = decimal_point;
-    if !format.no_exponent_without_fraction() && digit_count == 1 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && digit_count == 1
+        && (options.trim_floats() || options.trim_scientific())
+    {
         // No more digits and need to trim floats.
         cursor = 1;
     } else if digit_count < exact_count {
@@ -135,7 +142,7 @@ pub fn write_float_scientific<const FORMAT: u128>(
     }
 
     // Now, write our scientific notation.
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent());
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options);
 
     cursor
 }
@@ -553,16 +560,4 @@ macro_rules! grisu_impl {
 grisu_impl! { f32 f64 }
 
 #[cfg(feature = "f16")]
-macro_rules! grisu_unimpl {
-    ($($t:ident)*) => ($(
-        impl GrisuFloat for $t {
-            #[inline(always)]
-            fn grisu_power(_: usize) -> u64 {
-                unimplemented!()
-            }
-        }
-    )*);
-}
-
-#[cfg(feature = "f16")]
-grisu_unimpl! { bf16 f16 }
+grisu_impl! { bf16 f16 }
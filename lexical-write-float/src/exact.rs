@@ -0,0 +1,516 @@
+//! Exact digit generation using big-integer arithmetic.
+//!
+//! The shortest-digit algorithms only generate enough digits to uniquely
+//! identify the float, so they cannot write the exact value of the float
+//! to an arbitrary number of digits. This splits the float into its
+//! integer and fractional components as big integers, from which every
+//! digit of the exact value can be extracted, in any radix.
+//!
+//! This is used for fixed-point notation, and to write the exact
+//! significant digits of decimal floats.
+
+#![doc(hidden)]
+
+use core::cmp::Ordering;
+
+use lexical_util::algorithm::copy_to_dst;
+use lexical_util::digit::digit_to_char;
+use lexical_util::format::NumberFormat;
+use lexical_util::num::{AsPrimitive, Float};
+
+use crate::options::Options;
+use crate::shared;
+
+/// Number of 32-bit limbs in the big integer.
+///
+/// The largest integer component is below `2^1024`, and the largest
+/// fractional numerator is below `36 * 2^1074`, so this fits both.
+const LIMBS: usize = 36;
+
+/// Fixed-capacity, little-endian big integer.
+#[derive(Clone)]
+struct Bigint {
+    data: [u32; LIMBS],
+    len: usize,
+}
+
+impl Bigint {
+    /// Create a big integer from a native integer.
+    #[inline(always)]
+    fn from_u64(value: u64) -> Self {
+        let mut x = Self {
+            data: [0; LIMBS],
+            len: 2,
+        };
+        x.data[0] = value as u32;
+        x.data[1] = (value >> 32) as u32;
+        x.normalize();
+        x
+    }
+
+    /// Remove the high, zero limbs.
+    #[inline(always)]
+    fn normalize(&mut self) {
+        while self.len != 0 && self.data[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the number of bits required to store the value.
+    #[inline(always)]
+    fn bit_length(&self) -> usize {
+        if self.len == 0 {
+            0
+        } else {
+            32 * self.len - self.data[self.len - 1].leading_zeros() as usize
+        }
+    }
+
+    /// Shift the value left by `n` bits.
+    fn shl(&mut self, n: usize) {
+        if self.is_zero() {
+            return;
+        }
+        let limbs = n / 32;
+        let bits = n % 32;
+        let len = self.len + limbs + 1;
+        debug_assert!(len <= LIMBS, "big integer overflow");
+        for i in (0..self.len).rev() {
+            let limb = self.data[i] as u64;
+            let shifted = limb << bits;
+            self.data[i + limbs + 1] |= (shifted >> 32) as u32;
+            self.data[i + limbs] = shifted as u32;
+        }
+        self.data[..limbs].fill(0);
+        self.len = len;
+        self.normalize();
+    }
+
+    /// Multiply the value by a small integer.
+    fn mul_small(&mut self, y: u32) {
+        let mut carry = 0u64;
+        for limb in self.data[..self.len].iter_mut() {
+            let product = *limb as u64 * y as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            debug_assert!(self.len < LIMBS, "big integer overflow");
+            self.data[self.len] = carry as u32;
+            self.len += 1;
+        }
+    }
+
+    /// Divide the value by a small integer, returning the remainder.
+    fn div_small(&mut self, y: u32) -> u32 {
+        let mut rem = 0u64;
+        for limb in self.data[..self.len].iter_mut().rev() {
+            let value = (rem << 32) | *limb as u64;
+            *limb = (value / y as u64) as u32;
+            rem = value % y as u64;
+        }
+        self.normalize();
+        rem as u32
+    }
+
+    /// Remove and return the bits at or above `shift`.
+    ///
+    /// The removed bits must fit in 32 bits.
+    fn split_high(&mut self, shift: usize) -> u32 {
+        let index = shift / 32;
+        let bits = shift % 32;
+        if index >= self.len {
+            return 0;
+        }
+        let mut high = self.data[index] as u64 >> bits;
+        if index + 1 < self.len {
+            high |= (self.data[index + 1] as u64) << (32 - bits);
+        }
+        debug_assert!(high <= u32::MAX as u64, "high bits must fit in a limb");
+        self.data[index] &= ((1u64 << bits) - 1) as u32;
+        self.data[index + 1..self.len].fill(0);
+        self.len = index + 1;
+        self.normalize();
+        high as u32
+    }
+
+    /// Compare the value to `2^(shift - 1)`, where the value is below
+    /// `2^shift`.
+    fn cmp_half(&self, shift: usize) -> Ordering {
+        let length = self.bit_length();
+        if length < shift {
+            return Ordering::Less;
+        }
+        debug_assert!(length == shift, "value must be below 2^shift");
+        let half = shift - 1;
+        let index = half / 32;
+        let mask = (1u32 << (half % 32)) - 1;
+        if self.data[index] & mask != 0 || self.data[..index].iter().any(|&x| x != 0) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+/// The exact value of a finite, positive float.
+///
+/// The float is stored as an integer component and a fractional
+/// component of `fraction / 2^shift`.
+pub struct ExactFloat {
+    integer: Bigint,
+    fraction: Bigint,
+    shift: usize,
+    radix: u32,
+}
+
+impl ExactFloat {
+    /// Create the exact representation of a float.
+    pub fn new<F: Float>(float: F, radix: u32) -> Self {
+        let mantissa: u64 = float.mantissa().as_u64();
+        let exponent = float.exponent();
+        if exponent >= 0 {
+            let mut integer = Bigint::from_u64(mantissa);
+            integer.shl(exponent as usize);
+            Self {
+                integer,
+                fraction: Bigint::from_u64(0),
+                shift: 0,
+                radix,
+            }
+        } else {
+            let shift = exponent.unsigned_abs() as usize;
+            let (integer, fraction) = if shift < 64 {
+                (mantissa >> shift, mantissa & ((1u64 << shift) - 1))
+            } else {
+                (0, mantissa)
+            };
+            Self {
+                integer: Bigint::from_u64(integer),
+                fraction: Bigint::from_u64(fraction),
+                shift,
+                radix,
+            }
+        }
+    }
+
+    /// Write the digit values of the integer component, without leading zeros.
+    ///
+    /// This consumes the integer component, and returns the number of
+    /// digits written, which is `0` if the integer component is zero.
+    pub fn write_integer(&mut self, digits: &mut [u8]) -> usize {
+        let mut count = 0;
+        while !self.integer.is_zero() {
+            digits[count] = self.integer.div_small(self.radix) as u8;
+            count += 1;
+        }
+        digits[..count].reverse();
+        count
+    }
+
+    /// Extract the value of the next fractional digit.
+    #[inline(always)]
+    pub fn next_digit(&mut self) -> u8 {
+        if self.fraction.is_zero() {
+            return 0;
+        }
+        self.fraction.mul_small(self.radix);
+        self.fraction.split_high(self.shift) as u8
+    }
+
+    /// Compare the remaining fractional digits to one half of the last digit.
+    #[inline(always)]
+    pub fn cmp_half(&self) -> Ordering {
+        if self.fraction.is_zero() {
+            Ordering::Less
+        } else {
+            self.fraction.cmp_half(self.shift)
+        }
+    }
+
+    /// Get if the remaining fractional digits are all zero.
+    #[inline(always)]
+    pub fn is_fraction_zero(&self) -> bool {
+        self.fraction.is_zero()
+    }
+}
+
+/// Increment the digit values, returning if the carry overflowed.
+#[inline(always)]
+pub fn increment_digits(digits: &mut [u8], radix: u32) -> bool {
+    let max_digit = (radix - 1) as u8;
+    for digit in digits.iter_mut().rev() {
+        if *digit < max_digit {
+            *digit += 1;
+            return false;
+        }
+        *digit = 0;
+    }
+    true
+}
+
+/// Write a finite, positive float with a fixed number of fractional digits.
+///
+/// This never uses scientific notation, and rounds the exact value of
+/// the float, so `0.125` with 2 places is `0.12` when rounding ties to even.
+pub fn write_fixed<F: Float, const FORMAT: u128>(
+    float: F,
+    bytes: &mut [u8],
+    options: &Options,
+    places: usize,
+) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.radix();
+    let mut exact = ExactFloat::new(float, radix);
+
+    // Write the digit values, and round them.
+    let mut integer_count = exact.write_integer(bytes);
+    if integer_count == 0 {
+        bytes[0] = 0;
+        integer_count = 1;
+    }
+    let mut count = integer_count;
+    for digit in bytes[count..count + places].iter_mut() {
+        *digit = exact.next_digit();
+    }
+    count += places;
+    let is_odd = bytes[count - 1] % 2 == 1;
+    let is_inexact = !exact.is_fraction_zero();
+    if shared::should_round_up(options.round_mode(), exact.cmp_half(), is_inexact, is_odd)
+        && increment_digits(&mut bytes[..count], radix)
+    {
+        bytes.copy_within(0..count, 1);
+        bytes[0] = 1;
+        integer_count += 1;
+        count += 1;
+    }
+
+    // Convert the digit values to characters, and add the decimal point.
+    for digit in bytes[..count].iter_mut() {
+        *digit = digit_to_char(*digit as u32);
+    }
+    if places != 0 {
+        bytes.copy_within(integer_count..count, integer_count + 1);
+        bytes[integer_count] = options.decimal_point();
+        count += 1;
+    }
+
+    count
+}
+
+// EXACT DIGITS
+// ------------
+
+/// Maximum number of significant digits in the exact value of a float.
+///
+/// The largest subnormal `f64` has the most, with 767 significant digits.
+pub const MAX_EXACT_DIGITS: usize = 767;
+
+/// Write the exact significant digits of a finite, positive decimal float.
+///
+/// The digits are rounded to the maximum number of significant digits
+/// using the exact value of the float, and trailing zeros are removed.
+/// Returns the number of digits written and the scientific exponent.
+pub fn write_exact_digits<F: Float>(
+    float: F,
+    digits: &mut [u8],
+    options: &Options,
+) -> (usize, i32) {
+    debug_assert!(float > F::ZERO);
+
+    let mut exact = ExactFloat::new(float, 10);
+    let max_digits = options.max_significant_digits().map_or(usize::MAX, |x| x.get());
+
+    // Write the integer digits, or skip the leading fractional zeros.
+    let mut count = exact.write_integer(digits);
+    let mut sci_exp = count as i32 - 1;
+    if count == 0 {
+        loop {
+            let digit = exact.next_digit();
+            if digit != 0 {
+                digits[0] = digit;
+                break;
+            }
+            sci_exp -= 1;
+        }
+        count = 1;
+    }
+    while count < max_digits && !exact.is_fraction_zero() {
+        digits[count] = exact.next_digit();
+        count += 1;
+    }
+
+    // Round the digits, which may have truncated integer digits.
+    let (half, is_inexact) = if count > max_digits {
+        let truncated = digits[max_digits];
+        let is_above =
+            digits[max_digits + 1..count].iter().any(|&x| x != 0) || !exact.is_fraction_zero();
+        count = max_digits;
+        let half = match truncated.cmp(&5) {
+            Ordering::Equal if is_above => Ordering::Greater,
+            ordering => ordering,
+        };
+        (half, truncated != 0 || is_above)
+    } else {
+        (exact.cmp_half(), !exact.is_fraction_zero())
+    };
+    let is_odd = digits[count - 1] % 2 == 1;
+    if shared::should_round_up(options.round_mode(), half, is_inexact, is_odd)
+        && increment_digits(&mut digits[..count], 10)
+    {
+        // All the digits are now zero.
+        digits[0] = 1;
+        sci_exp += 1;
+    }
+
+    while count > 1 && digits[count - 1] == 0 {
+        count -= 1;
+    }
+    for digit in digits[..count].iter_mut() {
+        *digit = digit_to_char(*digit as u32);
+    }
+
+    (count, sci_exp)
+}
+
+/// Write the exact value of a finite, positive decimal float.
+///
+/// This writes every significant digit of the float, rather than the
+/// shortest digits that round-trip, up to the maximum significant digits.
+pub fn write_float<F: Float, const FORMAT: u128>(
+    float: F,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    let mut digits = [0u8; MAX_EXACT_DIGITS];
+    let (digit_count, sci_exp) = write_exact_digits(float, &mut digits, options);
+    write_float!(
+        float,
+        FORMAT,
+        sci_exp,
+        options,
+        write_float_scientific,
+        write_float_positive_exponent,
+        write_float_negative_exponent,
+        bytes => bytes,
+        args => &digits, digit_count, sci_exp, options,
+    )
+}
+
+/// Write float to string in scientific notation.
+pub fn write_float_scientific<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &[u8],
+    digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let exact_count = shared::min_exact_digits(digit_count, options);
+
+    // Write our significant digits, and any trailing zeros.
+    let mut cursor: usize;
+    bytes[0] = digits[0];
+    bytes[1] = options.decimal_point();
+    if !format.no_exponent_without_fraction()
+        && digit_count == 1
+        && (options.trim_floats() || options.trim_scientific())
+    {
+        cursor = 1;
+    } else if digit_count == 1 && exact_count == 1 {
+        bytes[2] = b'0';
+        cursor = 3;
+    } else {
+        copy_to_dst(&mut bytes[2..digit_count + 1], &digits[1..digit_count]);
+        cursor = digit_count + 1;
+        let zeros = exact_count - digit_count;
+        bytes[cursor..cursor + zeros].fill(b'0');
+        cursor += zeros;
+    }
+
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options);
+
+    cursor
+}
+
+/// Write negative float to string without scientific notation.
+///
+/// Has a negative exponent (shift right) and no scientific notation.
+pub fn write_float_negative_exponent<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &[u8],
+    digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    debug_assert!(sci_exp < 0);
+
+    // Write our leading zeros, and then our significant digits.
+    let sci_exp = sci_exp.wrapping_neg() as usize;
+    bytes[0] = b'0';
+    bytes[1] = options.decimal_point();
+    bytes[2..sci_exp + 1].fill(b'0');
+    let mut cursor = sci_exp + 1;
+    copy_to_dst(&mut bytes[cursor..cursor + digit_count], &digits[..digit_count]);
+    cursor += digit_count;
+
+    // Write any trailing zeros.
+    let zeros = shared::min_exact_digits(digit_count, options) - digit_count;
+    bytes[cursor..cursor + zeros].fill(b'0');
+    cursor += zeros;
+
+    cursor
+}
+
+/// Write positive float to string without scientific notation.
+///
+/// Has a positive exponent (shift left) and no scientific notation.
+pub fn write_float_positive_exponent<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &[u8],
+    mut digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    debug_assert!(sci_exp >= 0);
+
+    let leading_digits = sci_exp as usize + 1;
+    let mut cursor: usize;
+    if leading_digits >= digit_count {
+        // Every digit is in the integer component, so pad with zeros.
+        copy_to_dst(&mut bytes[..digit_count], &digits[..digit_count]);
+        bytes[digit_count..leading_digits].fill(b'0');
+        cursor = leading_digits;
+        digit_count = leading_digits;
+        if options.trim_floats() {
+            return cursor;
+        }
+        bytes[cursor] = options.decimal_point();
+        bytes[cursor + 1] = b'0';
+        cursor += 2;
+        digit_count += 1;
+    } else {
+        copy_to_dst(&mut bytes[..leading_digits], &digits[..leading_digits]);
+        bytes[leading_digits] = options.decimal_point();
+        copy_to_dst(
+            &mut bytes[leading_digits + 1..digit_count + 1],
+            &digits[leading_digits..digit_count],
+        );
+        cursor = digit_count + 1;
+    }
+
+    // Write any trailing zeros.
+    let exact_count = shared::min_exact_digits(digit_count, options);
+    if exact_count > digit_count {
+        let zeros = exact_count - digit_count;
+        bytes[cursor..cursor + zeros].fill(b'0');
+        cursor += zeros;
+    }
+
+    cursor
+}
@@ -177,7 +177,10 @@ where
     // Write any trailing digits to the output.
     // Won't panic safe if the buffer is large enough to hold the significant
     // digits.
-    if !format.no_exponent_without_fraction() && cursor == 2 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && cursor == 2
+        && (options.trim_floats() || options.trim_scientific())
+    {
         // Need to trim floats from trailing zeros, and we have only a decimal.
         cursor -= 1;
     } else if exact_count < 2 {
@@ -196,7 +199,7 @@ where
     // Now, write our scientific notation.
     // Won't panic safe if bytes is large enough to store all digits.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit, bits_per_base);
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent());
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options);
 
     cursor
 }
@@ -227,20 +227,23 @@
 //! #### f16
 //!
 //! This enables the use of the half-precision floats [`f16`][`ieee-f16`] and
-//! [`bf16`][`brain-float`]. However, since these have limited hardware support
-//! and are primarily used for vectorized operations, they are formatted as if
-//! they were an [`f32`]. Due to the low precision of 16-bit floats, the results
-//! may appear to have significant rounding error.
+//! [`bf16`][`brain-float`]. These are formatted using the shortest
+//! representation that round-trips to the same half-precision float, rather
+//! than the shortest representation of the equivalent [`f32`].
 //!
 //! ```rust
 //! # #[cfg(feature = "f16")] {
 //! # use core::str;
-//! use lexical_write_float::{f16, FormattedSize, ToLexical};
+//! use lexical_write_float::{bf16, f16, FormattedSize, ToLexical};
 //!
 //! let mut buffer = [0u8; f16::FORMATTED_SIZE];
 //! let value = f16::from_f64_const(1.234f64);
 //! let digits = value.to_lexical(&mut buffer);
-//! assert_eq!(str::from_utf8(digits), Ok("1.234375"));
+//! assert_eq!(str::from_utf8(digits), Ok("1.234"));
+//!
+//! let value = bf16::from_f64_const(0.1f64);
+//! let digits = value.to_lexical(&mut buffer);
+//! assert_eq!(str::from_utf8(digits), Ok("0.1"));
 //! # }
 //! ```
 //!
@@ -332,6 +335,9 @@
 //!   to write.
 //! - The rounding [`mode`][Options::round_mode] when truncating significant
 //!   digits while writing.
+//! - An explicit [`sign`][Options::exponent_plus_sign] for positive exponents,
+//!   and the [`minimum`][Options::min_exponent_digits] number of exponent
+//!   digits to write.
 //!
 //! In addition, pre-defined constants for each category of options may
 //! be found in their respective modules, for example, [`JSON`][`JSON-OPTS`].
@@ -449,8 +455,7 @@
     clippy::unnecessary_safety_comment,
     clippy::semicolon_if_nothing_returned,
     clippy::unwrap_used,
-    clippy::as_underscore,
-    clippy::doc_markdown
+    clippy::as_underscore
 )]
 #![allow(
     // used when concepts are logically separate
@@ -477,6 +482,7 @@ mod shared;
 pub mod algorithm;
 pub mod binary;
 pub mod compact;
+pub mod exact;
 pub mod float;
 pub mod hex;
 pub mod options;
@@ -496,9 +502,9 @@ pub use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
-pub use lexical_util::options::WriteOptions;
+pub use lexical_util::options::{DigitGrouping, WriteOptions};
 pub use lexical_util::result::Result;
 
 pub use self::api::{ToLexical, ToLexicalWithOptions};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder, RoundMode};
+pub use self::options::{Notation, Options, OptionsBuilder, RoundMode};
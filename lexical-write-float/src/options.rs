@@ -203,9 +203,11 @@ use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
 use lexical_util::constants::FormattedSize;
 use lexical_util::error::Error;
 use lexical_util::format::NumberFormat;
-use lexical_util::options::{self, WriteOptions};
+use lexical_util::options::{self, DigitGrouping, WriteOptions};
 use lexical_util::result::Result;
 
+use crate::exact;
+
 // NOTE: Rust guarantees the sizes are the same:
 //  https://doc.rust-lang.org/std/num/struct.NonZero.html
 
@@ -248,14 +250,65 @@ macro_rules! min {
 /// For example, using [`Round`][RoundMode::Round], `1.2345` rounded
 /// to 4 digits would be `1.235`, while [`Truncate`][RoundMode::Truncate]
 /// would be `1.234`.
+///
+/// [`Round`][RoundMode::Round] and [`Truncate`][RoundMode::Truncate]
+/// round the shortest digits that uniquely identify the float. The other
+/// modes round the exact value of the float, like C's `printf` or Java's
+/// `BigDecimal`, so `0.1` with 3 significant digits using
+/// [`Ceiling`][RoundMode::Ceiling] is `0.101`, since the nearest `f64` is
+/// slightly above `0.1`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum RoundMode {
     /// Round to the nearest float string with the given number of significant
     /// digits.
+    ///
+    /// With [`decimal_places`][Options::decimal_places], this rounds the
+    /// exact value of the float, with ties rounded to even.
     Round,
 
     /// Truncate the float string with the given number of significant digits.
     Truncate,
+
+    /// Round the exact value to the nearest digits, with ties rounded to
+    /// an even last digit.
+    HalfEven,
+
+    /// Round the exact value to the nearest digits, with ties rounded away
+    /// from zero.
+    HalfAwayFromZero,
+
+    /// Round the exact value to the nearest digits, with ties rounded toward
+    /// zero.
+    HalfTowardZero,
+
+    /// Round the exact value toward positive infinity.
+    Ceiling,
+
+    /// Round the exact value toward negative infinity.
+    Floor,
+}
+
+/// Notation for the exponent of decimal floats.
+///
+/// For example, `12500.0` is written as `12500.0` using
+/// [`Standard`][Notation::Standard], as `12.5e3` using
+/// [`Engineering`][Notation::Engineering], and as `12.5k` using
+/// [`SiPrefix`][Notation::SiPrefix].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Notation {
+    /// Write fixed or scientific notation, based on the exponent breaks.
+    Standard,
+
+    /// Write scientific notation with an exponent that is a multiple of 3,
+    /// with 1 to 3 integer digits.
+    Engineering,
+
+    /// Write engineering notation with the exponent as an SI prefix, such
+    /// as `k` for `e3` or `µ` for `e-6`.
+    ///
+    /// Exponents without an SI prefix, outside of `[-30, 30]`, are written
+    /// using engineering notation.
+    SiPrefix,
 }
 
 /// Maximum length for a special string.
@@ -304,7 +357,8 @@ pub struct OptionsBuilder {
     /// Note that this isn't fully respected: if you wish to format
     /// `0.1` with 25 significant digits, the correct result **should**
     /// be `0.100000000000000005551115`. However, we would output
-    /// `0.100000000000000000000000`, which is still the nearest float.
+    /// `0.100000000000000000000000`, which is still the nearest float,
+    /// unless [`exact_digits`][Self::exact_digits] is enabled.
     min_significant_digits: OptionUsize,
 
     /// Maximum exponent prior to using scientific notation.
@@ -342,6 +396,41 @@ pub struct OptionsBuilder {
 
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+
+    /// Sizes of the integer digit groups between digit separators.
+    integer_grouping: DigitGrouping,
+
+    /// Sizes of the fraction digit groups between digit separators.
+    fraction_grouping: DigitGrouping,
+
+    /// Write digits above 9 as lowercase letters.
+    lowercase_digits: bool,
+
+    /// Number of digits to write after the decimal point in fixed notation.
+    ///
+    /// If set, this overrides the significant digits and exponent breaks.
+    decimal_places: Option<usize>,
+
+    /// Write the exact significant digits of the float.
+    exact_digits: bool,
+
+    /// Notation for the exponent of decimal floats.
+    notation: Notation,
+
+    /// Write a `+` sign before positive exponents.
+    exponent_plus_sign: bool,
+
+    /// Minimum number of exponent digits to write, padded with zeros.
+    min_exponent_digits: OptionUsize,
+
+    /// Trim the trailing `.0` from floats written in scientific notation.
+    trim_scientific: bool,
+
+    /// Write the sign of negative zero.
+    signed_zero: bool,
+
+    /// Minimum number of digits to choose from for the shortest digits.
+    min_shortest_digits: OptionUsize,
 }
 
 impl OptionsBuilder {
@@ -360,6 +449,17 @@ impl OptionsBuilder {
             decimal_point: b'.',
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
+            integer_grouping: DigitGrouping::THOUSANDS,
+            fraction_grouping: DigitGrouping::NONE,
+            lowercase_digits: false,
+            decimal_places: None,
+            exact_digits: false,
+            notation: Notation::Standard,
+            exponent_plus_sign: false,
+            min_exponent_digits: None,
+            trim_scientific: false,
+            signed_zero: true,
+            min_shortest_digits: None,
         }
     }
 
@@ -584,6 +684,228 @@ impl OptionsBuilder {
         self.inf_string
     }
 
+    /// Get the sizes of the integer digit groups between digit separators.
+    ///
+    /// This is only used if the format has a [`digit_separator`] and
+    /// [`integer_internal_digit_separator`] is enabled. Defaults to
+    /// [`DigitGrouping::THOUSANDS`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{DigitGrouping, Options};
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_integer_grouping(), DigitGrouping::THOUSANDS);
+    /// ```
+    ///
+    /// [`digit_separator`]: lexical_util::format::NumberFormatBuilder::digit_separator
+    /// [`integer_internal_digit_separator`]: lexical_util::format::NumberFormatBuilder::integer_internal_digit_separator
+    #[inline(always)]
+    pub const fn get_integer_grouping(&self) -> DigitGrouping {
+        self.integer_grouping
+    }
+
+    /// Get the sizes of the fraction digit groups between digit separators.
+    ///
+    /// Fraction digits are grouped from the decimal point using the
+    /// primary group size.
+    ///
+    /// This is only used if the format has a [`digit_separator`] and
+    /// [`fraction_internal_digit_separator`] is enabled. Defaults to
+    /// [`DigitGrouping::NONE`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{DigitGrouping, Options};
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_fraction_grouping(), DigitGrouping::NONE);
+    /// ```
+    ///
+    /// [`digit_separator`]: lexical_util::format::NumberFormatBuilder::digit_separator
+    /// [`fraction_internal_digit_separator`]: lexical_util::format::NumberFormatBuilder::fraction_internal_digit_separator
+    #[inline(always)]
+    pub const fn get_fraction_grouping(&self) -> DigitGrouping {
+        self.fraction_grouping
+    }
+
+    /// Get if digits above 9 are written as lowercase letters.
+    ///
+    /// For example, `255.5` in hexadecimal would be `ff.8` rather than
+    /// `FF.8`. This does not change the exponent character. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_lowercase_digits(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_lowercase_digits(&self) -> bool {
+        self.lowercase_digits
+    }
+
+    /// Get the number of digits to write after the decimal point.
+    ///
+    /// If set, the float is always written in fixed-point notation with
+    /// exactly this many fractional digits, rounding the exact binary value
+    /// based on the [`round_mode`]. This ignores the significant digits and
+    /// exponent breaks. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_decimal_places(), None);
+    /// ```
+    ///
+    /// [`round_mode`]: Self::get_round_mode
+    #[inline(always)]
+    pub const fn get_decimal_places(&self) -> Option<usize> {
+        self.decimal_places
+    }
+
+    /// Get if the exact significant digits of the float are written.
+    ///
+    /// If enabled, decimal floats are written using their exact decimal
+    /// expansion, rather than the shortest digits that round-trip, up to
+    /// the [`max_significant_digits`]. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_exact_digits(), false);
+    /// ```
+    ///
+    /// [`max_significant_digits`]: Self::get_max_significant_digits
+    #[inline(always)]
+    pub const fn get_exact_digits(&self) -> bool {
+        self.exact_digits
+    }
+
+    /// Get the notation for the exponent of decimal floats.
+    ///
+    /// [`Engineering`][Notation::Engineering] and
+    /// [`SiPrefix`][Notation::SiPrefix] always write decimal floats with
+    /// an exponent that is a multiple of 3, ignoring the exponent breaks.
+    /// Defaults to [`Notation::Standard`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::options::{Notation, Options};
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_notation(), Notation::Standard);
+    /// ```
+    #[inline(always)]
+    pub const fn get_notation(&self) -> Notation {
+        self.notation
+    }
+
+    /// Get if a `+` sign is written before positive exponents.
+    ///
+    /// If enabled, `1.5e5` is written as `1.5e+5`, unless the number format
+    /// does not allow a positive exponent sign. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_exponent_plus_sign(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_exponent_plus_sign(&self) -> bool {
+        self.exponent_plus_sign
+    }
+
+    /// Get the minimum number of exponent digits to write.
+    ///
+    /// Exponents with fewer digits are padded with leading zeros, after the
+    /// sign. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_min_exponent_digits(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_min_exponent_digits(&self) -> OptionUsize {
+        self.min_exponent_digits
+    }
+
+    /// Get if the trailing `.0` is trimmed in scientific notation.
+    ///
+    /// If enabled, `1.0e20` is written as `1e20`, even if [`trim_floats`]
+    /// is disabled. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_trim_scientific(), false);
+    /// ```
+    ///
+    /// [`trim_floats`]: Self::get_trim_floats
+    #[inline(always)]
+    pub const fn get_trim_scientific(&self) -> bool {
+        self.trim_scientific
+    }
+
+    /// Get if the sign of negative zero is written.
+    ///
+    /// If disabled, `-0.0` is written like `0.0`. Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_signed_zero(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn get_signed_zero(&self) -> bool {
+        self.signed_zero
+    }
+
+    /// Get the minimum number of digits to choose from for the shortest
+    /// digits.
+    ///
+    /// If the shortest digits that round-trip are fewer, the closest number
+    /// with this many significant digits is written instead. Defaults to
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_min_shortest_digits(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_min_shortest_digits(&self) -> OptionUsize {
+        self.min_shortest_digits
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -857,8 +1179,10 @@ impl OptionsBuilder {
     /// Set the string representation for `Infinity`.
     ///
     /// The first character must start with `I` or `i` and all characters must
-    /// be valid ASCII letters (`A-Z` or `a-z`). If set to `None`, then writing
-    /// [`Infinity`][f64::INFINITY] returns an error. Defaults to `inf`.
+    /// be valid ASCII letters (`A-Z` or `a-z`), after an optional leading `+`
+    /// sign which is only written for positive infinity. If set to `None`,
+    /// then writing [`Infinity`][f64::INFINITY] returns an error. Defaults to
+    /// `inf`.
     ///
     /// # Examples
     ///
@@ -917,6 +1241,269 @@ impl OptionsBuilder {
         self.inf_string(inf_string)
     }
 
+    /// Set the sizes of the integer digit groups between digit separators.
+    ///
+    /// This is only used if the format has a [`digit_separator`] and
+    /// [`integer_internal_digit_separator`] is enabled. Defaults to
+    /// [`DigitGrouping::THOUSANDS`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{DigitGrouping, Options};
+    ///
+    /// let builder = Options::builder()
+    ///     .integer_grouping(DigitGrouping::INDIAN);
+    /// assert_eq!(builder.get_integer_grouping(), DigitGrouping::INDIAN);
+    /// ```
+    ///
+    /// [`digit_separator`]: lexical_util::format::NumberFormatBuilder::digit_separator
+    /// [`integer_internal_digit_separator`]: lexical_util::format::NumberFormatBuilder::integer_internal_digit_separator
+    #[inline(always)]
+    pub const fn integer_grouping(mut self, integer_grouping: DigitGrouping) -> Self {
+        self.integer_grouping = integer_grouping;
+        self
+    }
+
+    /// Set the sizes of the fraction digit groups between digit separators.
+    ///
+    /// Fraction digits are grouped from the decimal point using the
+    /// primary group size.
+    ///
+    /// This is only used if the format has a [`digit_separator`] and
+    /// [`fraction_internal_digit_separator`] is enabled. Defaults to
+    /// [`DigitGrouping::NONE`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{DigitGrouping, Options};
+    ///
+    /// let builder = Options::builder()
+    ///     .fraction_grouping(DigitGrouping::THOUSANDS);
+    /// assert_eq!(builder.get_fraction_grouping(), DigitGrouping::THOUSANDS);
+    /// ```
+    ///
+    /// [`digit_separator`]: lexical_util::format::NumberFormatBuilder::digit_separator
+    /// [`fraction_internal_digit_separator`]: lexical_util::format::NumberFormatBuilder::fraction_internal_digit_separator
+    #[inline(always)]
+    pub const fn fraction_grouping(mut self, fraction_grouping: DigitGrouping) -> Self {
+        self.fraction_grouping = fraction_grouping;
+        self
+    }
+
+    /// Set if digits above 9 are written as lowercase letters.
+    ///
+    /// For example, `255.5` in hexadecimal would be `ff.8` rather than
+    /// `FF.8`. This does not change the exponent character. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .lowercase_digits(true);
+    /// assert_eq!(builder.get_lowercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn lowercase_digits(mut self, lowercase_digits: bool) -> Self {
+        self.lowercase_digits = lowercase_digits;
+        self
+    }
+
+    /// Set the number of digits to write after the decimal point.
+    ///
+    /// If set, the float is always written in fixed-point notation with
+    /// exactly this many fractional digits, rounding the exact binary value
+    /// based on the [`round_mode`]. With `0` places, the decimal point is
+    /// not written. This ignores the significant digits and exponent
+    /// breaks. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .decimal_places(Some(2));
+    /// assert_eq!(builder.get_decimal_places(), Some(2));
+    /// ```
+    ///
+    /// [`round_mode`]: Self::round_mode
+    #[inline(always)]
+    pub const fn decimal_places(mut self, decimal_places: Option<usize>) -> Self {
+        self.decimal_places = decimal_places;
+        self
+    }
+
+    /// Set if the exact significant digits of the float are written.
+    ///
+    /// If enabled, decimal floats are written using their exact decimal
+    /// expansion, rather than the shortest digits that round-trip. This
+    /// writes every significant digit, up to 767 for an `f64`, unless
+    /// limited by [`max_significant_digits`], in which case the exact value
+    /// is rounded using the [`round_mode`]. Floats in other radixes are
+    /// unaffected. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .exact_digits(true);
+    /// assert_eq!(builder.get_exact_digits(), true);
+    /// ```
+    ///
+    /// [`max_significant_digits`]: Self::max_significant_digits
+    /// [`round_mode`]: Self::round_mode
+    #[inline(always)]
+    pub const fn exact_digits(mut self, exact_digits: bool) -> Self {
+        self.exact_digits = exact_digits;
+        self
+    }
+
+    /// Set the notation for the exponent of decimal floats.
+    ///
+    /// [`Engineering`][Notation::Engineering] and
+    /// [`SiPrefix`][Notation::SiPrefix] always write decimal floats with
+    /// an exponent that is a multiple of 3, ignoring the exponent breaks.
+    /// Floats in other radixes, or written with [`decimal_places`], are
+    /// unaffected. Defaults to [`Notation::Standard`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::options::{Notation, Options};
+    ///
+    /// let builder = Options::builder()
+    ///     .notation(Notation::Engineering);
+    /// assert_eq!(builder.get_notation(), Notation::Engineering);
+    /// ```
+    ///
+    /// [`decimal_places`]: Self::decimal_places
+    #[inline(always)]
+    pub const fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Set if a `+` sign is written before positive exponents.
+    ///
+    /// If enabled, `1.5e5` is written as `1.5e+5`, and a zero exponent is
+    /// written as `e+0`, unless the number format does not allow a positive
+    /// exponent sign. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .exponent_plus_sign(true);
+    /// assert_eq!(builder.get_exponent_plus_sign(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn exponent_plus_sign(mut self, exponent_plus_sign: bool) -> Self {
+        self.exponent_plus_sign = exponent_plus_sign;
+        self
+    }
+
+    /// Set the minimum number of exponent digits to write.
+    ///
+    /// Exponents with fewer digits are padded with leading zeros, after the
+    /// sign, so `1.5e5` is written as `1.5e05` with 2 digits. Defaults to
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num;
+    ///
+    /// use lexical_write_float::Options;
+    ///
+    /// let minimum = num::NonZeroUsize::new(2);
+    /// let builder = Options::builder()
+    ///     .min_exponent_digits(minimum);
+    /// assert_eq!(builder.get_min_exponent_digits(), minimum);
+    /// ```
+    #[inline(always)]
+    pub const fn min_exponent_digits(mut self, min_exponent_digits: OptionUsize) -> Self {
+        self.min_exponent_digits = min_exponent_digits;
+        self
+    }
+
+    /// Set if the trailing `.0` is trimmed in scientific notation.
+    ///
+    /// If enabled, `1.0e20` is written as `1e20`, even if [`trim_floats`]
+    /// is disabled, so floats written in fixed notation still keep their
+    /// `.0`. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .trim_scientific(true);
+    /// assert_eq!(builder.get_trim_scientific(), true);
+    /// ```
+    ///
+    /// [`trim_floats`]: Self::trim_floats
+    #[inline(always)]
+    pub const fn trim_scientific(mut self, trim_scientific: bool) -> Self {
+        self.trim_scientific = trim_scientific;
+        self
+    }
+
+    /// Set if the sign of negative zero is written.
+    ///
+    /// If disabled, `-0.0` is written like `0.0`. Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .signed_zero(false);
+    /// assert_eq!(builder.get_signed_zero(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn signed_zero(mut self, signed_zero: bool) -> Self {
+        self.signed_zero = signed_zero;
+        self
+    }
+
+    /// Set the minimum number of digits to choose from for the shortest
+    /// digits.
+    ///
+    /// If the shortest digits that round-trip are fewer, the closest number
+    /// with this many significant digits is written instead, which also
+    /// round-trips. With 2 digits, this matches Java, which writes the
+    /// smallest subnormal `f64` as `4.9E-324` rather than `5.0E-324`. This
+    /// only applies to decimal floats. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num;
+    ///
+    /// use lexical_write_float::Options;
+    ///
+    /// let minimum = num::NonZeroUsize::new(2);
+    /// let builder = Options::builder()
+    ///     .min_shortest_digits(minimum);
+    /// assert_eq!(builder.get_min_shortest_digits(), minimum);
+    /// ```
+    #[inline(always)]
+    pub const fn min_shortest_digits(mut self, min_shortest_digits: OptionUsize) -> Self {
+        self.min_shortest_digits = min_shortest_digits;
+        self
+    }
+
     // BUILDERS
 
     /// Determine if [`nan_string`][`Self::nan_string`] is valid.
@@ -950,7 +1537,7 @@ impl OptionsBuilder {
             return true;
         }
 
-        let inf = unwrap_str(self.inf_string);
+        let inf = strip_inf_sign(unwrap_str(self.inf_string));
         let length = inf.len();
         if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
             false
@@ -1007,6 +1594,17 @@ impl OptionsBuilder {
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
+            integer_grouping: self.integer_grouping,
+            fraction_grouping: self.fraction_grouping,
+            lowercase_digits: self.lowercase_digits,
+            decimal_places: self.decimal_places,
+            exact_digits: self.exact_digits,
+            notation: self.notation,
+            exponent_plus_sign: self.exponent_plus_sign,
+            min_exponent_digits: self.min_exponent_digits,
+            trim_scientific: self.trim_scientific,
+            signed_zero: self.signed_zero,
+            min_shortest_digits: self.min_shortest_digits,
         }
     }
 
@@ -1042,7 +1640,7 @@ impl OptionsBuilder {
         }
 
         if self.inf_string.is_some() {
-            let inf = unwrap_str(self.inf_string);
+            let inf = strip_inf_sign(unwrap_str(self.inf_string));
             if inf.is_empty() || !matches!(inf[0], b'I' | b'i') {
                 return Err(Error::InvalidInfString);
             } else if !is_valid_letter_slice(inf) {
@@ -1154,6 +1752,41 @@ pub struct Options {
 
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+
+    /// Sizes of the integer digit groups between digit separators.
+    integer_grouping: DigitGrouping,
+
+    /// Sizes of the fraction digit groups between digit separators.
+    fraction_grouping: DigitGrouping,
+
+    /// Write digits above 9 as lowercase letters.
+    lowercase_digits: bool,
+
+    /// Number of digits to write after the decimal point in fixed notation.
+    ///
+    /// If set, this overrides the significant digits and exponent breaks.
+    decimal_places: Option<usize>,
+
+    /// Write the exact significant digits of the float.
+    exact_digits: bool,
+
+    /// Notation for the exponent of decimal floats.
+    notation: Notation,
+
+    /// Write a `+` sign before positive exponents.
+    exponent_plus_sign: bool,
+
+    /// Minimum number of exponent digits to write, padded with zeros.
+    min_exponent_digits: OptionUsize,
+
+    /// Trim the trailing `.0` from floats written in scientific notation.
+    trim_scientific: bool,
+
+    /// Write the sign of negative zero.
+    signed_zero: bool,
+
+    /// Minimum number of digits to choose from for the shortest digits.
+    min_shortest_digits: OptionUsize,
 }
 
 impl Options {
@@ -1292,7 +1925,7 @@ impl Options {
                 Some(v) => v.get(),
                 None => 9,
             };
-            let exp = max!(min_exp.abs(), max_exp) as usize;
+            let exp = max!(min_exp.unsigned_abs(), max_exp.unsigned_abs()) as usize;
             if cfg!(feature = "power-of-two") && exp < 13 {
                 // 11 for the exponent digits in binary, 1 for the sign, 1 for the symbol
                 count += 13;
@@ -1313,7 +1946,9 @@ impl Options {
 
         // Now add the number of significant digits.
         let radix = format.radix();
-        let formatted_digits = if radix == 10 {
+        let formatted_digits = if radix == 10 && self.uses_exact_digits() {
+            exact::MAX_EXACT_DIGITS
+        } else if radix == 10 {
             // Really should be 18, but add some extra to be cautious.
             28
         } else {
@@ -1339,12 +1974,56 @@ impl Options {
         } else {
             digits
         };
-        count += digits;
+        // Saturate, since the digits and places may be arbitrarily large.
+        count = count.saturating_add(digits);
+
+        // Engineering notation writes up to 2 more integer digits, and
+        // can add a digit to the exponent.
+        if !matches!(self.notation, Notation::Standard) {
+            count += 3;
+        }
+
+        // The exponent can have an explicit sign and be padded with zeros.
+        if self.exponent_plus_sign {
+            count += 1;
+        }
+        if let Some(min_digits) = self.min_exponent_digits {
+            count = count.saturating_add(min_digits.get());
+        }
+
+        // Fixed notation can write every integer digit, and max is ~2^1024.
+        if let Some(places) = self.decimal_places {
+            let integer_digits: usize = if radix == 10 {
+                309
+            } else {
+                1024
+            };
+            count = max!(count, (2 + integer_digits).saturating_add(places));
+        }
 
         // we need to make sure we have at least enough room for the
         // default formatting size, no matter what, just as a precaution.
         count = max!(count, formatted_size);
 
+        // Add room for any digit separators, assuming every digit could be
+        // in the integer or fraction component.
+        if format.has_digit_separator() {
+            if format.integer_internal_digit_separator() {
+                count = count.saturating_add(self.integer_grouping.integer_separators(count));
+            }
+            if format.fraction_internal_digit_separator() {
+                count = count.saturating_add(self.fraction_grouping.fraction_separators(count));
+            }
+        }
+
+        // Add room for the base prefix and suffix.
+        if format.has_base_prefix() {
+            count = count.saturating_add(2);
+        }
+        if format.has_base_suffix() {
+            count = count.saturating_add(1);
+        }
+
         count
     }
 
@@ -1356,6 +2035,17 @@ impl Options {
         self.rebuild().is_valid()
     }
 
+    /// Get if decimal floats are written using their exact digits.
+    ///
+    /// This is true if [`exact_digits`][Self::exact_digits] is enabled, or
+    /// the [`round_mode`][Self::round_mode] rounds the exact value of the
+    /// float to the maximum significant digits.
+    #[inline(always)]
+    pub(crate) const fn uses_exact_digits(&self) -> bool {
+        let is_exact_round = !matches!(self.round_mode, RoundMode::Round | RoundMode::Truncate);
+        self.exact_digits || (is_exact_round && self.max_significant_digits.is_some())
+    }
+
     /// Get the maximum number of significant digits to write.
     ///
     /// This limits the total number of written digits, truncating based
@@ -1608,6 +2298,345 @@ impl Options {
         self.inf_string
     }
 
+    /// Get the sizes of the integer digit groups between digit separators.
+    ///
+    /// This is only used if the format has a [`digit_separator`] and
+    /// [`integer_internal_digit_separator`] is enabled. Defaults to
+    /// [`DigitGrouping::THOUSANDS`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{DigitGrouping, Options};
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .integer_grouping(DigitGrouping::INDIAN)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.integer_grouping(), DigitGrouping::INDIAN);
+    /// ```
+    ///
+    /// [`digit_separator`]: lexical_util::format::NumberFormatBuilder::digit_separator
+    /// [`integer_internal_digit_separator`]: lexical_util::format::NumberFormatBuilder::integer_internal_digit_separator
+    #[inline(always)]
+    pub const fn integer_grouping(&self) -> DigitGrouping {
+        self.integer_grouping
+    }
+
+    /// Get the sizes of the fraction digit groups between digit separators.
+    ///
+    /// Fraction digits are grouped from the decimal point using the
+    /// primary group size.
+    ///
+    /// This is only used if the format has a [`digit_separator`] and
+    /// [`fraction_internal_digit_separator`] is enabled. Defaults to
+    /// [`DigitGrouping::NONE`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{DigitGrouping, Options};
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .fraction_grouping(DigitGrouping::THOUSANDS)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.fraction_grouping(), DigitGrouping::THOUSANDS);
+    /// ```
+    ///
+    /// [`digit_separator`]: lexical_util::format::NumberFormatBuilder::digit_separator
+    /// [`fraction_internal_digit_separator`]: lexical_util::format::NumberFormatBuilder::fraction_internal_digit_separator
+    #[inline(always)]
+    pub const fn fraction_grouping(&self) -> DigitGrouping {
+        self.fraction_grouping
+    }
+
+    /// Get if digits above 9 are written as lowercase letters.
+    ///
+    /// For example, `255.5` in hexadecimal would be `ff.8` rather than
+    /// `FF.8`. This does not change the exponent character. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .lowercase_digits(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.lowercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn lowercase_digits(&self) -> bool {
+        self.lowercase_digits
+    }
+
+    /// Get the number of digits to write after the decimal point.
+    ///
+    /// If set, the float is always written in fixed-point notation with
+    /// exactly this many fractional digits, rounding the exact binary value
+    /// based on the [`round_mode`]. With `0` places, the decimal point is
+    /// not written. This ignores the significant digits and exponent
+    /// breaks. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .decimal_places(Some(2))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.decimal_places(), Some(2));
+    ///
+    /// let mut buffer = [0u8; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    /// let digits = 0.125f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("0.12"));
+    /// let digits = 1e21f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("1000000000000000000000.00"));
+    /// ```
+    ///
+    /// [`round_mode`]: Self::round_mode
+    #[inline(always)]
+    pub const fn decimal_places(&self) -> Option<usize> {
+        self.decimal_places
+    }
+
+    /// Get if the exact significant digits of the float are written.
+    ///
+    /// If enabled, decimal floats are written using their exact decimal
+    /// expansion, rather than the shortest digits that round-trip. This
+    /// writes every significant digit, up to 767 for an `f64`, unless
+    /// limited by [`max_significant_digits`], in which case the exact value
+    /// is rounded using the [`round_mode`]. Floats in other radixes are
+    /// unaffected. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::{num, str};
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .exact_digits(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.exact_digits(), true);
+    ///
+    /// let mut buffer = [0u8; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    /// let digits = 0.1f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("0.1000000000000000055511151231257827021181583404541015625"));
+    ///
+    /// const ROUNDED: Options = Options::builder()
+    ///     .exact_digits(true)
+    ///     .max_significant_digits(num::NonZeroUsize::new(20))
+    ///     .build_strict();
+    /// let digits = 0.1f64.to_lexical_with_options::<STANDARD>(&mut buffer, &ROUNDED);
+    /// assert_eq!(str::from_utf8(digits), Ok("0.10000000000000000555"));
+    /// ```
+    ///
+    /// [`max_significant_digits`]: Self::max_significant_digits
+    /// [`round_mode`]: Self::round_mode
+    #[inline(always)]
+    pub const fn exact_digits(&self) -> bool {
+        self.exact_digits
+    }
+
+    /// Get the notation for the exponent of decimal floats.
+    ///
+    /// [`Engineering`][Notation::Engineering] and
+    /// [`SiPrefix`][Notation::SiPrefix] always write decimal floats with
+    /// an exponent that is a multiple of 3, ignoring the exponent breaks.
+    /// Floats in other radixes, or written with [`decimal_places`], are
+    /// unaffected. Defaults to [`Notation::Standard`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    /// use lexical_write_float::options::Notation;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .notation(Notation::Engineering)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.notation(), Notation::Engineering);
+    ///
+    /// let mut buffer = [0u8; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    /// let digits = 12500.0f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("12.5e3"));
+    ///
+    /// const SI: Options = Options::builder()
+    ///     .notation(Notation::SiPrefix)
+    ///     .trim_floats(true)
+    ///     .build_strict();
+    /// let digits = 3.3e-6f64.to_lexical_with_options::<STANDARD>(&mut buffer, &SI);
+    /// assert_eq!(str::from_utf8(digits), Ok("3.3µ"));
+    /// let digits = 47e-9f64.to_lexical_with_options::<STANDARD>(&mut buffer, &SI);
+    /// assert_eq!(str::from_utf8(digits), Ok("47n"));
+    /// ```
+    ///
+    /// [`decimal_places`]: Self::decimal_places
+    #[inline(always)]
+    pub const fn notation(&self) -> Notation {
+        self.notation
+    }
+
+    /// Get if a `+` sign is written before positive exponents.
+    ///
+    /// If enabled, `1.5e5` is written as `1.5e+5`, and a zero exponent is
+    /// written as `e+0`, unless the number format does not allow a positive
+    /// exponent sign. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .exponent_plus_sign(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.exponent_plus_sign(), true);
+    ///
+    /// let mut buffer = [0u8; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    /// let digits = 1.5e20f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("1.5e+20"));
+    /// ```
+    #[inline(always)]
+    pub const fn exponent_plus_sign(&self) -> bool {
+        self.exponent_plus_sign
+    }
+
+    /// Get the minimum number of exponent digits to write.
+    ///
+    /// Exponents with fewer digits are padded with leading zeros, after the
+    /// sign, so `1.5e5` is written as `1.5e05` with 2 digits. Defaults to
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::{num, str};
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .min_exponent_digits(num::NonZeroUsize::new(2))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.min_exponent_digits(), num::NonZeroUsize::new(2));
+    ///
+    /// let mut buffer = [0u8; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    /// let digits = 1.5e-7f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("1.5e-07"));
+    /// let digits = 1e100f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("1.0e100"));
+    /// ```
+    #[inline(always)]
+    pub const fn min_exponent_digits(&self) -> OptionUsize {
+        self.min_exponent_digits
+    }
+
+    /// Get if the trailing `.0` is trimmed in scientific notation.
+    ///
+    /// If enabled, `1.0e20` is written as `1e20`, even if [`trim_floats`]
+    /// is disabled, so floats written in fixed notation still keep their
+    /// `.0`. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .trim_scientific(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.trim_scientific(), true);
+    ///
+    /// let mut buffer = [0u8; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    /// let digits = 1e20f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("1e20"));
+    /// let digits = 1f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("1.0"));
+    /// ```
+    ///
+    /// [`trim_floats`]: Self::trim_floats
+    #[inline(always)]
+    pub const fn trim_scientific(&self) -> bool {
+        self.trim_scientific
+    }
+
+    /// Get if the sign of negative zero is written.
+    ///
+    /// If disabled, `-0.0` is written like `0.0`. Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .signed_zero(false)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.signed_zero(), false);
+    ///
+    /// let mut buffer = [0u8; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    /// let digits = (-0.0f64).to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("0.0"));
+    /// ```
+    #[inline(always)]
+    pub const fn signed_zero(&self) -> bool {
+        self.signed_zero
+    }
+
+    /// Get the minimum number of digits to choose from for the shortest
+    /// digits.
+    ///
+    /// If the shortest digits that round-trip are fewer, the closest number
+    /// with this many significant digits is written instead, which also
+    /// round-trips. This is ignored if the maximum significant digits are
+    /// fewer. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::{num, str};
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .min_shortest_digits(num::NonZeroUsize::new(2))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.min_shortest_digits(), num::NonZeroUsize::new(2));
+    ///
+    /// let mut buffer = [0u8; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    /// let digits = 5e-324f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("4.9e-324"));
+    /// let digits = 1e-323f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("9.9e-324"));
+    /// let digits = 2.0f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("2.0"));
+    /// ```
+    #[inline(always)]
+    pub const fn min_shortest_digits(&self) -> OptionUsize {
+        self.min_shortest_digits
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -1765,6 +2794,17 @@ impl Options {
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
+            integer_grouping: self.integer_grouping,
+            fraction_grouping: self.fraction_grouping,
+            lowercase_digits: self.lowercase_digits,
+            decimal_places: self.decimal_places,
+            exact_digits: self.exact_digits,
+            notation: self.notation,
+            exponent_plus_sign: self.exponent_plus_sign,
+            min_exponent_digits: self.min_exponent_digits,
+            trim_scientific: self.trim_scientific,
+            signed_zero: self.signed_zero,
+            min_shortest_digits: self.min_shortest_digits,
         }
     }
 }
@@ -1824,6 +2864,15 @@ const fn unwrap_str(option: Option<&'static [u8]>) -> &'static [u8] {
     }
 }
 
+/// Strip the optional leading `+` sign from an Inf string.
+#[inline(always)]
+const fn strip_inf_sign(inf: &'static [u8]) -> &'static [u8] {
+    match inf {
+        [b'+', rest @ ..] => rest,
+        _ => inf,
+    }
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
@@ -2393,3 +3442,129 @@ pub const MYSQL: Options = Options::builder()
 pub const MONGODB: Options = Options::builder()
     .inf_string(options::MONGODB_INF)
     .build_strict();
+
+// LANGUAGE FORMATTERS
+// -------------------
+
+// These reproduce the output of each language's default float formatter,
+// and should be paired with the format of the same name.
+
+/// Number format of [`Number.prototype.toString`] in JavaScript.
+///
+/// Uses the shortest round-trip digits, and scientific notation for
+/// values below `1e-6` or at least `1e21`. Negative zero is written as `0`.
+///
+/// [`Number.prototype.toString`]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toString
+#[rustfmt::skip]
+pub const JAVASCRIPT_TO_STRING: Options = Options::builder()
+    .positive_exponent_break(num::NonZeroI32::new(20))
+    .negative_exponent_break(num::NonZeroI32::new(-6))
+    .trim_floats(true)
+    .exponent_plus_sign(true)
+    .signed_zero(false)
+    .inf_string(options::JAVASCRIPT_INF)
+    .build_strict();
+
+/// Number format of [`repr`] for a float in Python.
+///
+/// Uses the shortest round-trip digits, and scientific notation for
+/// values below `1e-4` or at least `1e16`, with at least 2 exponent digits.
+///
+/// [`repr`]: https://docs.python.org/3/library/functions.html#repr
+#[rustfmt::skip]
+pub const PYTHON_REPR: Options = Options::builder()
+    .positive_exponent_break(num::NonZeroI32::new(15))
+    .negative_exponent_break(num::NonZeroI32::new(-4))
+    .trim_scientific(true)
+    .exponent_plus_sign(true)
+    .min_exponent_digits(num::NonZeroUsize::new(2))
+    .nan_string(options::PYTHON_REPR_NAN)
+    .build_strict();
+
+/// Number format of [`strconv.FormatFloat`] in Go, as `FormatFloat(f, 'g', -1, 64)`.
+///
+/// Uses the shortest round-trip digits, and scientific notation for
+/// values below `1e-4` or at least `1e6`, with at least 2 exponent digits.
+///
+/// [`strconv.FormatFloat`]: https://pkg.go.dev/strconv#FormatFloat
+#[rustfmt::skip]
+pub const GO_FORMAT_FLOAT: Options = Options::builder()
+    .positive_exponent_break(num::NonZeroI32::new(5))
+    .negative_exponent_break(num::NonZeroI32::new(-4))
+    .trim_floats(true)
+    .exponent_plus_sign(true)
+    .min_exponent_digits(num::NonZeroUsize::new(2))
+    .inf_string(options::GO_FORMAT_FLOAT_INF)
+    .build_strict();
+
+/// Number format of [`Double.toString`] in Java 19 and later.
+///
+/// Uses the shortest round-trip digits, and scientific notation for
+/// values below `1e-3` or at least `1e7`. Like Java, if 1 digit
+/// round-trips, the closest number with 2 digits is written, so the
+/// smallest subnormal `f64` is written as `4.9E-324`.
+///
+/// [`Double.toString`]: https://docs.oracle.com/en/java/javase/21/docs/api/java.base/java/lang/Double.html#toString(double)
+#[rustfmt::skip]
+pub const JAVA_TO_STRING: Options = Options::builder()
+    .positive_exponent_break(num::NonZeroI32::new(6))
+    .negative_exponent_break(num::NonZeroI32::new(-3))
+    .exponent(b'E')
+    .inf_string(options::JAVA_STRING_INF)
+    .min_shortest_digits(num::NonZeroUsize::new(2))
+    .build_strict();
+
+/// Number format of the [`%g`] conversion of `printf` in C.
+///
+/// Rounds the exact value to 6 significant digits, and uses scientific
+/// notation for values below `1e-4` or at least `1e6`, with at least 2
+/// exponent digits.
+///
+/// [`%g`]: https://en.cppreference.com/w/c/io/fprintf
+#[rustfmt::skip]
+pub const C_PRINTF_G: Options = Options::builder()
+    .max_significant_digits(num::NonZeroUsize::new(6))
+    .round_mode(RoundMode::HalfEven)
+    .positive_exponent_break(num::NonZeroI32::new(5))
+    .negative_exponent_break(num::NonZeroI32::new(-4))
+    .trim_floats(true)
+    .exponent_plus_sign(true)
+    .min_exponent_digits(num::NonZeroUsize::new(2))
+    .nan_string(options::C_PRINTF_NAN)
+    .build_strict();
+
+/// Number format of the [`%e`] conversion of `printf` in C.
+///
+/// Rounds the exact value to 7 significant digits, and always uses
+/// scientific notation with at least 2 exponent digits, when paired with
+/// the format of the same name.
+///
+/// [`%e`]: https://en.cppreference.com/w/c/io/fprintf
+#[rustfmt::skip]
+pub const C_PRINTF_E: Options = Options::builder()
+    .max_significant_digits(num::NonZeroUsize::new(7))
+    .min_significant_digits(num::NonZeroUsize::new(7))
+    .round_mode(RoundMode::HalfEven)
+    .exponent_plus_sign(true)
+    .min_exponent_digits(num::NonZeroUsize::new(2))
+    .nan_string(options::C_PRINTF_NAN)
+    .build_strict();
+
+/// Number format of the [`%.17g`] conversion of `printf` in C.
+///
+/// Rounds the exact value to 17 significant digits, and uses scientific
+/// notation for values below `1e-4` or at least `1e17`, with at least 2
+/// exponent digits.
+///
+/// [`%.17g`]: https://en.cppreference.com/w/c/io/fprintf
+#[rustfmt::skip]
+pub const C_PRINTF_G17: Options = Options::builder()
+    .max_significant_digits(num::NonZeroUsize::new(17))
+    .round_mode(RoundMode::HalfEven)
+    .positive_exponent_break(num::NonZeroI32::new(16))
+    .negative_exponent_break(num::NonZeroI32::new(-4))
+    .trim_floats(true)
+    .exponent_plus_sign(true)
+    .min_exponent_digits(num::NonZeroUsize::new(2))
+    .nan_string(options::C_PRINTF_NAN)
+    .build_strict();
@@ -12,6 +12,8 @@
 #![cfg(feature = "radix")]
 #![doc(hidden)]
 
+use core::cmp::Ordering;
+
 use lexical_util::algorithm::{copy_to_dst, ltrim_char_count, rtrim_char_count};
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 use lexical_util::digit::{char_to_digit_const, digit_to_char_const};
@@ -34,12 +36,67 @@ use crate::shared;
 /// # Panics
 ///
 /// Panics if exponent notation is used.
-#[allow(clippy::collapsible_if)] // reason="conditions are different logical concepts"
 pub fn write_float<F: Float, const FORMAT: u128>(
     float: F,
     bytes: &mut [u8],
     options: &Options,
 ) -> usize
+where
+    <F as Float>::Unsigned: WriteInteger + FormattedSize,
+{
+    // We only compute fractional digits up to the input double's precision.
+    // This fails if the value is at f64::MAX. IF we take the next positive,
+    // we'll get literal infinite. We don't care about NaN comparisons, since
+    // the float **must** be finite, so do this.
+    let delta = if float.to_bits() == F::MAX.to_bits() {
+        F::as_cast(0.5) * (float - float.prev_positive())
+    } else {
+        F::as_cast(0.5) * (float.next_positive() - float)
+    };
+    write_float_delta::<_, FORMAT>(float, delta, bytes, options)
+}
+
+/// Naive float-to-string algorithm for half-precision floats.
+///
+/// Half-precision floats do not have native arithmetic, so the digits are
+/// generated using `f32`. However, the digits are only generated up to the
+/// precision of the half-precision float, so this writes the shortest
+/// representation that round-trips to `F`, not to the equivalent `f32`.
+/// Half the distance to the neighboring floats is always exact as an `f32`,
+/// and we use the distance to the closer neighbor, so powers of two, which
+/// are closer to the previous float, also round-trip.
+///
+/// This has the same preconditions as [`write_float`].
+pub fn write_float_half<F: Float, const FORMAT: u128>(
+    float: F,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    debug_assert!(F::BITS < 32);
+    let value = float.as_f32();
+    let delta = if value == 0.0 {
+        0.5 * float.next_positive().as_f32()
+    } else if float.to_bits() == F::MAX.to_bits() {
+        0.5 * (value - float.prev_positive().as_f32())
+    } else {
+        let previous = value - float.prev_positive().as_f32();
+        let next = float.next_positive().as_f32() - value;
+        0.5 * previous.min(next)
+    };
+    write_float_delta::<_, FORMAT>(value, delta, bytes, options)
+}
+
+/// Naive float-to-string algorithm, writing digits up to `delta`.
+///
+/// `delta` is half the distance to the neighboring floats, which
+/// determines the number of fraction digits written.
+#[allow(clippy::collapsible_if)] // reason="conditions are different logical concepts"
+fn write_float_delta<F: Float, const FORMAT: u128>(
+    float: F,
+    delta: F,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize
 where
     <F as Float>::Unsigned: WriteInteger + FormattedSize,
 {
@@ -71,16 +128,7 @@ where
     let mut integer = float.floor();
     let mut fraction = float - integer;
 
-    // We only compute fractional digits up to the input double's precision.
-    // This fails if the value is at f64::MAX. IF we take the next positive,
-    // we'll get literal infinite. We don't care about NaN comparisons, since
-    // the float **must** be finite, so do this.
-    let mut delta = if float.to_bits() == F::MAX.to_bits() {
-        F::as_cast(0.5) * (float - float.prev_positive())
-    } else {
-        F::as_cast(0.5) * (float.next_positive() - float)
-    };
-    delta = F::ZERO.next_positive().max_finite(delta);
+    let mut delta = F::ZERO.next_positive().max_finite(delta);
     debug_assert!(delta > F::ZERO);
 
     // Write our fraction digits.
@@ -234,7 +282,10 @@ pub fn write_float_scientific<const FORMAT: u128>(
 
     // Write any trailing digits to the output.
     // Won't panic since bytes cannot be empty.
-    if !format.no_exponent_without_fraction() && cursor == 2 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && cursor == 2
+        && (options.trim_floats() || options.trim_scientific())
+    {
         // Need to trim floats from trailing zeros, and we have only a decimal.
         cursor -= 1;
     } else if exact_count < 2 {
@@ -251,7 +302,7 @@ pub fn write_float_scientific<const FORMAT: u128>(
     }
 
     // Now, write our scientific notation.
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent());
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options);
 
     cursor
 }
@@ -370,7 +421,6 @@ const MAX_DIGIT_LENGTH: usize = BUFFER_SIZE - MAX_NONDIGIT_LENGTH;
 /// of significant digits. Returns the number of digits of the mantissa,
 /// and if the rounding did a full carry.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-#[allow(clippy::comparison_chain)] // reason="conditions are different logical concepts"
 pub fn truncate_and_round(
     buffer: &mut [u8],
     start: usize,
@@ -389,6 +439,9 @@ pub fn truncate_and_round(
         return (digit_count, false);
     };
 
+    // Need to add the number of leading zeros to the digits `digit_count`,
+    // which can be more than the significant digits for small fractions.
+    let max_digits = max_digits + ltrim_char_count(&buffer[start..end], b'0');
     if max_digits >= digit_count {
         return (digit_count, false);
     }
@@ -397,49 +450,37 @@ pub fn truncate_and_round(
         return (max_digits, false);
     }
 
-    // Need to add the number of leading zeros to the digits `digit_count`.
-    let max_digits = {
-        let digits = &mut buffer[start..start + max_digits];
-        max_digits + ltrim_char_count(digits, b'0')
-    };
-
-    // We need to round-nearest, tie-even, so we need to handle
-    // the truncation **here**. If the representation is above
-    // halfway at all, we need to round up, even if 1 bit.
+    // Compare the truncated digits to the halfway point. If the
+    // representation is above halfway at all, it is above the halfway
+    // point, even if by 1 digit.
     let last = buffer[start + max_digits - 1];
     let first = buffer[start + max_digits];
+    let truncated = &buffer[start + max_digits + 1..end];
     let halfway = digit_to_char_const(radix / 2, radix);
     let rem = radix % 2;
-    if first < halfway {
-        // Just truncate, going to round-down anyway.
-        (max_digits, false)
-    } else if first > halfway {
-        // Round-up always.
-        let digits = &mut buffer[start..start + max_digits];
-        shared::round_up(digits, max_digits, radix)
+    let half = if first != halfway {
+        first.cmp(&halfway)
     } else if rem == 0 {
         // Even radix, our halfway point `$c00000.....`.
-        let truncated = &buffer[start + max_digits + 1..end];
-        if truncated.iter().all(|&x| x == b'0') && last & 1 == 0 {
-            // At an exact halfway point, and even, round-down.
-            (max_digits, false)
+        if truncated.iter().all(|&x| x == b'0') {
+            Ordering::Equal
         } else {
-            // Above halfway or at halfway and even, round-up
-            let digits = &mut buffer[start..start + max_digits];
-            shared::round_up(digits, max_digits, radix)
+            Ordering::Greater
         }
     } else {
         // Odd radix, our halfway point is `$c$c$c$c$c$c....`. Cannot halfway points.
-        let truncated = &buffer[start + max_digits + 1..end];
-        for &c in truncated.iter() {
-            if c < halfway {
-                return (max_digits, false);
-            } else if c > halfway {
-                // Above halfway
-                let digits = &mut buffer[start..start + max_digits];
-                return shared::round_up(digits, max_digits, radix);
-            }
-        }
+        truncated
+            .iter()
+            .map(|c| c.cmp(&halfway))
+            .find(|&x| x != Ordering::Equal)
+            .unwrap_or(Ordering::Less)
+    };
+    let is_inexact = first != b'0' || truncated.iter().any(|&x| x != b'0');
+    let is_odd = last & 1 == 1;
+    if shared::should_round_up(options.round_mode(), half, is_inexact, is_odd) {
+        let digits = &mut buffer[start..start + max_digits];
+        shared::round_up(digits, max_digits, radix)
+    } else {
         (max_digits, false)
     }
 }
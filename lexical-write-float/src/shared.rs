@@ -1,5 +1,7 @@
 //! Shared utilities for writing floats.
 
+use core::cmp::Ordering;
+
 use lexical_util::digit::{char_to_valid_digit_const, digit_to_char_const};
 use lexical_util::format::NumberFormat;
 use lexical_write_integer::write::WriteInteger;
@@ -16,6 +18,53 @@ pub fn min_exact_digits(digit_count: usize, options: &Options) -> usize {
     exact_count
 }
 
+/// Get the options to write the minimum digits for the shortest digits.
+///
+/// If the shortest digits are fewer than the minimum, the exact value of
+/// the float rounded to the minimum digits is the closest number with
+/// that many digits. It also round-trips, since it is at least as close
+/// as the shortest digits padded to the minimum.
+#[inline(always)]
+pub fn min_shortest_options(digit_count: usize, options: &Options) -> Option<Options> {
+    let min_digits = options.min_shortest_digits()?;
+    let is_limited = matches!(options.max_significant_digits(), Some(max) if max < min_digits);
+    if digit_count >= min_digits.get() || is_limited {
+        return None;
+    }
+    let options = options
+        .rebuild()
+        .max_significant_digits(Some(min_digits))
+        .round_mode(RoundMode::HalfEven)
+        .min_shortest_digits(None)
+        .build_unchecked();
+    Some(options)
+}
+
+/// Determine if the truncated digits must be rounded up.
+///
+/// The float must be positive, since the directed rounding modes are
+/// mirrored for negative floats before writing. `half` compares the
+/// truncated digits to one half of the last kept digit, `is_inexact`
+/// is if any truncated digit is non-zero, and `is_odd` is if the last
+/// kept digit is odd.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn should_round_up(
+    round_mode: RoundMode,
+    half: Ordering,
+    is_inexact: bool,
+    is_odd: bool,
+) -> bool {
+    match round_mode {
+        RoundMode::Round | RoundMode::HalfEven => {
+            half == Ordering::Greater || (half == Ordering::Equal && is_odd)
+        },
+        RoundMode::HalfAwayFromZero => half != Ordering::Less,
+        RoundMode::HalfTowardZero => half == Ordering::Greater,
+        RoundMode::Ceiling => is_inexact,
+        RoundMode::Truncate | RoundMode::Floor => false,
+    }
+}
+
 /// Round-up the last digit, from a buffer of digits.
 ///
 /// Round up the last digit, incrementally handling all subsequent
@@ -53,7 +102,6 @@ pub fn round_up(digits: &mut [u8], count: usize, radix: u32) -> (usize, bool) {
 /// relative to the digits. Returns the digit count, resulting exp, and if
 /// the input carried to the next digit.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-#[allow(clippy::comparison_chain)] // reason="conditions are different logical concepts"
 pub fn truncate_and_round_decimal(
     digits: &mut [u8],
     digit_count: usize,
@@ -76,35 +124,26 @@ pub fn truncate_and_round_decimal(
         return (max_digits, false);
     }
 
-    // We need to round-nearest, tie-even, so we need to handle
-    // the truncation **here**. If the representation is above
-    // halfway at all, we need to round up, even if 1 digit.
-
-    // Get the last non-truncated digit, and the remaining ones.
+    // Compare the truncated digits to the halfway point. If the
+    // representation is above halfway at all, it is above the halfway
+    // point, even if by 1 digit.
     // Won't panic if `digit_count < digits.len()`, since `max_digits <
     // digit_count`.
     let truncated = digits[max_digits];
-    let (digits, carried) = if truncated < b'5' {
-        // Just truncate, going to round-down anyway.
-        (max_digits, false)
-    } else if truncated > b'5' {
-        // Round-up always.
+    let is_above = digits[max_digits + 1..digit_count].iter().any(|&x| x != b'0');
+    let half = match truncated.cmp(&b'5') {
+        Ordering::Equal if is_above => Ordering::Greater,
+        ordering => ordering,
+    };
+    let is_inexact = truncated != b'0' || is_above;
+    let is_odd = digits[max_digits - 1] % 2 == 1;
+    if should_round_up(options.round_mode(), half, is_inexact, is_odd) {
+        // Won't panic `digit_count <= digits.len()`, because `max_digits <
+        // digit_count`.
         round_up(digits, max_digits, 10)
     } else {
-        // Have a near-halfway case, resolve it.
-        let to_round = &digits[max_digits - 1..digit_count];
-        let is_odd = to_round[0] % 2 == 1;
-        let is_above = to_round[2..].iter().any(|&x| x != b'0');
-        if is_odd || is_above {
-            // Won't panic `digit_count <= digits.len()`, because `max_digits <
-            // digit_count`.
-            round_up(digits, max_digits, 10)
-        } else {
-            (max_digits, false)
-        }
-    };
-
-    (digits, carried)
+        (max_digits, false)
+    }
 }
 
 /// Write the sign for the exponent.
@@ -113,13 +152,18 @@ pub fn write_exponent_sign<const FORMAT: u128>(
     bytes: &mut [u8],
     cursor: &mut usize,
     exp: i32,
+    options: &Options,
 ) -> u32 {
     let format = NumberFormat::<{ FORMAT }> {};
     if exp < 0 {
         bytes[*cursor] = b'-';
         *cursor += 1;
         exp.wrapping_neg() as u32
-    } else if cfg!(feature = "format") && format.required_exponent_sign() {
+    } else if cfg!(feature = "format") && format.no_positive_exponent_sign() {
+        exp as u32
+    } else if options.exponent_plus_sign()
+        || (cfg!(feature = "format") && format.required_exponent_sign())
+    {
         bytes[*cursor] = b'+';
         *cursor += 1;
         exp as u32
@@ -129,17 +173,28 @@ pub fn write_exponent_sign<const FORMAT: u128>(
 }
 
 /// Write the symbol, sign, and digits for the exponent.
+///
+/// The digits are padded with leading zeros to the minimum exponent digits.
 #[cfg_attr(not(feature = "compact"), inline(always))]
 pub fn write_exponent<const FORMAT: u128>(
     bytes: &mut [u8],
     cursor: &mut usize,
     exp: i32,
-    exponent_character: u8,
+    options: &Options,
 ) {
-    bytes[*cursor] = exponent_character;
+    bytes[*cursor] = options.exponent();
     *cursor += 1;
-    let positive_exp: u32 = write_exponent_sign::<FORMAT>(bytes, cursor, exp);
-    *cursor += positive_exp.write_exponent_signed::<FORMAT>(&mut bytes[*cursor..]);
+    let positive_exp: u32 = write_exponent_sign::<FORMAT>(bytes, cursor, exp, options);
+    let count = positive_exp.write_exponent_signed::<FORMAT>(&mut bytes[*cursor..]);
+    let min_digits = options.min_exponent_digits().map_or(0, |x| x.get());
+    if count < min_digits {
+        let padding = min_digits - count;
+        bytes.copy_within(*cursor..*cursor + count, *cursor + padding);
+        bytes[*cursor..*cursor + padding].fill(b'0');
+        *cursor += min_digits;
+    } else {
+        *cursor += count;
+    }
 }
 
 /// Detect the notation to use for the float formatter and call the appropriate
@@ -179,7 +234,10 @@ macro_rules! write_float {
         let max_exp = $options.positive_exponent_break().map_or(9, |x| x.get());
 
         let outside_break = $sci_exp < min_exp || $sci_exp > max_exp;
-        let require_exponent = format.required_exponent_notation() || outside_break;
+        let is_engineering = format.radix() == 10
+            && !matches!($options.notation(), $crate::options::Notation::Standard);
+        let require_exponent =
+            format.required_exponent_notation() || outside_break || is_engineering;
         if !format.no_exponent_notation() && require_exponent {
             // Write digits in scientific notation.
             $write_scientific::<$($generic,)? FORMAT>($bytes, $($args,)*)
@@ -2,13 +2,20 @@
 
 #![doc(hidden)]
 
+use core::num::{NonZeroI32, NonZeroUsize};
+
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+use lexical_util::digit::char_to_valid_digit_const;
+use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
+use lexical_util::options::{si_prefix, DigitGrouping};
+use lexical_util::result::Result;
 use lexical_util::{algorithm::copy_to_dst, constants::FormattedSize};
-use lexical_write_integer::write::WriteInteger;
+use lexical_write_integer::grouping::{write_fraction_separators, write_integer_separators};
+use lexical_write_integer::write::{write_base_prefix, write_base_suffix, WriteInteger};
 
 /// Select the back-end.
 #[cfg(not(feature = "compact"))]
@@ -17,12 +24,14 @@ use crate::algorithm::write_float as write_float_decimal;
 use crate::binary;
 #[cfg(feature = "compact")]
 use crate::compact::write_float as write_float_decimal;
+use crate::exact;
 use crate::float::RawFloat;
 #[cfg(feature = "power-of-two")]
 use crate::hex;
-use crate::options::Options;
+use crate::options::{Notation, OptionI32, OptionUsize, Options, RoundMode};
 #[cfg(feature = "radix")]
 use crate::radix;
+use crate::shared;
 
 /// Write an special string to the buffer.
 #[inline(always)]
@@ -48,15 +57,271 @@ fn write_nan(bytes: &mut [u8], options: &Options, count: usize) -> usize {
 }
 
 /// Write an Inf string to the buffer.
+///
+/// The Inf string may start with a `+` sign, which is skipped if a sign
+/// was already written.
 fn write_inf(bytes: &mut [u8], options: &Options, count: usize) -> usize {
+    let inf_string = match options.inf_string() {
+        Some([b'+', rest @ ..]) if count != 0 => Some(rest),
+        inf_string => inf_string,
+    };
     count
         + write_special(
             bytes,
-            options.inf_string(),
+            inf_string,
             "Inf explicitly disabled but asked to write Inf as string.",
         )
 }
 
+/// Convert the digits above 9 to lowercase letters.
+///
+/// This skips the exponent character, which may be a letter.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_lowercase_digits(bytes: &mut [u8], count: usize, options: &Options) {
+    let exponent = options.exponent();
+    for c in bytes[..count].iter_mut() {
+        if *c != exponent {
+            c.make_ascii_lowercase();
+        }
+    }
+}
+
+/// Get the number of integer and fraction digits in a written float.
+///
+/// `bytes` must contain the written float, without the sign.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn digit_counts(bytes: &[u8], options: &Options) -> (usize, usize) {
+    let decimal_point = options.decimal_point();
+    let exponent = options.exponent();
+    let is_end = |c: u8| c == decimal_point || c == exponent;
+    let integer_count = bytes.iter().position(|&c| is_end(c)).unwrap_or(bytes.len());
+    let fraction_count = match bytes.get(integer_count) {
+        Some(&c) if c == decimal_point => {
+            let fraction = &bytes[integer_count + 1..];
+            fraction.iter().position(|&c| c == exponent).unwrap_or(fraction.len())
+        },
+        _ => 0,
+    };
+    (integer_count, fraction_count)
+}
+
+/// Get the grouping of the integer and fraction digits for the format.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn digit_groupings<const FORMAT: u128>(options: &Options) -> (DigitGrouping, DigitGrouping) {
+    let format = NumberFormat::<FORMAT> {};
+    let integer_grouping = if format.integer_internal_digit_separator() {
+        options.integer_grouping()
+    } else {
+        DigitGrouping::NONE
+    };
+    let fraction_grouping = if format.fraction_internal_digit_separator() {
+        options.fraction_grouping()
+    } else {
+        DigitGrouping::NONE
+    };
+    (integer_grouping, fraction_grouping)
+}
+
+/// Insert digit separators into the integer and fraction digits.
+///
+/// `bytes[..count]` must contain the written float, without the sign.
+/// Returns the number of bytes written, including the separators.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_digit_separators<const FORMAT: u128>(
+    bytes: &mut [u8],
+    count: usize,
+    options: &Options,
+) -> usize {
+    let separator = NumberFormat::<FORMAT> {}.digit_separator();
+    let decimal_point = options.decimal_point();
+    let (integer_count, fraction_count) = digit_counts(&bytes[..count], options);
+    let has_fraction = integer_count < count && bytes[integer_count] == decimal_point;
+    let fraction_start = integer_count + has_fraction as usize;
+    let fraction_end = fraction_start + fraction_count;
+
+    let (integer_grouping, fraction_grouping) = digit_groupings::<FORMAT>(options);
+    let integer_shift = integer_grouping.integer_separators(integer_count);
+    let fraction_shift = fraction_grouping.fraction_separators(fraction_count);
+    if integer_shift == 0 && fraction_shift == 0 {
+        return count;
+    }
+
+    // Move every component to its final position, starting from the back,
+    // so we never overwrite bytes we have yet to move.
+    let shift = integer_shift + fraction_shift;
+    bytes.copy_within(fraction_end..count, fraction_end + shift);
+    if has_fraction {
+        let start = fraction_start + integer_shift;
+        bytes.copy_within(fraction_start..fraction_end, start);
+        write_fraction_separators(
+            &mut bytes[start..],
+            fraction_count,
+            separator,
+            fraction_grouping,
+        );
+        bytes[start - 1] = decimal_point;
+    }
+    write_integer_separators(bytes, integer_count, separator, integer_grouping);
+
+    count + shift
+}
+
+/// Parse the exponent digits, and optional sign, written by our writers.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn parse_exponent<const FORMAT: u128>(bytes: &[u8]) -> i32 {
+    let radix = NumberFormat::<FORMAT> {}.exponent_radix();
+    let (is_negative, digits) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    let exp = digits
+        .iter()
+        .fold(0i32, |exp, &c| exp * radix as i32 + char_to_valid_digit_const(c, radix) as i32);
+    if is_negative {
+        -exp
+    } else {
+        exp
+    }
+}
+
+/// Convert a decimal float in scientific notation to engineering notation.
+///
+/// This moves the decimal point so the exponent is a multiple of 3, padding
+/// the integer digits with zeros if required. `bytes[..count]` must
+/// contain the written float, without the sign, and floats without an
+/// exponent are unchanged. Returns the number of bytes written.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_engineering<const FORMAT: u128>(
+    bytes: &mut [u8],
+    count: usize,
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    let decimal_point = options.decimal_point();
+    let exponent = options.exponent();
+    let exponent_index = match bytes[..count].iter().position(|&c| c == exponent) {
+        Some(index) => index,
+        None => return count,
+    };
+
+    let sci_exp = parse_exponent::<FORMAT>(&bytes[exponent_index + 1..count]);
+
+    // Shift the decimal point to the right, padding with zeros if we run
+    // out of fraction digits.
+    let shift = sci_exp.rem_euclid(3) as usize;
+    let mut cursor = exponent_index;
+    if bytes[..cursor].contains(&decimal_point) {
+        let mut point = 1;
+        for _ in 0..shift {
+            if point + 1 < cursor {
+                bytes.swap(point, point + 1);
+            } else {
+                bytes[point] = b'0';
+                bytes[point + 1] = decimal_point;
+                cursor += 1;
+            }
+            point += 1;
+        }
+        if point + 1 == cursor {
+            let can_trim = options.trim_floats() || options.trim_scientific();
+            if can_trim && !format.no_exponent_without_fraction() {
+                cursor -= 1;
+            } else {
+                bytes[cursor] = b'0';
+                cursor += 1;
+            }
+        }
+    } else {
+        bytes[cursor..cursor + shift].fill(b'0');
+        cursor += shift;
+    }
+
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp - shift as i32, options);
+    cursor
+}
+
+/// Replace the exponent of a float in engineering notation with an SI
+/// prefix.
+///
+/// `bytes[..count]` must contain the written float, without the sign.
+/// Floats without an exponent, or with an exponent that does not have an
+/// SI prefix, are unchanged. Returns the number of bytes written.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_si_prefix<const FORMAT: u128>(bytes: &mut [u8], count: usize, options: &Options) -> usize {
+    let exponent = options.exponent();
+    let exponent_index = match bytes[..count].iter().rposition(|&c| c == exponent) {
+        Some(index) => index,
+        None => return count,
+    };
+
+    let sci_exp = parse_exponent::<FORMAT>(&bytes[exponent_index + 1..count]);
+
+    if let Some(prefix) = si_prefix(sci_exp) {
+        exponent_index + copy_to_dst(&mut bytes[exponent_index..], prefix)
+    } else {
+        count
+    }
+}
+
+/// Get the rounding mode for the magnitude of a negative float.
+///
+/// The writers only see the absolute value, so rounding toward positive
+/// infinity rounds the magnitude toward zero, and vice-versa.
+#[inline(always)]
+const fn negated_round_mode(round_mode: RoundMode) -> RoundMode {
+    match round_mode {
+        RoundMode::Ceiling => RoundMode::Floor,
+        RoundMode::Floor => RoundMode::Ceiling,
+        _ => round_mode,
+    }
+}
+
+/// Get if the float is written with a negative sign.
+#[inline(always)]
+fn is_negative_sign<T: WriteFloat>(float: T, options: &Options) -> bool {
+    float.needs_negative_sign() && (options.signed_zero() || float != T::ZERO)
+}
+
+/// Get the options to write the magnitude of the float, if they differ.
+#[inline(always)]
+fn magnitude_options(options: &Options, is_negative: bool) -> Option<Options> {
+    if is_negative && matches!(options.round_mode(), RoundMode::Ceiling | RoundMode::Floor) {
+        let round_mode = negated_round_mode(options.round_mode());
+        Some(options.rebuild().round_mode(round_mode).build_unchecked())
+    } else {
+        None
+    }
+}
+
+/// Get the magnitude of the float.
+///
+/// Negative zero may be written without its sign, so this uses the sign
+/// bit rather than the sign that is written.
+#[inline(always)]
+fn magnitude<T: WriteFloat>(float: T) -> T {
+    if float.needs_negative_sign() {
+        -float
+    } else {
+        float
+    }
+}
+
+/// Write the mantissa sign, returning the number of bytes written.
+#[inline(always)]
+fn write_sign<const FORMAT: u128>(bytes: &mut [u8], is_negative: bool) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    if is_negative {
+        bytes[0] = b'-';
+        1
+    } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+        bytes[0] = b'+';
+        1
+    } else {
+        0
+    }
+}
+
 /// Check if a buffer is sufficiently large.
 #[inline(always)]
 fn check_buffer<T, const FORMAT: u128>(len: usize, options: &Options) -> bool
@@ -67,6 +332,103 @@ where
     len >= size
 }
 
+/// Write a finite, positive float without the sign.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_finite<T, const FORMAT: u128>(float: T, bytes: &mut [u8], options: &Options) -> usize
+where
+    T: WriteFloat,
+    T::Unsigned: FormattedSize + WriteInteger,
+{
+    if let Some(places) = options.decimal_places() {
+        return exact::write_fixed::<_, FORMAT>(float, bytes, options, places);
+    }
+    let is_decimal = NumberFormat::<FORMAT> {}.radix() == 10;
+    if options.uses_exact_digits() && is_decimal && float != T::ZERO {
+        return exact::write_float::<_, FORMAT>(float, bytes, options);
+    }
+
+    #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
+    {
+        let format = NumberFormat::<FORMAT> {};
+        let radix = format.radix();
+        let exponent_base = format.exponent_base();
+        if radix == 10 {
+            write_float_decimal::<_, FORMAT>(float, bytes, options)
+        } else if radix != exponent_base {
+            hex::write_float::<_, FORMAT>(float, bytes, options)
+        } else {
+            binary::write_float::<_, FORMAT>(float, bytes, options)
+        }
+    }
+
+    #[cfg(feature = "radix")]
+    {
+        let format = NumberFormat::<FORMAT> {};
+        let radix = format.radix();
+        let exponent_base = format.exponent_base();
+        if radix == 10 {
+            write_float_decimal::<_, FORMAT>(float, bytes, options)
+        } else if radix != exponent_base {
+            hex::write_float::<_, FORMAT>(float, bytes, options)
+        } else if matches!(radix, 2 | 4 | 8 | 16 | 32) {
+            binary::write_float::<_, FORMAT>(float, bytes, options)
+        } else if T::BITS < 32 {
+            radix::write_float_half::<_, FORMAT>(float, bytes, options)
+        } else {
+            radix::write_float::<_, FORMAT>(float, bytes, options)
+        }
+    }
+
+    #[cfg(not(feature = "power-of-two"))]
+    {
+        write_float_decimal::<_, FORMAT>(float, bytes, options)
+    }
+}
+
+/// Write the digits of a finite, positive float in the notation of the
+/// options.
+///
+/// This writes the digits without the sign, base prefix, digit separators,
+/// SI prefix, or base suffix. Returns the number of bytes written.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_digits<T, const FORMAT: u128>(float: T, bytes: &mut [u8], options: &Options) -> usize
+where
+    T: WriteFloat,
+    T::Unsigned: FormattedSize + WriteInteger,
+{
+    let mut written = write_finite::<_, FORMAT>(float, bytes, options);
+    if is_engineering::<FORMAT>(options) {
+        written = write_engineering::<FORMAT>(bytes, written, options);
+    }
+    if options.lowercase_digits() {
+        write_lowercase_digits(bytes, written, options);
+    }
+    written
+}
+
+/// Format the digits written by [`write_digits`].
+///
+/// This adds the digit separators, SI prefix, and base suffix to
+/// `bytes[..count]`. Returns the number of bytes written.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_formatted<const FORMAT: u128>(bytes: &mut [u8], count: usize, options: &Options) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    let mut written = count;
+    if format.has_digit_separator() {
+        written = write_digit_separators::<FORMAT>(bytes, written, options);
+    }
+    if is_engineering::<FORMAT>(options) && options.notation() == Notation::SiPrefix {
+        written = write_si_prefix::<FORMAT>(bytes, written, options);
+    }
+    written + write_base_suffix::<FORMAT>(&mut bytes[written..])
+}
+
+/// Get if decimal floats are written in engineering notation.
+#[inline(always)]
+fn is_engineering<const FORMAT: u128>(options: &Options) -> bool {
+    NumberFormat::<FORMAT> {}.radix() == 10 && options.notation() != Notation::Standard
+}
+
 /// Write float trait.
 pub trait WriteFloat: RawFloat + FormattedSize {
     /// Forward float writing parameters and write the float.
@@ -79,8 +441,9 @@ pub trait WriteFloat: RawFloat + FormattedSize {
     /// Panics if the number format is invalid, or if scientific notation
     /// is used and the exponent base does not equal the mantissa radix
     /// and the format is not a hexadecimal float. It also panics
-    /// if `options.nan_string` or `options.inf_string` is None and asked
-    /// to serialize a NaN or Inf value.
+    /// if `options.nan_string` or `options.inf_string` is None, or the
+    /// number format does not allow special values, and asked to
+    /// serialize a NaN or Inf value.
     ///
     /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
     /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
@@ -107,52 +470,21 @@ pub trait WriteFloat: RawFloat + FormattedSize {
             }
         }
 
-        let (float, count, bytes) = if self.needs_negative_sign() {
-            bytes[0] = b'-';
-            (-self, 1, &mut bytes[1..])
-        } else if cfg!(feature = "format") && format.required_mantissa_sign() {
-            bytes[0] = b'+';
-            (self, 1, &mut bytes[1..])
-        } else {
-            (self, 0, bytes)
-        };
+        let is_negative = is_negative_sign(self, options);
+        let negated = magnitude_options(options, is_negative);
+        let options = negated.as_ref().unwrap_or(options);
+        let count = write_sign::<FORMAT>(bytes, is_negative);
+        let bytes = &mut bytes[count..];
 
         // Handle special values.
-        if !self.is_special() {
-            #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
-            {
-                let radix = format.radix();
-                let exponent_base = format.exponent_base();
-                count
-                    + if radix == 10 {
-                        write_float_decimal::<_, FORMAT>(float, bytes, options)
-                    } else if radix != exponent_base {
-                        hex::write_float::<_, FORMAT>(float, bytes, options)
-                    } else {
-                        binary::write_float::<_, FORMAT>(float, bytes, options)
-                    }
-            }
-
-            #[cfg(feature = "radix")]
-            {
-                let radix = format.radix();
-                let exponent_base = format.exponent_base();
-                count
-                    + if radix == 10 {
-                        write_float_decimal::<_, FORMAT>(float, bytes, options)
-                    } else if radix != exponent_base {
-                        hex::write_float::<_, FORMAT>(float, bytes, options)
-                    } else if matches!(radix, 2 | 4 | 8 | 16 | 32) {
-                        binary::write_float::<_, FORMAT>(float, bytes, options)
-                    } else {
-                        radix::write_float::<_, FORMAT>(float, bytes, options)
-                    }
-            }
-
-            #[cfg(not(feature = "power-of-two"))]
-            {
-                count + write_float_decimal::<_, FORMAT>(float, bytes, options)
-            }
+        if self.is_special() && cfg!(feature = "format") && format.no_special() {
+            // PANIC: the format does not support serializing special values.
+            panic!("Special values are not allowed by the number format.");
+        } else if !self.is_special() {
+            let prefix = write_base_prefix::<FORMAT>(bytes);
+            let bytes = &mut bytes[prefix..];
+            let written = write_digits::<_, FORMAT>(magnitude(self), bytes, options);
+            count + prefix + write_formatted::<FORMAT>(bytes, written, options)
         } else if self.is_nan() {
             write_nan(bytes, options, count)
         } else {
@@ -161,6 +493,180 @@ pub trait WriteFloat: RawFloat + FormattedSize {
     }
 }
 
+/// The size of the scratch buffer used to find the exact size of a float.
+///
+/// This fits any float written with the [`scratch_options`].
+const SCRATCH_SIZE: usize = 2560;
+
+/// The maximum exponent break, significant digits, and decimal places in
+/// the scratch buffer.
+///
+/// This is more than the exponent, integer digits, or significant digits
+/// of any finite float in any radix, so any larger value only adds padding
+/// zeros, or more fraction digits that cannot carry when rounded.
+const SCRATCH_DIGITS: usize = 1100;
+
+/// The maximum exponent digits in the scratch buffer.
+///
+/// This is more than the exponent digits of any finite float in any radix.
+const SCRATCH_EXPONENT_DIGITS: usize = 32;
+
+/// Get the options to write a float to the scratch buffer.
+///
+/// The exponent breaks, significant digits, and decimal places are limited
+/// so it fits in [`SCRATCH_SIZE`] bytes, and digit separators are not
+/// written, which only removes padding zeros and separators.
+fn scratch_options(options: &Options) -> Options {
+    let limit = SCRATCH_DIGITS as i32;
+    let limit_break = |x: OptionI32| x.and_then(|x| NonZeroI32::new(x.get().clamp(-limit, limit)));
+    let limit_count =
+        |x: OptionUsize, max: usize| x.and_then(|x| NonZeroUsize::new(x.get().min(max)));
+    options
+        .rebuild()
+        .negative_exponent_break(limit_break(options.negative_exponent_break()))
+        .positive_exponent_break(limit_break(options.positive_exponent_break()))
+        .min_significant_digits(limit_count(options.min_significant_digits(), SCRATCH_DIGITS))
+        .min_exponent_digits(limit_count(options.min_exponent_digits(), SCRATCH_EXPONENT_DIGITS))
+        .decimal_places(options.decimal_places().map(|x| x.min(SCRATCH_DIGITS)))
+        .integer_grouping(DigitGrouping::NONE)
+        .fraction_grouping(DigitGrouping::NONE)
+        .build_unchecked()
+}
+
+/// Write a float to a buffer smaller than the upper bound of its size.
+///
+/// The float is first written to a scratch buffer with the
+/// [`scratch_options`], which is used to find the exact number of bytes
+/// required. The digits are then copied to the buffer with the removed
+/// padding zeros, or written to it directly if they have more decimal
+/// places, before adding the separators, SI prefix, and base suffix.
+///
+/// # Errors
+///
+/// If the buffer is smaller than the exact number of bytes required.
+pub fn write_float_exact<T, const FORMAT: u128>(
+    value: T,
+    bytes: &mut [u8],
+    options: &Options,
+) -> Result<usize>
+where
+    T: WriteFloat,
+    T::Unsigned: FormattedSize + WriteInteger,
+{
+    let scratch_options = scratch_options(options);
+    debug_assert!(check_buffer::<T, FORMAT>(SCRATCH_SIZE, &scratch_options));
+    let mut scratch = [0u8; SCRATCH_SIZE];
+    if value.is_special() {
+        let count = value.write_float::<FORMAT>(&mut scratch, &scratch_options);
+        let dst = bytes.get_mut(..count).ok_or(Error::BufferTooSmall(count))?;
+        dst.copy_from_slice(&scratch[..count]);
+        return Ok(count);
+    }
+
+    // Write the sign, prefix, and digits to the scratch buffer.
+    let is_negative = is_negative_sign(value, options);
+    let float = magnitude(value);
+    let negated = magnitude_options(&scratch_options, is_negative);
+    let scratch_options = negated.as_ref().unwrap_or(&scratch_options);
+    let mut lead = write_sign::<FORMAT>(&mut scratch, is_negative);
+    lead += write_base_prefix::<FORMAT>(&mut scratch[lead..]);
+    let (lead_bytes, digits) = scratch.split_at_mut(lead);
+    let min_digits = options.min_significant_digits().map_or(0, |x| x.get());
+    let fewer_count = if options.decimal_places().is_none() && min_digits > SCRATCH_DIGITS {
+        // Trimmed floats are not padded, which we find by writing the float
+        // with one fewer significant digit.
+        let fewer = scratch_options
+            .rebuild()
+            .min_significant_digits(NonZeroUsize::new(SCRATCH_DIGITS - 1))
+            .build_unchecked();
+        Some(write_digits::<_, FORMAT>(float, digits, &fewer))
+    } else {
+        None
+    };
+    let written = write_digits::<_, FORMAT>(float, digits, scratch_options);
+    let is_padded = fewer_count.map_or(false, |count| count < written);
+    let digits = &digits[..written];
+
+    // Find the number of bytes removed by the scratch options.
+    let fraction_zeros = match options.decimal_places() {
+        Some(places) => places.saturating_sub(SCRATCH_DIGITS),
+        None if is_padded => min_digits - SCRATCH_DIGITS,
+        None => 0,
+    };
+    let exponent_index = digits.iter().position(|&c| c == options.exponent());
+    let fraction_end = exponent_index.unwrap_or(written);
+    let (integer_count, fraction_count) = digit_counts(&digits[..fraction_end], options);
+    let fraction_count = fraction_count.saturating_add(fraction_zeros);
+    let count = fraction_end.saturating_add(fraction_zeros);
+
+    // Find the exact number of bytes of the formatted float. The exponent
+    // may be replaced by an SI prefix, which drops its padding zeros.
+    let format = NumberFormat::<FORMAT> {};
+    let (integer_grouping, fraction_grouping) = digit_groupings::<FORMAT>(options);
+    let separators = if format.has_digit_separator() {
+        let integer_separators = integer_grouping.integer_separators(integer_count);
+        integer_separators.saturating_add(fraction_grouping.fraction_separators(fraction_count))
+    } else {
+        0
+    };
+    let exponent = &digits[fraction_end..];
+    let is_si_prefix =
+        is_engineering::<FORMAT>(options) && options.notation() == Notation::SiPrefix;
+    let prefix = match exponent.split_first() {
+        Some((_, exponent)) if is_si_prefix => si_prefix(parse_exponent::<FORMAT>(exponent)),
+        _ => None,
+    };
+    let exponent_zeros = match (prefix, options.min_exponent_digits()) {
+        (None, Some(min_digits)) if !exponent.is_empty() => {
+            min_digits.get().saturating_sub(SCRATCH_EXPONENT_DIGITS)
+        },
+        _ => 0,
+    };
+    let exponent_count = prefix.map_or(exponent.len() + exponent_zeros, |x| x.len());
+    let size = (lead + format.has_base_suffix() as usize)
+        .saturating_add(count)
+        .saturating_add(separators)
+        .saturating_add(exponent_count);
+    if bytes.len() < size {
+        return Err(Error::BufferTooSmall(size));
+    }
+
+    // Write the digits with the removed padding zeros, and format them.
+    bytes[..lead].copy_from_slice(lead_bytes);
+    let dst = &mut bytes[lead..];
+    if options.decimal_places().is_some() && fraction_zeros != 0 {
+        // The fixed writer only writes the final digits, so we can write
+        // the extra decimal places directly.
+        let negated = magnitude_options(options, is_negative);
+        let options = negated.as_ref().unwrap_or(options);
+        let written = write_digits::<_, FORMAT>(float, dst, options);
+        debug_assert_eq!(written, count, "must write the removed decimal places");
+    } else {
+        copy_to_dst(dst, &digits[..fraction_end]);
+        dst[fraction_end..count].fill(b'0');
+    }
+    let mut cursor = count;
+    if format.has_digit_separator() {
+        cursor = write_digit_separators::<FORMAT>(dst, cursor, options);
+    }
+    if let Some(prefix) = prefix {
+        cursor += copy_to_dst(&mut dst[cursor..], prefix);
+    } else if let Some((&symbol, exponent)) = exponent.split_first() {
+        let sign_count = matches!(exponent.first(), Some(b'+' | b'-')) as usize;
+        let (sign, exponent) = exponent.split_at(sign_count);
+        dst[cursor] = symbol;
+        cursor += 1;
+        cursor += copy_to_dst(&mut dst[cursor..], sign);
+        dst[cursor..cursor + exponent_zeros].fill(b'0');
+        cursor += exponent_zeros;
+        cursor += copy_to_dst(&mut dst[cursor..], exponent);
+    }
+    cursor += write_base_suffix::<FORMAT>(&mut dst[cursor..]);
+    debug_assert_eq!(lead + cursor, size, "must write the exact number of bytes");
+
+    Ok(lead + cursor)
+}
+
 macro_rules! write_float_impl {
     ($($t:ty)*) => ($(
         impl WriteFloat for $t {}
@@ -170,17 +676,4 @@ macro_rules! write_float_impl {
 write_float_impl! { f32 f64 }
 
 #[cfg(feature = "f16")]
-macro_rules! write_float_as_f32 {
-    ($($t:ty)*) => ($(
-        impl WriteFloat for $t {
-            #[inline(always)]
-            fn write_float<const FORMAT: u128>(self, bytes: &mut [u8], options: &Options) -> usize
-            {
-                self.as_f32().write_float::<FORMAT>(bytes, options)
-            }
-        }
-    )*)
-}
-
-#[cfg(feature = "f16")]
-write_float_as_f32! { bf16 f16 }
+write_float_impl! { bf16 f16 }
@@ -2,7 +2,11 @@
 
 use core::num;
 
+#[cfg(feature = "f16")]
+use lexical_util::bf16::bf16;
 use lexical_util::constants::BUFFER_SIZE;
+#[cfg(feature = "f16")]
+use lexical_util::f16::f16;
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::num::Float;
 use lexical_write_float::algorithm::DragonboxFloat;
@@ -179,7 +183,7 @@ fn compute_nearest_normal(float: f64) -> (u64, i32) {
 #[test]
 fn compute_nearest_normal_test() {
     assert_eq!(compute_nearest_normal(1.23456), (123456, -5));
-    assert_eq!(compute_nearest_normal(13.9999999999999982236431606), (13999999999999998, -15));
+    assert_eq!(compute_nearest_normal(13.999999999999998), (13999999999999998, -15));
 }
 
 fn compute_left_closed_directed(float: f64) -> (u64, i32) {
@@ -190,10 +194,7 @@ fn compute_left_closed_directed(float: f64) -> (u64, i32) {
 #[test]
 fn compute_left_closed_directed_test() {
     assert_eq!(compute_left_closed_directed(1.23456), (12345600000000002, -16));
-    assert_eq!(
-        compute_left_closed_directed(13.9999999999999982236431606),
-        (13999999999999999, -15)
-    );
+    assert_eq!(compute_left_closed_directed(13.999999999999998), (13999999999999999, -15));
 }
 
 fn compute_right_closed_directed(float: f64) -> (u64, i32) {
@@ -208,10 +209,21 @@ fn compute_right_closed_directed(float: f64) -> (u64, i32) {
 #[test]
 fn compute_right_closed_directed_test() {
     assert_eq!(compute_right_closed_directed(1.23456), (123456, -5));
-    assert_eq!(
-        compute_right_closed_directed(13.9999999999999982236431606),
-        (13999999999999982, -15)
-    );
+    assert_eq!(compute_right_closed_directed(13.999999999999998), (13999999999999982, -15));
+}
+
+#[test]
+fn compute_mul_parity_f32_test() {
+    // The parity is the lowest integer bit of the product, and the product
+    // is an integer if the 32 bits below it are zero.
+    assert_eq!(f32::compute_mul_parity(1, &(1 << 62), 2), (true, true));
+    assert_eq!(f32::compute_mul_parity(1, &(1 << 61), 2), (false, false));
+    assert_eq!(f32::compute_mul_parity(1, &(1 << 30), 2), (false, false));
+    assert_eq!(f32::compute_mul_parity(1, &((1 << 62) | (1 << 30)), 2), (true, false));
+    // Any higher integer bits are ignored.
+    assert_eq!(f32::compute_mul_parity(1, &(3 << 62), 2), (true, true));
+    assert_eq!(f32::compute_mul_parity(2, &(3 << 61), 2), (true, true));
+    assert_eq!(f32::compute_mul_parity(3, &(1 << 63), 1), (true, true));
 }
 
 fn write_digits_f32(buffer: &mut [u8], value: u64, expected: &str) {
@@ -257,7 +269,7 @@ fn write_float_scientific(mant: u64, exp: i32, options: &Options, expected: &str
     let digit_count = f64::digit_count(fp.mant);
     let sci_exp = fp.exp + digit_count as i32 - 1;
     let count =
-        algorithm::write_float_scientific::<f64, DECIMAL>(&mut buffer, fp, sci_exp, &options);
+        algorithm::write_float_scientific::<f64, DECIMAL>(&mut buffer, fp, sci_exp, options);
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -345,12 +357,8 @@ fn write_float_positive_exponent(mant: u64, exp: i32, options: &Options, expecte
     };
     let digit_count = f64::digit_count(fp.mant);
     let sci_exp = fp.exp + digit_count as i32 - 1;
-    let count = algorithm::write_float_positive_exponent::<f64, DECIMAL>(
-        &mut buffer,
-        fp,
-        sci_exp,
-        &options,
-    );
+    let count =
+        algorithm::write_float_positive_exponent::<f64, DECIMAL>(&mut buffer, fp, sci_exp, options);
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -434,12 +442,8 @@ fn write_float_negative_exponent(mant: u64, exp: i32, options: &Options, expecte
     };
     let digit_count = f64::digit_count(fp.mant);
     let sci_exp = fp.exp + digit_count as i32 - 1;
-    let count = algorithm::write_float_negative_exponent::<f64, DECIMAL>(
-        &mut buffer,
-        fp,
-        sci_exp,
-        &options,
-    );
+    let count =
+        algorithm::write_float_negative_exponent::<f64, DECIMAL>(&mut buffer, fp, sci_exp, options);
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -541,7 +545,7 @@ const F32_DATA: [f32; 31] = [
     123456789.123,
     123456789.1234,
     123456789.12345,
-    1.2345678912345e8,
+    1.2345679e8,
     1.2345e+8,
     1.2345e+11,
     1.2345e+38,
@@ -599,10 +603,10 @@ fn f32_test() {
     write_float::<_, DECIMAL>(1.0f32, &OPTS1, "1");
     write_float::<_, DECIMAL>(10.0f32, &OPTS1, "10");
     write_float::<_, DECIMAL>(10.0f32, &OPTS1, "10");
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f32, &OPTS1, "1.2345679");
-    write_float::<_, DECIMAL>(1.2345678901234567890e1f32, &OPTS1, "12.345679");
-    write_float::<_, DECIMAL>(1.2345678901234567890e2f32, &OPTS1, "123.45679");
-    write_float::<_, DECIMAL>(1.2345678901234567890e3f32, &OPTS1, "1234.5679");
+    write_float::<_, DECIMAL>(1.2345679f32, &OPTS1, "1.2345679");
+    write_float::<_, DECIMAL>(1.2345679e1f32, &OPTS1, "12.345679");
+    write_float::<_, DECIMAL>(1.2345679e2f32, &OPTS1, "123.45679");
+    write_float::<_, DECIMAL>(1.2345679e3f32, &OPTS1, "1234.5679");
     write_float::<_, DECIMAL>(2.3786281e+38f32, &OPTS1, "2.3786281e38");
 
     const OPTS2: Options = Options::new();
@@ -660,10 +664,10 @@ fn f64_test() {
     write_float::<_, DECIMAL>(1.0f64, &TRIM, "1");
     write_float::<_, DECIMAL>(10.0f64, &TRIM, "10");
     write_float::<_, DECIMAL>(10.0f64, &TRIM, "10");
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f64, &TRIM, "1.2345678901234567");
-    write_float::<_, DECIMAL>(1.2345678901234567890e1f64, &TRIM, "12.345678901234567");
-    write_float::<_, DECIMAL>(1.2345678901234567890e2f64, &TRIM, "123.45678901234568");
-    write_float::<_, DECIMAL>(1.2345678901234567890e3f64, &TRIM, "1234.567890123457");
+    write_float::<_, DECIMAL>(1.2345678901234567f64, &TRIM, "1.2345678901234567");
+    write_float::<_, DECIMAL>(1.2345678901234567e1f64, &TRIM, "12.345678901234567");
+    write_float::<_, DECIMAL>(1.2345678901234568e2f64, &TRIM, "123.45678901234568");
+    write_float::<_, DECIMAL>(1.234567890123457e3f64, &TRIM, "1234.567890123457");
     write_float::<_, DECIMAL>(1.5f64, &TRIM, "1.5");
     write_float::<_, DECIMAL>(1.0e-17f64, &TRIM, "1e-17");
     write_float::<_, DECIMAL>(9.99999999999999e-16f64, &TRIM, "9.99999999999999e-16");
@@ -705,14 +709,14 @@ fn f64_test() {
         .round_mode(RoundMode::Round)
         .build_strict();
 
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f64, &TRUNCATE, "1.234");
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f64, &ROUND, "1.235");
-    write_float::<_, DECIMAL>(1.2345678901234567890e1f64, &TRUNCATE, "12.34");
-    write_float::<_, DECIMAL>(1.2345678901234567890e1f64, &ROUND, "12.35");
-    write_float::<_, DECIMAL>(1.2345678901234567890e2f64, &TRUNCATE, "123.4");
-    write_float::<_, DECIMAL>(1.2345678901234567890e2f64, &ROUND, "123.5");
-    write_float::<_, DECIMAL>(1.2345678901234567890e3f64, &TRUNCATE, "1234.0");
-    write_float::<_, DECIMAL>(1.2345678901234567890e3f64, &ROUND, "1235.0");
+    write_float::<_, DECIMAL>(1.2345678901234567f64, &TRUNCATE, "1.234");
+    write_float::<_, DECIMAL>(1.2345678901234567f64, &ROUND, "1.235");
+    write_float::<_, DECIMAL>(1.2345678901234567e1f64, &TRUNCATE, "12.34");
+    write_float::<_, DECIMAL>(1.2345678901234567e1f64, &ROUND, "12.35");
+    write_float::<_, DECIMAL>(1.2345678901234568e2f64, &TRUNCATE, "123.4");
+    write_float::<_, DECIMAL>(1.2345678901234568e2f64, &ROUND, "123.5");
+    write_float::<_, DECIMAL>(1.234567890123457e3f64, &TRUNCATE, "1234.0");
+    write_float::<_, DECIMAL>(1.234567890123457e3f64, &ROUND, "1235.0");
 }
 
 #[test]
@@ -727,24 +731,106 @@ fn f64_roundtrip_test() {
     }
 }
 
+#[test]
+#[cfg(feature = "f16")]
+fn f16_test() {
+    const TRIM: Options = Options::builder().trim_floats(true).build_strict();
+    write_float::<_, DECIMAL>(f16::from_f32(0.0), &TRIM, "0");
+    write_float::<_, DECIMAL>(f16::from_f32(1.0), &TRIM, "1");
+    write_float::<_, DECIMAL>(f16::from_f32(0.1), &TRIM, "0.1");
+    write_float::<_, DECIMAL>(f16::from_f32(1.234), &TRIM, "1.234");
+    write_float::<_, DECIMAL>(f16::from_bits(0x0001), &TRIM, "6e-8");
+    write_float::<_, DECIMAL>(f16::from_bits(0x03FF), &TRIM, "0.000061");
+    write_float::<_, DECIMAL>(f16::from_bits(0x0400), &TRIM, "0.00006104");
+    write_float::<_, DECIMAL>(f16::from_bits(0x6C04), &TRIM, "4110");
+    write_float::<_, DECIMAL>(f16::from_bits(0x7BFF), &TRIM, "65500");
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn bf16_test() {
+    const TRIM: Options = Options::builder().trim_floats(true).build_strict();
+    write_float::<_, DECIMAL>(bf16::from_f32(0.0), &TRIM, "0");
+    write_float::<_, DECIMAL>(bf16::from_f32(1.0), &TRIM, "1");
+    write_float::<_, DECIMAL>(bf16::from_f32(0.1), &TRIM, "0.1");
+    write_float::<_, DECIMAL>(bf16::from_f32(1.234), &TRIM, "1.234");
+    write_float::<_, DECIMAL>(bf16::from_bits(0x0001), &TRIM, "1e-40");
+    write_float::<_, DECIMAL>(bf16::from_bits(0x0080), &TRIM, "1.18e-38");
+    write_float::<_, DECIMAL>(bf16::from_bits(0x440A), &TRIM, "550");
+    write_float::<_, DECIMAL>(bf16::from_bits(0x46BC), &TRIM, "24000");
+    write_float::<_, DECIMAL>(bf16::from_bits(0x7F7F), &TRIM, "3.39e38");
+}
+
+/// Check the written float is closer to the float than to its neighbors.
+#[cfg(feature = "f16")]
+fn is_roundtrip(bits: u16, max_bits: u16, to_f64: impl Fn(u16) -> f64, actual: &str) -> bool {
+    let value = to_f64(bits);
+    let next = if bits == max_bits {
+        2.0 * value - to_f64(bits - 1)
+    } else {
+        to_f64(bits + 1)
+    };
+    let prev = if bits == 0 {
+        0.0
+    } else {
+        to_f64(bits - 1)
+    };
+    let lower = (value + prev) / 2.0;
+    let upper = (value + next) / 2.0;
+    let parsed = actual.parse::<f64>().unwrap();
+    if bits % 2 == 0 {
+        lower <= parsed && parsed <= upper
+    } else {
+        lower < parsed && parsed < upper
+    }
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn f16_roundtrip_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    const OPTIONS: Options = Options::builder().build_strict();
+    let to_f64 = |bits| f16::from_bits(bits).to_f64();
+    for bits in 1..0x7C00 {
+        let float = f16::from_bits(bits);
+        let count = algorithm::write_float::<_, DECIMAL>(float, &mut buffer, &OPTIONS);
+        let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
+        assert!(is_roundtrip(bits, 0x7BFF, to_f64, actual), "{bits:#x} => {actual}");
+    }
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn bf16_roundtrip_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    const OPTIONS: Options = Options::builder().build_strict();
+    let to_f64 = |bits| bf16::from_bits(bits).to_f64();
+    for bits in 1..0x7F80 {
+        let float = bf16::from_bits(bits);
+        let count = algorithm::write_float::<_, DECIMAL>(float, &mut buffer, &OPTIONS);
+        let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
+        assert!(is_roundtrip(bits, 0x7F7F, to_f64, actual), "{bits:#x} => {actual}");
+    }
+}
+
 #[test]
 fn is_endpoint_test() {
-    assert_eq!(algorithm::is_endpoint(5, 2, 10), true);
-    assert_eq!(algorithm::is_endpoint(5, 6, 10), false);
+    assert!(algorithm::is_endpoint(5, 2, 10));
+    assert!(!algorithm::is_endpoint(5, 6, 10));
 }
 
 #[test]
 fn is_right_endpoint_test() {
-    assert_eq!(algorithm::is_right_endpoint::<f64>(1), true);
-    assert_eq!(algorithm::is_right_endpoint::<f64>(2), true);
-    assert_eq!(algorithm::is_right_endpoint::<f64>(3), true);
-    assert_eq!(algorithm::is_right_endpoint::<f64>(4), false);
+    assert!(algorithm::is_right_endpoint::<f64>(1));
+    assert!(algorithm::is_right_endpoint::<f64>(2));
+    assert!(algorithm::is_right_endpoint::<f64>(3));
+    assert!(!algorithm::is_right_endpoint::<f64>(4));
 }
 
 #[test]
 fn is_left_endpoint_test() {
-    assert_eq!(algorithm::is_left_endpoint::<f64>(1), false);
-    assert_eq!(algorithm::is_left_endpoint::<f64>(2), true);
-    assert_eq!(algorithm::is_left_endpoint::<f64>(3), true);
-    assert_eq!(algorithm::is_left_endpoint::<f64>(4), false);
+    assert!(!algorithm::is_left_endpoint::<f64>(1));
+    assert!(algorithm::is_left_endpoint::<f64>(2));
+    assert!(algorithm::is_left_endpoint::<f64>(3));
+    assert!(!algorithm::is_left_endpoint::<f64>(4));
 }
@@ -1,6 +1,9 @@
+use core::num;
+
 use lexical_util::constants::BUFFER_SIZE;
 use lexical_util::format::STANDARD;
-use lexical_write_float::{Options, ToLexical, ToLexicalWithOptions};
+use lexical_write_float::options::STANDARD as STANDARD_OPTIONS;
+use lexical_write_float::{options, Notation, Options, RoundMode, ToLexical, ToLexicalWithOptions};
 
 #[test]
 fn error_tests() {
@@ -78,3 +81,766 @@ fn hex_test() {
     let result = float.to_lexical_with_options::<BASE16_2_10>(&mut buffer, &HEX_OPTIONS);
     assert_eq!(result, b"3.039^12");
 }
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_separator_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+    use lexical_write_float::DigitGrouping;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .fraction_internal_digit_separator(true)
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+    const FRACTION: Options = Options::builder()
+        .integer_grouping(DigitGrouping::INDIAN)
+        .fraction_grouping(DigitGrouping::THOUSANDS)
+        .build_strict();
+    const SCI: Options = Options::builder()
+        .fraction_grouping(DigitGrouping::THOUSANDS)
+        .max_significant_digits(num::NonZeroUsize::new(7))
+        .build_strict();
+
+    let mut buffer = [b'\x00'; FRACTION.buffer_size_const::<f64, FORMAT>()];
+    let mut write = |x: f64, options: &Options| -> String {
+        let digits = x.to_lexical_with_options::<FORMAT>(&mut buffer, options);
+        String::from_utf8(digits.to_vec()).unwrap()
+    };
+    assert_eq!(write(0.0, &OPTIONS), "0.0");
+    assert_eq!(write(999.0, &OPTIONS), "999.0");
+    assert_eq!(write(1000000.0, &OPTIONS), "1_000_000.0");
+    assert_eq!(write(-1234567.891, &OPTIONS), "-1_234_567.891");
+    assert_eq!(write(1234567.891234, &FRACTION), "12_34_567.891_234");
+    assert_eq!(write(0.000012345, &FRACTION), "0.000_012_345");
+    assert_eq!(write(1.2345678e20, &SCI), "1.234_568e20");
+    assert_eq!(write(f64::NAN, &OPTIONS), "NaN");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_flags_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    let mut buffer = [b'\x00'; 512];
+    let mut write = |x: f64, options: &Options, format: u128| {
+        let digits = match format {
+            SIGNED => x.to_lexical_with_options::<SIGNED>(&mut buffer, options),
+            UNSIGNED => x.to_lexical_with_options::<UNSIGNED>(&mut buffer, options),
+            _ => unreachable!(),
+        };
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+
+    const SIGNED: u128 = NumberFormatBuilder::new()
+        .required_mantissa_sign(true)
+        .required_exponent_sign(true)
+        .build_strict();
+    const UNSIGNED: u128 = NumberFormatBuilder::new()
+        .no_positive_exponent_sign(true)
+        .no_exponent_without_fraction(true)
+        .build_strict();
+    const DIGITS: Options = Options::builder()
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .positive_exponent_break(num::NonZeroI32::new(2))
+        .build_strict();
+    assert_eq!(write(1.5e3, &DIGITS, SIGNED), "+1.5e+03");
+    assert_eq!(write(-1.5e-30, &DIGITS, SIGNED), "-1.5e-30");
+    assert_eq!(write(0.5, &DIGITS, SIGNED), "+0.5");
+    assert_eq!(write(1.0, &DIGITS, SIGNED), "+1.0");
+    assert_eq!(write(-0.0, &DIGITS.rebuild().signed_zero(false).build_strict(), SIGNED), "+0.0");
+    assert_eq!(write(f64::NAN, &DIGITS, SIGNED), "+NaN");
+
+    const TRIM: Options = Options::builder()
+        .trim_floats(true)
+        .exponent_plus_sign(true)
+        .positive_exponent_break(num::NonZeroI32::new(2))
+        .build_strict();
+    assert_eq!(write(1e3, &TRIM, SIGNED), "+1e+3");
+    assert_eq!(write(1e3, &TRIM, UNSIGNED), "1.0e3");
+    assert_eq!(write(1.5e3, &TRIM, UNSIGNED), "1.5e3");
+    assert_eq!(write(1.0, &TRIM, UNSIGNED), "1");
+    let engineering = TRIM.rebuild().notation(Notation::Engineering).build_strict();
+    assert_eq!(write(1e4, &engineering, SIGNED), "+10e+3");
+    assert_eq!(write(1e4, &engineering, UNSIGNED), "10.0e3");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn fixed_column_test() {
+    use lexical_util::format::{self, NumberFormatBuilder};
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .required_exponent_notation(true)
+        .required_mantissa_sign(true)
+        .build_strict();
+    const OPTIONS: Options = Options::builder()
+        .min_significant_digits(num::NonZeroUsize::new(5))
+        .max_significant_digits(num::NonZeroUsize::new(5))
+        .exponent(b'E')
+        .exponent_plus_sign(true)
+        .min_exponent_digits(num::NonZeroUsize::new(3))
+        .build_strict();
+    let mut buffer = [b'\x00'; OPTIONS.buffer_size_const::<f64, FORMAT>()];
+    let mut write = |x: f64| {
+        let digits = x.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+    assert_eq!(write(0.0), "+0.0000E+000");
+    assert_eq!(write(1.5), "+1.5000E+000");
+    assert_eq!(write(-1.5e5), "-1.5000E+005");
+    assert_eq!(write(123456.0), "+1.2346E+005");
+    assert_eq!(write(2.5e-42), "+2.5000E-042");
+    assert_eq!(write(1.7976931348623157e308), "+1.7977E+308");
+
+    let options = options::C_PRINTF_E;
+    let mut buffer = [b'\x00'; 512];
+    let mut write = |x: f64| {
+        let digits = x.to_lexical_with_options::<{ format::C_PRINTF_E }>(&mut buffer, &options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+    assert_eq!(write(0.0), "0.000000e+00");
+    assert_eq!(write(1.5e5), "1.500000e+05");
+    assert_eq!(write(1e-7), "1.000000e-07");
+    assert_eq!(write(0.1), "1.000000e-01");
+    assert_eq!(write(2.5e-6), "2.500000e-06");
+    assert_eq!(write(123456789.0), "1.234568e+08");
+    assert_eq!(write(-1e100), "-1.000000e+100");
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "format")]
+fn no_special_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().no_special(true).build_strict();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    f64::NAN.to_lexical_with_options::<FORMAT>(&mut buffer, &STANDARD_OPTIONS);
+}
+
+#[test]
+fn try_write_test() {
+    use lexical_write_float::Error;
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    for length in 0..3 {
+        assert_eq!(Err(Error::BufferTooSmall(3)), 1.5f64.try_to_lexical(&mut buffer[..length]));
+    }
+    assert_eq!(Ok(&b"1.5"[..]), 1.5f64.try_to_lexical(&mut buffer[..3]).map(|x| &*x));
+    assert_eq!(Err(Error::BufferTooSmall(23)), f64::MIN_POSITIVE.try_to_lexical(&mut buffer[..10]));
+    assert_eq!(Ok(&b"-inf"[..]), f32::NEG_INFINITY.try_to_lexical(&mut buffer).map(|x| &*x));
+
+    // The exact size is required, even if the upper bound is much larger.
+    const DIGITS: Options =
+        Options::builder().min_significant_digits(num::NonZeroUsize::new(500)).build_strict();
+    assert_eq!(
+        Err(Error::BufferTooSmall(501)),
+        1.5f64.try_to_lexical_with_options::<STANDARD>(&mut buffer, &DIGITS)
+    );
+    let mut large = [b'\x00'; 5003];
+    let digits = 1.5f64.try_to_lexical_with_options::<STANDARD>(&mut large[..501], &DIGITS);
+    assert_eq!(Ok(501), digits.map(|x| x.len()));
+
+    const PLACES: Options = Options::builder().decimal_places(Some(2)).build_strict();
+    let digits = 1.5f64.try_to_lexical_with_options::<STANDARD>(&mut buffer, &PLACES);
+    assert_eq!(Ok(&b"1.50"[..]), digits.map(|x| &*x));
+
+    // The padding does not fit in the scratch buffer.
+    const PADDED: Options =
+        Options::builder().min_significant_digits(num::NonZeroUsize::new(5000)).build_strict();
+    assert_eq!(
+        Err(Error::BufferTooSmall(5003)),
+        (-0.5f64).try_to_lexical_with_options::<STANDARD>(&mut buffer, &PADDED)
+    );
+    let digits = (-0.5f64).try_to_lexical_with_options::<STANDARD>(&mut large, &PADDED);
+    let digits = digits.unwrap();
+    assert!(digits.starts_with(b"-0.5"));
+    assert!(digits[4..].iter().all(|&c| c == b'0'));
+
+    const MANY_PLACES: Options = Options::builder().decimal_places(Some(5000)).build_strict();
+    assert_eq!(
+        Err(Error::BufferTooSmall(5002)),
+        0.1f64.try_to_lexical_with_options::<STANDARD>(&mut buffer, &MANY_PLACES)
+    );
+    let digits = 0.1f64.try_to_lexical_with_options::<STANDARD>(&mut large[..5002], &MANY_PLACES);
+    let digits = digits.unwrap();
+    assert!(digits.starts_with(b"0.1000000000000000055511151231257827"));
+    assert_eq!(digits.len(), 5002);
+
+    const NO_NAN: Options = Options::builder().nan_string(None).build_strict();
+    assert_eq!(
+        Err(Error::UnsupportedSpecial),
+        f64::NAN.try_to_lexical_with_options::<STANDARD>(&mut buffer, &NO_NAN)
+    );
+    const INVALID: Options = Options::builder().nan_string(Some(b"x")).build_unchecked();
+    assert_eq!(
+        Err(Error::InvalidNanString),
+        1.5f64.try_to_lexical_with_options::<STANDARD>(&mut buffer, &INVALID)
+    );
+
+    #[cfg(feature = "format")]
+    {
+        use lexical_util::format::NumberFormatBuilder;
+
+        const FORMAT: u128 = NumberFormatBuilder::new().no_special(true).build_strict();
+        assert_eq!(
+            Err(Error::UnsupportedSpecial),
+            f64::NAN.try_to_lexical_with_options::<FORMAT>(&mut buffer, &STANDARD_OPTIONS)
+        );
+    }
+
+    #[cfg(feature = "power-of-two")]
+    {
+        use lexical_util::format::NumberFormatBuilder;
+
+        const FORMAT: u128 = NumberFormatBuilder::new()
+            .mantissa_radix(8)
+            .exponent_base(num::NonZeroU8::new(4))
+            .build_unchecked();
+        assert_eq!(
+            Err(Error::InvalidExponentBase),
+            1.5f64.try_to_lexical_with_options::<FORMAT>(&mut buffer, &STANDARD_OPTIONS)
+        );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn base_prefix_suffix_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .base_suffix(num::NonZeroU8::new(b'h'))
+        .build_strict();
+    const OPTIONS: Options = Options::builder().exponent(b'p').build_strict();
+    const LOWER: Options = Options::builder().exponent(b'P').lowercase_digits(true).build_strict();
+
+    let mut buffer = [b'\x00'; OPTIONS.buffer_size_const::<f64, FORMAT>()];
+    assert_eq!(b"0x0.0h", 0.0f64.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS));
+    assert_eq!(b"-0xFF.8h", (-255.5f64).to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS));
+    assert_eq!(b"0xff.8h", 255.5f64.to_lexical_with_options::<FORMAT>(&mut buffer, &LOWER));
+    assert_eq!(
+        b"0xa.bP40h",
+        11751030521856.0f64.to_lexical_with_options::<FORMAT>(&mut buffer, &LOWER)
+    );
+    assert_eq!(b"NaN", f64::NAN.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS));
+}
+
+#[test]
+fn decimal_places_test() {
+    const OPTIONS: Options = Options::builder().decimal_places(Some(2)).build_strict();
+    let mut buffer = [b'\x00'; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    let mut write = |x: f64| {
+        let digits = x.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+    assert_eq!(write(0.0), "0.00");
+    assert_eq!(write(-0.0), "-0.00");
+    assert_eq!(write(0.125), "0.12");
+    assert_eq!(write(0.375), "0.38");
+    assert_eq!(write(-0.001), "-0.00");
+    assert_eq!(write(9.995), "9.99");
+    assert_eq!(write(9.996), "10.00");
+    assert_eq!(write(1234.5), "1234.50");
+    assert_eq!(write(1e21), "1000000000000000000000.00");
+    assert_eq!(write(f64::NAN), "NaN");
+
+    const TRUNCATE: Options = OPTIONS.rebuild().round_mode(RoundMode::Truncate).build_strict();
+    let digits = 0.999f64.to_lexical_with_options::<STANDARD>(&mut buffer, &TRUNCATE);
+    assert_eq!(digits, b"0.99");
+
+    const INTEGER: Options = Options::builder().decimal_places(Some(0)).build_strict();
+    let digits = 2.5f64.to_lexical_with_options::<STANDARD>(&mut buffer, &INTEGER);
+    assert_eq!(digits, b"2");
+    let digits = 3.5f64.to_lexical_with_options::<STANDARD>(&mut buffer, &INTEGER);
+    assert_eq!(digits, b"4");
+}
+
+#[test]
+fn decimal_places_std_test() {
+    use std::fmt::Write;
+
+    let mut expected = String::new();
+    let values = [
+        0.1f64,
+        0.3,
+        2.675,
+        1.0 / 3.0,
+        123456.789,
+        5e-324,
+        2.2250738585072014e-308,
+        1.7976931348623157e308,
+        9007199254740993.0,
+        0.5,
+        1.5,
+        2.5,
+    ];
+    for places in [0, 1, 2, 3, 7, 17, 30, 340, 1100] {
+        let options = Options::builder().decimal_places(Some(places)).build_strict();
+        let mut buffer = vec![b'\x00'; options.buffer_size_const::<f64, STANDARD>()];
+        for &value in values.iter() {
+            for value in [value, -value] {
+                let digits = value.to_lexical_with_options::<STANDARD>(&mut buffer, &options);
+                expected.clear();
+                write!(expected, "{value:.places$}").unwrap();
+                assert_eq!(std::str::from_utf8(digits), Ok(expected.as_str()));
+            }
+        }
+        let mut buffer = vec![b'\x00'; options.buffer_size_const::<f32, STANDARD>()];
+        for value in [0.1f32, 16777217.0, f32::MAX, f32::MIN_POSITIVE, 1e-45] {
+            let digits = value.to_lexical_with_options::<STANDARD>(&mut buffer, &options);
+            expected.clear();
+            write!(expected, "{value:.places$}").unwrap();
+            assert_eq!(std::str::from_utf8(digits), Ok(expected.as_str()));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn decimal_places_radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    const HEX: u128 = NumberFormatBuilder::from_radix(16);
+    const OPTIONS: Options = Options::builder().decimal_places(Some(3)).build_strict();
+    let mut buffer = [b'\x00'; OPTIONS.buffer_size_const::<f64, BINARY>()];
+    let digits = 2.625f64.to_lexical_with_options::<BINARY>(&mut buffer, &OPTIONS);
+    assert_eq!(digits, b"10.101");
+    let digits = 2.5625f64.to_lexical_with_options::<BINARY>(&mut buffer, &OPTIONS);
+    assert_eq!(digits, b"10.100");
+    let digits = 255.999f64.to_lexical_with_options::<HEX>(&mut buffer, &OPTIONS);
+    assert_eq!(digits, b"FF.FFC");
+}
+
+#[test]
+fn exact_digits_test() {
+    const OPTIONS: Options = Options::builder().exact_digits(true).build_strict();
+    let mut buffer = [b'\x00'; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    let mut write = |x: f64, options: &Options| {
+        let digits = x.to_lexical_with_options::<STANDARD>(&mut buffer, options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+    assert_eq!(write(0.0, &OPTIONS), "0.0");
+    assert_eq!(write(1.0, &OPTIONS), "1.0");
+    assert_eq!(write(0.5, &OPTIONS), "0.5");
+    assert_eq!(write(-0.1, &OPTIONS), "-0.1000000000000000055511151231257827021181583404541015625");
+    assert_eq!(write(1e23, &OPTIONS), "9.9999999999999991611392e22");
+    assert_eq!(write(123456.0, &OPTIONS), "123456.0");
+    assert_eq!(
+        write(1e-7, &OPTIONS),
+        "9.99999999999999954748111825886258685613938723690807819366455078125e-8"
+    );
+    assert!(
+        write(5e-324, &OPTIONS).starts_with("4.940656458412465441765687928682213723650598026143")
+    );
+    assert!(write(5e-324, &OPTIONS).ends_with("5533447265625e-324"));
+
+    const MAX: Options =
+        OPTIONS.rebuild().max_significant_digits(num::NonZeroUsize::new(3)).build_strict();
+    assert_eq!(write(0.1, &MAX), "0.1");
+    assert_eq!(write(999.5, &MAX), "1000.0");
+    assert_eq!(write(0.125, &MAX), "0.125");
+    assert_eq!(write(1.0625, &MAX), "1.06");
+    assert_eq!(write(1.1875, &MAX), "1.19");
+    assert_eq!(write(123456.0, &MAX), "123000.0");
+    assert_eq!(write(123500.0, &MAX), "124000.0");
+    assert_eq!(write(122500.0, &MAX), "122000.0");
+    assert_eq!(write(122500.5, &MAX), "123000.0");
+
+    const MIN: Options =
+        OPTIONS.rebuild().min_significant_digits(num::NonZeroUsize::new(25)).build_strict();
+    assert_eq!(write(0.5, &MIN), "0.5000000000000000000000000");
+    assert_eq!(write(0.1, &MIN), "0.1000000000000000055511151231257827021181583404541015625");
+}
+
+#[test]
+fn exact_digits_std_test() {
+    use std::fmt::Write;
+
+    // Get the significant digits from scientific notation.
+    fn significant(x: &str) -> &str {
+        let mantissa = x.split(['e', 'E']).next().unwrap();
+        mantissa.trim_end_matches('0').trim_end_matches('.')
+    }
+
+    let mut expected = String::new();
+    let mut actual = String::new();
+    let values = [0.1f64, 1.0 / 3.0, 2.675, 1e23, 5e-324, 2.2250738585072014e-308, f64::MAX];
+    for digits in [1, 2, 5, 17, 20, 50, 800] {
+        let options = Options::builder()
+            .exact_digits(true)
+            .max_significant_digits(num::NonZeroUsize::new(digits))
+            .positive_exponent_break(num::NonZeroI32::new(1))
+            .negative_exponent_break(num::NonZeroI32::new(-1))
+            .build_strict();
+        let mut buffer = vec![b'\x00'; options.buffer_size_const::<f64, STANDARD>()];
+        for value in values {
+            let result = value.to_lexical_with_options::<STANDARD>(&mut buffer, &options);
+            actual.clear();
+            actual.push_str(std::str::from_utf8(result).unwrap());
+            expected.clear();
+            write!(expected, "{value:.prec$e}", prec = digits - 1).unwrap();
+            let actual = actual.replace('.', "");
+            let expected = expected.replace('.', "");
+            let actual = significant(&actual).trim_start_matches('0');
+            assert_eq!(actual, significant(&expected).trim_start_matches('0'));
+        }
+    }
+}
+
+#[test]
+fn round_mode_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut write = |x: f64, digits: usize, round_mode: RoundMode| {
+        let options = Options::builder()
+            .max_significant_digits(num::NonZeroUsize::new(digits))
+            .round_mode(round_mode)
+            .build_strict();
+        let digits = x.to_lexical_with_options::<STANDARD>(&mut buffer, &options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+
+    assert_eq!(write(0.125, 2, RoundMode::HalfEven), "0.12");
+    assert_eq!(write(0.125, 2, RoundMode::HalfAwayFromZero), "0.13");
+    assert_eq!(write(0.125, 2, RoundMode::HalfTowardZero), "0.12");
+    assert_eq!(write(0.125, 2, RoundMode::Ceiling), "0.13");
+    assert_eq!(write(0.125, 2, RoundMode::Floor), "0.12");
+    assert_eq!(write(-0.125, 2, RoundMode::HalfEven), "-0.12");
+    assert_eq!(write(-0.125, 2, RoundMode::HalfAwayFromZero), "-0.13");
+    assert_eq!(write(-0.125, 2, RoundMode::HalfTowardZero), "-0.12");
+    assert_eq!(write(-0.125, 2, RoundMode::Ceiling), "-0.12");
+    assert_eq!(write(-0.125, 2, RoundMode::Floor), "-0.13");
+
+    assert_eq!(write(2.5, 1, RoundMode::HalfEven), "2.0");
+    assert_eq!(write(3.5, 1, RoundMode::HalfEven), "4.0");
+    assert_eq!(write(2.5, 1, RoundMode::HalfAwayFromZero), "3.0");
+    assert_eq!(write(2.5, 1, RoundMode::HalfTowardZero), "2.0");
+    assert_eq!(write(9.5, 1, RoundMode::HalfAwayFromZero), "10.0");
+
+    // The exact values are not at the halfway point.
+    assert_eq!(write(0.135, 2, RoundMode::HalfTowardZero), "0.14");
+    assert_eq!(write(0.145, 2, RoundMode::HalfAwayFromZero), "0.14");
+    assert_eq!(write(0.1, 3, RoundMode::Ceiling), "0.101");
+    assert_eq!(write(0.1, 3, RoundMode::Floor), "0.1");
+    assert_eq!(write(-0.1, 3, RoundMode::Floor), "-0.101");
+    assert_eq!(write(-0.1, 3, RoundMode::Ceiling), "-0.1");
+    assert_eq!(write(0.1, 3, RoundMode::HalfEven), "0.1");
+    assert_eq!(write(1.0, 3, RoundMode::Ceiling), "1.0");
+}
+
+#[test]
+fn round_mode_decimal_places_test() {
+    let mut buffer = [b'\x00'; 512];
+    let mut write = |x: f64, round_mode: RoundMode| {
+        let options =
+            Options::builder().decimal_places(Some(2)).round_mode(round_mode).build_strict();
+        let digits = x.to_lexical_with_options::<STANDARD>(&mut buffer, &options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+
+    assert_eq!(write(0.125, RoundMode::HalfEven), "0.12");
+    assert_eq!(write(0.125, RoundMode::HalfAwayFromZero), "0.13");
+    assert_eq!(write(0.375, RoundMode::HalfTowardZero), "0.37");
+    assert_eq!(write(-0.125, RoundMode::HalfAwayFromZero), "-0.13");
+    assert_eq!(write(0.001, RoundMode::Ceiling), "0.01");
+    assert_eq!(write(-0.001, RoundMode::Ceiling), "-0.00");
+    assert_eq!(write(-0.001, RoundMode::Floor), "-0.01");
+    assert_eq!(write(0.019, RoundMode::Floor), "0.01");
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn round_mode_radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let mut buffer = [b'\x00'; 512];
+    let mut write = |x: f64, digits: usize, round_mode: RoundMode| {
+        let options = Options::builder()
+            .max_significant_digits(num::NonZeroUsize::new(digits))
+            .round_mode(round_mode)
+            .build_strict();
+        let digits = x.to_lexical_with_options::<BINARY>(&mut buffer, &options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+
+    assert_eq!(write(0.75, 1, RoundMode::HalfEven), "1.0");
+    assert_eq!(write(0.75, 1, RoundMode::HalfTowardZero), "0.1");
+    assert_eq!(write(0.75, 1, RoundMode::HalfAwayFromZero), "1.0");
+    assert_eq!(write(0.75, 1, RoundMode::Floor), "0.1");
+    assert_eq!(write(-0.75, 1, RoundMode::Ceiling), "-0.1");
+    assert_eq!(write(-0.75, 1, RoundMode::Floor), "-1.0");
+    assert_eq!(write(2.5, 2, RoundMode::HalfEven), "10.0");
+    assert_eq!(write(2.5, 2, RoundMode::HalfAwayFromZero), "11.0");
+    assert_eq!(write(2.25, 2, RoundMode::Ceiling), "11.0");
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn round_leading_zeros_radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    // Leading zeros can push the significant digits past the written digits.
+    const RADIX7: u128 = NumberFormatBuilder::from_radix(7);
+    const OPTIONS: Options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(20))
+        .negative_exponent_break(num::NonZeroI32::new(-300))
+        .build_strict();
+    let mut buffer = [b'\x00'; 1024];
+    let mut write = |x: f64, options: &Options| {
+        let digits = x.to_lexical_with_options::<RADIX7>(&mut buffer, options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+
+    assert_eq!(write(0.5, &OPTIONS), "0.3333333333333333334");
+    assert_eq!(write(0.1, &OPTIONS), "0.04620462046204620463");
+    assert_eq!(write(1e-10, &OPTIONS), "0.0000000000012455202342600253");
+    let small = |digits: &str| ["0.", &"0".repeat(118), digits].concat();
+    assert_eq!(write(1e-100, &OPTIONS), small("34544233315016101164"));
+
+    const SHORT: Options =
+        OPTIONS.rebuild().max_significant_digits(num::NonZeroUsize::new(5)).build_strict();
+    const TRUNCATE: Options = SHORT.rebuild().round_mode(RoundMode::Truncate).build_strict();
+    const CEILING: Options = SHORT.rebuild().round_mode(RoundMode::Ceiling).build_strict();
+    assert_eq!(write(1e-100, &SHORT), small("34544"));
+    assert_eq!(write(1e-100, &TRUNCATE), small("34544"));
+    assert_eq!(write(1e-100, &CEILING), small("34545"));
+}
+
+#[test]
+#[cfg(all(feature = "f16", feature = "radix"))]
+fn half_radix_test() {
+    use lexical_util::bf16::bf16;
+    use lexical_util::f16::f16;
+    use lexical_util::format::NumberFormatBuilder;
+
+    // Half-precision floats are written with the shortest digits for their
+    // own precision, not for the equivalent `f32`.
+    const RADIX3: u128 = NumberFormatBuilder::from_radix(3);
+    const RADIX7: u128 = NumberFormatBuilder::from_radix(7);
+    let mut buffer = [b'\x00'; 1024];
+    let write = |digits: &[u8]| std::str::from_utf8(digits).unwrap().to_string();
+
+    let value = f16::from_f32(0.3);
+    assert_eq!(
+        write(value.to_lexical_with_options::<RADIX3>(&mut buffer, &STANDARD_OPTIONS)),
+        "0.0220022"
+    );
+    let value = value.as_f32();
+    assert_eq!(
+        write(value.to_lexical_with_options::<RADIX3>(&mut buffer, &STANDARD_OPTIONS)),
+        "0.0220022012120202"
+    );
+    let value = f16::from_f32(1.1);
+    assert_eq!(
+        write(value.to_lexical_with_options::<RADIX7>(&mut buffer, &STANDARD_OPTIONS)),
+        "1.046"
+    );
+    let value = f16::from_bits(1);
+    assert_eq!(
+        write(value.to_lexical_with_options::<RADIX3>(&mut buffer, &STANDARD_OPTIONS)),
+        "1.0e-120"
+    );
+    let value = bf16::from_f32(3.3);
+    assert_eq!(
+        write(value.to_lexical_with_options::<RADIX7>(&mut buffer, &STANDARD_OPTIONS)),
+        "3.204"
+    );
+
+    // Powers of two are closer to the previous float, so need more digits.
+    let value = bf16::from_bits(0x0B80);
+    assert_eq!(
+        write(value.to_lexical_with_options::<RADIX3>(&mut buffer, &STANDARD_OPTIONS)),
+        "1.11201e-2110"
+    );
+}
+
+#[test]
+fn engineering_notation_test() {
+    const OPTIONS: Options = Options::builder().notation(Notation::Engineering).build_strict();
+    let mut buffer = [b'\x00'; 1024];
+    let mut write = |x: f64, options: &Options| {
+        let digits = x.to_lexical_with_options::<STANDARD>(&mut buffer, options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+
+    assert_eq!(write(0.0, &OPTIONS), "0.0e0");
+    assert_eq!(write(1.5, &OPTIONS), "1.5e0");
+    assert_eq!(write(12.5, &OPTIONS), "12.5e0");
+    assert_eq!(write(125.0, &OPTIONS), "125.0e0");
+    assert_eq!(write(1000.0, &OPTIONS), "1.0e3");
+    assert_eq!(write(10000.0, &OPTIONS), "10.0e3");
+    assert_eq!(write(100000.0, &OPTIONS), "100.0e3");
+    assert_eq!(write(12500.0, &OPTIONS), "12.5e3");
+    assert_eq!(write(123456.0, &OPTIONS), "123.456e3");
+    assert_eq!(write(-0.5, &OPTIONS), "-500.0e-3");
+    assert_eq!(write(0.05, &OPTIONS), "50.0e-3");
+    assert_eq!(write(4.7e-8, &OPTIONS), "47.0e-9");
+    assert_eq!(write(1e-100, &OPTIONS), "100.0e-102");
+    assert_eq!(write(1.7976931348623157e308, &OPTIONS), "179.76931348623157e306");
+
+    const TRIM: Options = OPTIONS.rebuild().trim_floats(true).build_strict();
+    assert_eq!(write(10000.0, &TRIM), "10e3");
+    assert_eq!(write(1e5, &TRIM), "100e3");
+    assert_eq!(write(1.25e4, &TRIM), "12.5e3");
+
+    const MIN: Options =
+        OPTIONS.rebuild().min_significant_digits(num::NonZeroUsize::new(5)).build_strict();
+    assert_eq!(write(1e4, &MIN), "10.000e3");
+
+    const EXACT: Options = OPTIONS.rebuild().exact_digits(true).build_strict();
+    assert_eq!(write(1e23, &EXACT), "99.999999999999991611392e21");
+}
+
+#[test]
+fn si_prefix_notation_test() {
+    const OPTIONS: Options =
+        Options::builder().notation(Notation::SiPrefix).trim_floats(true).build_strict();
+    let mut buffer = [b'\x00'; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    let mut write = |x: f64, options: &Options| {
+        let digits = x.to_lexical_with_options::<STANDARD>(&mut buffer, options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+
+    assert_eq!(write(0.0, &OPTIONS), "0");
+    assert_eq!(write(1.5, &OPTIONS), "1.5");
+    assert_eq!(write(12500.0, &OPTIONS), "12.5k");
+    assert_eq!(write(3.3e-6, &OPTIONS), "3.3\u{b5}");
+    assert_eq!(write(47e-9, &OPTIONS), "47n");
+    assert_eq!(write(-2.2e6, &OPTIONS), "-2.2M");
+    assert_eq!(write(1e-30, &OPTIONS), "1q");
+    assert_eq!(write(1e30, &OPTIONS), "1Q");
+    assert_eq!(write(1e-31, &OPTIONS), "100e-33");
+    assert_eq!(write(1e33, &OPTIONS), "1e33");
+
+    const FRACTION: Options = OPTIONS.rebuild().trim_floats(false).build_strict();
+    assert_eq!(write(1e3, &FRACTION), "1.0k");
+    assert_eq!(write(0.1, &FRACTION), "100.0m");
+}
+
+#[test]
+fn exponent_sign_digits_test() {
+    const OPTIONS: Options = Options::builder()
+        .exponent_plus_sign(true)
+        .min_exponent_digits(num::NonZeroUsize::new(3))
+        .build_strict();
+    let mut buffer = [b'\x00'; OPTIONS.buffer_size_const::<f64, STANDARD>()];
+    let mut write = |x: f64, options: &Options| {
+        let digits = x.to_lexical_with_options::<STANDARD>(&mut buffer, options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+
+    assert_eq!(write(1.5, &OPTIONS), "1.5");
+    assert_eq!(write(1.5e10, &OPTIONS), "1.5e+010");
+    assert_eq!(write(-1.5e-10, &OPTIONS), "-1.5e-010");
+    assert_eq!(write(1e300, &OPTIONS), "1.0e+300");
+    assert_eq!(write(5e-324, &OPTIONS), "5.0e-324");
+
+    const TRIM: Options = OPTIONS.rebuild().trim_scientific(true).build_strict();
+    assert_eq!(write(1e10, &TRIM), "1e+010");
+    assert_eq!(write(1.0, &TRIM), "1.0");
+    assert_eq!(
+        write(1e4, &TRIM.rebuild().notation(Notation::Engineering).build_strict()),
+        "10e+003"
+    );
+
+    const UNSIGNED_ZERO: Options = Options::builder().signed_zero(false).build_strict();
+    assert_eq!(write(-0.0, &UNSIGNED_ZERO), "0.0");
+    assert_eq!(write(-1.0, &UNSIGNED_ZERO), "-1.0");
+    assert_eq!(write(-0.0, &STANDARD_OPTIONS), "-0.0");
+}
+
+#[test]
+fn language_presets_test() {
+    let mut buffer = [b'\x00'; 512];
+    let mut write = |x: f64, options: &Options| {
+        let digits = x.to_lexical_with_options::<STANDARD>(&mut buffer, options);
+        std::str::from_utf8(digits).unwrap().to_string()
+    };
+
+    let options = options::JAVASCRIPT_TO_STRING;
+    assert_eq!(write(1e21, &options), "1e+21");
+    assert_eq!(write(1e20, &options), "100000000000000000000");
+    assert_eq!(write(1e-6, &options), "0.000001");
+    assert_eq!(write(1.5e-7, &options), "1.5e-7");
+    assert_eq!(write(1.23e27, &options), "1.23e+27");
+    assert_eq!(write(100.0, &options), "100");
+    assert_eq!(write(0.1 + 0.2, &options), "0.30000000000000004");
+    assert_eq!(write(5e-324, &options), "5e-324");
+    assert_eq!(write(-0.0, &options), "0");
+    assert_eq!(write(f64::NAN, &options), "NaN");
+    assert_eq!(write(f64::NEG_INFINITY, &options), "-Infinity");
+
+    let options = options::PYTHON_REPR;
+    assert_eq!(write(1e16, &options), "1e+16");
+    assert_eq!(write(1e15, &options), "1000000000000000.0");
+    assert_eq!(write(1e-5, &options), "1e-05");
+    assert_eq!(write(1e-4, &options), "0.0001");
+    assert_eq!(write(1.5e300, &options), "1.5e+300");
+    assert_eq!(write(1.0, &options), "1.0");
+    assert_eq!(write(-0.0, &options), "-0.0");
+    assert_eq!(write(f64::NAN, &options), "nan");
+    assert_eq!(write(f64::INFINITY, &options), "inf");
+    assert_eq!(write(f64::NEG_INFINITY, &options), "-inf");
+
+    let options = options::GO_FORMAT_FLOAT;
+    assert_eq!(write(1e6, &options), "1e+06");
+    assert_eq!(write(1e5, &options), "100000");
+    assert_eq!(write(1234567.0, &options), "1.234567e+06");
+    assert_eq!(write(1e-5, &options), "1e-05");
+    assert_eq!(write(1e-4, &options), "0.0001");
+    assert_eq!(write(1.0, &options), "1");
+    assert_eq!(write(-0.0, &options), "-0");
+    assert_eq!(write(f64::NAN, &options), "NaN");
+    assert_eq!(write(f64::INFINITY, &options), "+Inf");
+    assert_eq!(write(f64::NEG_INFINITY, &options), "-Inf");
+
+    let options = options::JAVA_TO_STRING;
+    assert_eq!(write(1e7, &options), "1.0E7");
+    assert_eq!(write(1e6, &options), "1000000.0");
+    assert_eq!(write(1e-3, &options), "0.001");
+    assert_eq!(write(1e-4, &options), "1.0E-4");
+    assert_eq!(write(123.456, &options), "123.456");
+    assert_eq!(write(1.7976931348623157e308, &options), "1.7976931348623157E308");
+    assert_eq!(write(1.0, &options), "1.0");
+    assert_eq!(write(2e-3, &options), "0.002");
+    assert_eq!(write(5e-324, &options), "4.9E-324");
+    assert_eq!(write(1e-323, &options), "9.9E-324");
+    assert_eq!(write(2e-323, &options), "2.0E-323");
+    assert_eq!(write(-0.0, &options), "-0.0");
+    assert_eq!(write(f64::NAN, &options), "NaN");
+    assert_eq!(write(f64::NEG_INFINITY, &options), "-Infinity");
+
+    let options = options::C_PRINTF_G;
+    assert_eq!(write(123456789.0, &options), "1.23457e+08");
+    assert_eq!(write(1e5, &options), "100000");
+    assert_eq!(write(999999.5, &options), "1e+06");
+    assert_eq!(write(0.000123456789, &options), "0.000123457");
+    assert_eq!(write(1e-5, &options), "1e-05");
+    assert_eq!(write(1e100, &options), "1e+100");
+    assert_eq!(write(0.1, &options), "0.1");
+    assert_eq!(write(1.0, &options), "1");
+    assert_eq!(write(0.5, &options), "0.5");
+    assert_eq!(write(-0.0, &options), "-0");
+    assert_eq!(write(f64::NAN, &options), "nan");
+    assert_eq!(write(f64::NEG_INFINITY, &options), "-inf");
+
+    let options = options::C_PRINTF_G17;
+    assert_eq!(write(0.1, &options), "0.10000000000000001");
+    assert_eq!(write(0.3, &options), "0.29999999999999999");
+    assert_eq!(write(123.456, &options), "123.456");
+    assert_eq!(write(1e16, &options), "10000000000000000");
+    assert_eq!(write(1e17, &options), "1e+17");
+    assert_eq!(write(1e-5, &options), "1.0000000000000001e-05");
+}
@@ -187,7 +187,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BINARY>(2.0f64, &OPTIONS, "1.0e1");
     write_float_scientific::<_, BINARY>(0.5f64, &OPTIONS, "1.0e-1");
     write_float_scientific::<_, BINARY>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTIONS,
         "1.01110111010011110000000111111110110100110010011001e100",
     );
@@ -218,7 +218,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BASE4>(2.0f64, &OPTIONS, "2.0e0");
     write_float_scientific::<_, BASE4>(0.5f64, &OPTIONS, "2.0e-1");
     write_float_scientific::<_, BASE4>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTIONS,
         "1.1313103300013332310302121e2",
     );
@@ -248,11 +248,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, OCTAL>(1.0f64, &OPTIONS, "1.0e0");
     write_float_scientific::<_, OCTAL>(2.0f64, &OPTIONS, "2.0e0");
     write_float_scientific::<_, OCTAL>(0.5f64, &OPTIONS, "4.0e-1");
-    write_float_scientific::<_, OCTAL>(
-        0.2345678901234567890e2f64,
-        &OPTIONS,
-        "2.73517003773231144e1",
-    );
+    write_float_scientific::<_, OCTAL>(2.345678901234568e1f64, &OPTIONS, "2.73517003773231144e1");
     write_float_scientific::<_, OCTAL>(0.1172839450617284e2f64, &OPTIONS, "1.35647401775514462e1");
     write_float_scientific::<_, OCTAL>(0.0586419725308642e2f64, &OPTIONS, "5.6723600776646231e0");
     write_float_scientific::<_, OCTAL>(0.0293209862654321e2f64, &OPTIONS, "2.73517003773231144e0");
@@ -263,7 +259,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, HEX>(1.0f64, &HEX_OPTIONS, "1.0^0");
     write_float_scientific::<_, HEX>(2.0f64, &HEX_OPTIONS, "2.0^0");
     write_float_scientific::<_, HEX>(0.5f64, &HEX_OPTIONS, "8.0^-1");
-    write_float_scientific::<_, HEX>(0.2345678901234567890e2f64, &HEX_OPTIONS, "1.774F01FED3264^1");
+    write_float_scientific::<_, HEX>(2.345678901234568e1f64, &HEX_OPTIONS, "1.774F01FED3264^1");
     write_float_scientific::<_, HEX>(0.1172839450617284e2f64, &HEX_OPTIONS, "B.BA780FF69932^0");
     write_float_scientific::<_, HEX>(0.0586419725308642e2f64, &HEX_OPTIONS, "5.DD3C07FB4C99^0");
     write_float_scientific::<_, HEX>(0.0293209862654321e2f64, &HEX_OPTIONS, "2.EE9E03FDA64C8^0");
@@ -274,7 +270,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BASE32>(1.0f64, &HEX_OPTIONS, "1.0^0");
     write_float_scientific::<_, BASE32>(2.0f64, &HEX_OPTIONS, "2.0^0");
     write_float_scientific::<_, BASE32>(0.5f64, &HEX_OPTIONS, "G.0^-1");
-    write_float_scientific::<_, BASE32>(0.2345678901234567890e2f64, &HEX_OPTIONS, "N.EJO1VR9ICG^0");
+    write_float_scientific::<_, BASE32>(2.345678901234568e1f64, &HEX_OPTIONS, "N.EJO1VR9ICG^0");
     write_float_scientific::<_, BASE32>(0.1172839450617284e2f64, &HEX_OPTIONS, "B.N9S0VTKP68^0");
     write_float_scientific::<_, BASE32>(0.0586419725308642e2f64, &HEX_OPTIONS, "5.RKU0FUQCJ4^0");
     write_float_scientific::<_, BASE32>(0.0293209862654321e2f64, &HEX_OPTIONS, "2.TQF07VD69I^0");
@@ -284,7 +280,7 @@ fn write_float_scientific_test() {
 
     // Check no formatting, binary.
     write_float_scientific::<_, BINARY>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTIONS,
         "1.11100000011001010010000101000110001011001111110111e-11",
     );
@@ -311,7 +307,7 @@ fn write_float_scientific_test() {
 
     // Check no formatting, base 4.
     write_float_scientific::<_, BASE4>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTIONS,
         "3.3000302210022030112133232e-2",
     );
@@ -337,25 +333,21 @@ fn write_float_scientific_test() {
     );
 
     // Check no formatting, octal.
-    write_float_scientific::<_, OCTAL>(
-        0.2345678901234567890f64,
-        &OPTIONS,
-        "1.70062441214263756e-1",
-    );
+    write_float_scientific::<_, OCTAL>(0.2345678901234568f64, &OPTIONS, "1.70062441214263756e-1");
     write_float_scientific::<_, OCTAL>(0.1172839450617284f64, &OPTIONS, "7.4031220506131767e-2");
     write_float_scientific::<_, OCTAL>(0.0586419725308642f64, &OPTIONS, "3.60145102430547734e-2");
     write_float_scientific::<_, OCTAL>(0.0293209862654321f64, &OPTIONS, "1.70062441214263756e-2");
     write_float_scientific::<_, OCTAL>(0.01466049313271605f64, &OPTIONS, "7.4031220506131767e-3");
 
     // Check no formatting, hexadecimal.
-    write_float_scientific::<_, HEX>(0.2345678901234567890f64, &HEX_OPTIONS, "3.C0CA428C59FB8^-1");
+    write_float_scientific::<_, HEX>(0.2345678901234568f64, &HEX_OPTIONS, "3.C0CA428C59FB8^-1");
     write_float_scientific::<_, HEX>(0.1172839450617284f64, &HEX_OPTIONS, "1.E06521462CFDC^-1");
     write_float_scientific::<_, HEX>(0.0586419725308642f64, &HEX_OPTIONS, "F.03290A3167EE^-2");
     write_float_scientific::<_, HEX>(0.0293209862654321f64, &HEX_OPTIONS, "7.81948518B3F7^-2");
     write_float_scientific::<_, HEX>(0.01466049313271605f64, &HEX_OPTIONS, "3.C0CA428C59FB8^-2");
 
     // Check no formatting, base 32.
-    write_float_scientific::<_, BASE32>(0.2345678901234567890f64, &HEX_OPTIONS, "7.G6A8A65JUS^-1");
+    write_float_scientific::<_, BASE32>(0.2345678901234568f64, &HEX_OPTIONS, "7.G6A8A65JUS^-1");
     write_float_scientific::<_, BASE32>(0.1172839450617284f64, &HEX_OPTIONS, "3.O354532PVE^-1");
     write_float_scientific::<_, BASE32>(0.0586419725308642f64, &HEX_OPTIONS, "1.S1II2HHCVN^-1");
     write_float_scientific::<_, BASE32>(0.0293209862654321f64, &HEX_OPTIONS, "U.0P918OMFRG^-2");
@@ -363,41 +355,25 @@ fn write_float_scientific_test() {
 
     // Different exponent radix.
     write_float_scientific::<_, BASE2_2_4>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTIONS,
         "1.01110111010011110000000111111110110100110010011001e10",
     );
     write_float_scientific::<_, BASE4_4_8>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTIONS,
         "1.1313103300013332310302121e2",
     );
 
     // Check no formatting, f32, binary.
-    write_float_scientific::<_, BINARY>(
-        1.2345678901234567890f32,
-        &OPTIONS,
-        "1.0011110000001100101001e0",
-    );
-    write_float_scientific::<_, BINARY>(
-        3.2345678901234567890f32,
-        &OPTIONS,
-        "1.10011110000001100101001e1",
-    );
+    write_float_scientific::<_, BINARY>(1.2345679f32, &OPTIONS, "1.0011110000001100101001e0");
+    write_float_scientific::<_, BINARY>(3.2345679f32, &OPTIONS, "1.10011110000001100101001e1");
     write_float_scientific::<_, BINARY>(1f32, &OPTIONS, "1.0e0");
-    write_float_scientific::<_, BINARY>(
-        0.2345678901234567890f32,
-        &OPTIONS,
-        "1.11100000011001010010001e-11",
-    );
-    write_float_scientific::<_, BINARY>(
-        0.7345678901234567890f32,
-        &OPTIONS,
-        "1.011110000001100101001e-1",
-    );
+    write_float_scientific::<_, BINARY>(0.2345679f32, &OPTIONS, "1.11100000011001010010001e-11");
+    write_float_scientific::<_, BINARY>(0.7345679f32, &OPTIONS, "1.011110000001100101001e-1");
     write_float_scientific::<_, BINARY>(1.4e-45f32, &OPTIONS, "1.0e-10010101");
     write_float_scientific::<_, BINARY>(
-        3.4028234664e38f32,
+        3.4028235e38f32,
         &OPTIONS,
         "1.11111111111111111111111e1111111",
     );
@@ -410,7 +386,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BINARY>(2.0f64, &MIN_DIGITS, "1.0000e1");
     write_float_scientific::<_, BINARY>(0.5f64, &MIN_DIGITS, "1.0000e-1");
     write_float_scientific::<_, BASE4>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &MIN_DIGITS,
         "1.1313103300013332310302121e2",
     );
@@ -424,7 +400,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BINARY>(2.0f64, &TRIM_MIN_DIGITS, "1e1");
     write_float_scientific::<_, BINARY>(0.5f64, &TRIM_MIN_DIGITS, "1e-1");
     write_float_scientific::<_, BASE4>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &TRIM_MIN_DIGITS,
         "1.1313103300013332310302121e2",
     );
@@ -433,11 +409,7 @@ fn write_float_scientific_test() {
     const TRIM: Options = Options::builder().trim_floats(true).build_strict();
     write_float_scientific::<_, BINARY>(1f32, &TRIM, "1e0");
     write_float_scientific::<_, BINARY>(1.4e-45f32, &TRIM, "1e-10010101");
-    write_float_scientific::<_, BINARY>(
-        1.2345678901234567890f32,
-        &TRIM,
-        "1.0011110000001100101001e0",
-    );
+    write_float_scientific::<_, BINARY>(1.2345679f32, &TRIM, "1.0011110000001100101001e0");
 }
 
 // NOTE: This doesn't handle float rounding or truncation.
@@ -473,7 +445,7 @@ fn write_float_negative_exponent_test() {
     // Check no formatting, binary.
     const OPTS1: Options = Options::builder().build_strict();
     write_float_negative_exponent::<_, BINARY>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTS1,
         "0.00111100000011001010010000101000110001011001111110111",
     );
@@ -500,7 +472,7 @@ fn write_float_negative_exponent_test() {
 
     // Check no formatting, base 4.
     write_float_negative_exponent::<_, BASE4>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTS1,
         "0.033000302210022030112133232",
     );
@@ -527,7 +499,7 @@ fn write_float_negative_exponent_test() {
 
     // Check no formatting, octal.
     write_float_negative_exponent::<_, OCTAL>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTS1,
         "0.170062441214263756",
     );
@@ -554,7 +526,7 @@ fn write_float_negative_exponent_test() {
 
     // Check no formatting, hexadecimal.
     write_float_negative_exponent::<_, HEX>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &HEX_OPTIONS,
         "0.3C0CA428C59FB8",
     );
@@ -581,7 +553,7 @@ fn write_float_negative_exponent_test() {
 
     // Check no formatting, base 32.
     write_float_negative_exponent::<_, BASE32>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &HEX_OPTIONS,
         "0.7G6A8A65JUS",
     );
@@ -608,42 +580,34 @@ fn write_float_negative_exponent_test() {
 
     // Different exponent radix.
     write_float_negative_exponent::<_, BASE2_2_4>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTS1,
         "0.00111100000011001010010000101000110001011001111110111",
     );
     write_float_negative_exponent::<_, BASE4_2_32>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTS1,
         "0.033000302210022030112133232",
     );
     write_float_negative_exponent::<_, BASE4_4_8>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTS1,
         "0.033000302210022030112133232",
     );
     write_float_negative_exponent::<_, BASE4_8_4>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTS1,
         "0.033000302210022030112133232",
     );
-    write_float_negative_exponent::<_, BASE32_2_32>(
-        0.2345678901234567890f64,
-        &OPTS1,
-        "0.7G6A8A65JUS",
-    );
+    write_float_negative_exponent::<_, BASE32_2_32>(0.2345678901234568f64, &OPTS1, "0.7G6A8A65JUS");
 
     // Check no formatting, f32, binary.
     write_float_negative_exponent::<_, BINARY>(
-        0.2345678901234567890f32,
+        0.2345679f32,
         &OPTS1,
         "0.00111100000011001010010001",
     );
-    write_float_negative_exponent::<_, BINARY>(
-        0.7345678901234567890f32,
-        &OPTS1,
-        "0.1011110000001100101001",
-    );
+    write_float_negative_exponent::<_, BINARY>(0.7345679f32, &OPTS1, "0.1011110000001100101001");
     write_float_negative_exponent::<_, BINARY>(1.4e-45f32, &OPTS1, "0.00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001");
 
     // Check with a minimum number of digits.
@@ -651,7 +615,7 @@ fn write_float_negative_exponent_test() {
         Options::builder().min_significant_digits(num::NonZeroUsize::new(5)).build_strict();
     write_float_negative_exponent::<_, BINARY>(0.5f64, &OPTS2, "0.10000");
     write_float_negative_exponent::<_, BASE4>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTS2,
         "0.033000302210022030112133232",
     );
@@ -662,7 +626,7 @@ fn write_float_negative_exponent_test() {
         .build_strict();
     write_float_negative_exponent::<_, BINARY>(0.5f64, &OPTS3, "0.10000");
     write_float_negative_exponent::<_, BASE4>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTS3,
         "0.033000302210022030112133232",
     );
@@ -671,7 +635,7 @@ fn write_float_negative_exponent_test() {
     const OPTS4: Options = Options::builder().trim_floats(true).build_strict();
     write_float_negative_exponent::<_, BINARY>(0.5f64, &OPTS4, "0.1");
     write_float_negative_exponent::<_, BASE4>(
-        0.2345678901234567890f64,
+        0.2345678901234568f64,
         &OPTS4,
         "0.033000302210022030112133232",
     );
@@ -716,7 +680,7 @@ fn write_float_positive_exponent_test() {
     write_float_positive_exponent::<_, BINARY>(1.0f64, &OPTS1, "1.0");
     write_float_positive_exponent::<_, BINARY>(2.0f64, &OPTS1, "10.0");
     write_float_positive_exponent::<_, BINARY>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTS1,
         "10111.0111010011110000000111111110110100110010011001",
     );
@@ -746,7 +710,7 @@ fn write_float_positive_exponent_test() {
     write_float_positive_exponent::<_, BASE4>(1.0f64, &OPTS1, "1.0");
     write_float_positive_exponent::<_, BASE4>(2.0f64, &OPTS1, "2.0");
     write_float_positive_exponent::<_, BASE4>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTS1,
         "113.13103300013332310302121",
     );
@@ -776,7 +740,7 @@ fn write_float_positive_exponent_test() {
     write_float_positive_exponent::<_, OCTAL>(1.0f64, &OPTS1, "1.0");
     write_float_positive_exponent::<_, OCTAL>(2.0f64, &OPTS1, "2.0");
     write_float_positive_exponent::<_, OCTAL>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTS1,
         "27.3517003773231144",
     );
@@ -806,7 +770,7 @@ fn write_float_positive_exponent_test() {
     write_float_positive_exponent::<_, HEX>(1.0f64, &HEX_OPTIONS, "1.0");
     write_float_positive_exponent::<_, HEX>(2.0f64, &HEX_OPTIONS, "2.0");
     write_float_positive_exponent::<_, HEX>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &HEX_OPTIONS,
         "17.74F01FED3264",
     );
@@ -836,7 +800,7 @@ fn write_float_positive_exponent_test() {
     write_float_positive_exponent::<_, BASE32>(1.0f64, &HEX_OPTIONS, "1.0");
     write_float_positive_exponent::<_, BASE32>(2.0f64, &HEX_OPTIONS, "2.0");
     write_float_positive_exponent::<_, BASE32>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &HEX_OPTIONS,
         "N.EJO1VR9ICG",
     );
@@ -863,73 +827,53 @@ fn write_float_positive_exponent_test() {
 
     // Different exponent radix.
     write_float_positive_exponent::<_, BASE2_2_4>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTS1,
         "10111.0111010011110000000111111110110100110010011001",
     );
     write_float_positive_exponent::<_, BASE4_2_32>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTS1,
         "113.13103300013332310302121",
     );
     write_float_positive_exponent::<_, BASE4_4_8>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTS1,
         "113.13103300013332310302121",
     );
     write_float_positive_exponent::<_, BASE4_8_4>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &OPTS1,
         "113.13103300013332310302121",
     );
     write_float_positive_exponent::<_, BASE32_2_32>(
-        0.2345678901234567890e2f64,
+        2.345678901234568e1f64,
         &HEX_OPTIONS,
         "N.EJO1VR9ICG",
     );
 
     // Check no formatting, f32, binary.
-    write_float_positive_exponent::<_, BINARY>(
-        0.2345678901234567890e2f32,
-        &OPTS1,
-        "10111.0111010011110000001",
-    );
-    write_float_positive_exponent::<_, BINARY>(
-        0.7345678901234567890e2f32,
-        &OPTS1,
-        "1001001.011101001111",
-    );
-    write_float_positive_exponent::<_, BINARY>(3.4028234664e38f32, &OPTS1, "11111111111111111111111100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0");
+    write_float_positive_exponent::<_, BINARY>(2.3456789e1f32, &OPTS1, "10111.0111010011110000001");
+    write_float_positive_exponent::<_, BINARY>(7.345679e1f32, &OPTS1, "1001001.011101001111");
+    write_float_positive_exponent::<_, BINARY>(3.4028235e38f32, &OPTS1, "11111111111111111111111100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0");
 
     // Check with a minimum number of digits.
     const OPTS2: Options =
         Options::builder().min_significant_digits(num::NonZeroUsize::new(5)).build_strict();
     write_float_positive_exponent::<_, BINARY>(1.0f64, &OPTS2, "1.0000");
-    write_float_positive_exponent::<_, BINARY>(
-        0.2345678901234567890e2f32,
-        &OPTS2,
-        "10111.0111010011110000001",
-    );
+    write_float_positive_exponent::<_, BINARY>(2.3456789e1f32, &OPTS2, "10111.0111010011110000001");
 
     const OPTS3: Options = Options::builder()
         .min_significant_digits(num::NonZeroUsize::new(5))
         .trim_floats(true)
         .build_strict();
     write_float_positive_exponent::<_, BINARY>(1.0f64, &OPTS3, "1");
-    write_float_positive_exponent::<_, BINARY>(
-        0.2345678901234567890e2f32,
-        &OPTS3,
-        "10111.0111010011110000001",
-    );
+    write_float_positive_exponent::<_, BINARY>(2.3456789e1f32, &OPTS3, "10111.0111010011110000001");
 
     // Check trimming floats works.
     const OPTS4: Options = Options::builder().trim_floats(true).build_strict();
     write_float_positive_exponent::<_, BINARY>(1.0f64, &OPTS4, "1");
-    write_float_positive_exponent::<_, BINARY>(
-        0.2345678901234567890e2f32,
-        &OPTS4,
-        "10111.0111010011110000001",
-    );
+    write_float_positive_exponent::<_, BINARY>(2.3456789e1f32, &OPTS4, "10111.0111010011110000001");
 }
 
 fn write_float<T: Float, const FORMAT: u128>(f: T, options: &Options, expected: &str)
@@ -951,7 +895,7 @@ fn write_float_test() {
     write_float::<_, BINARY>(2.0f64, &OPTS1, "10.0");
     write_float::<_, BINARY>(0.5f64, &OPTS1, "0.1");
     write_float::<_, BINARY>(
-        23.45678901234567890f64,
+        23.45678901234568f64,
         &OPTS1,
         "10111.0111010011110000000111111110110100110010011001",
     );
@@ -1014,8 +958,8 @@ fn write_float_test() {
     write_float::<_, BINARY>(1.0f64, &OPTS3, "1.0");
     write_float::<_, BINARY>(2.0f64, &OPTS3, "10.0");
     write_float::<_, BINARY>(0.5f64, &OPTS3, "0.1");
-    write_float::<_, BINARY>(0.2345678901234567890f64, &OPTS3, "0.001111");
-    write_float::<_, BINARY>(23.45678901234567890f64, &OPTS3, "10111.0");
+    write_float::<_, BINARY>(0.2345678901234568f64, &OPTS3, "0.001111");
+    write_float::<_, BINARY>(23.45678901234568f64, &OPTS3, "10111.0");
     write_float::<_, BINARY>(93.82715604938272f64, &OPTS3, "1011100.0");
     write_float::<_, BINARY>(375.3086241975309f64, &OPTS3, "101110000.0");
 
@@ -1024,8 +968,8 @@ fn write_float_test() {
         .max_significant_digits(num::NonZeroUsize::new(5))
         .trim_floats(true)
         .build_strict();
-    write_float::<_, BINARY>(0.2345678901234567890f64, &OPTS4, "0.001111");
-    write_float::<_, BINARY>(23.45678901234567890f64, &OPTS4, "10111");
+    write_float::<_, BINARY>(0.2345678901234568f64, &OPTS4, "0.001111");
+    write_float::<_, BINARY>(23.45678901234568f64, &OPTS4, "10111");
     write_float::<_, BINARY>(93.82715604938272f64, &OPTS4, "1011100");
     write_float::<_, BINARY>(375.3086241975309f64, &OPTS4, "101110000");
 
@@ -1038,8 +982,8 @@ fn write_float_test() {
         .max_significant_digits(num::NonZeroUsize::new(4))
         .round_mode(RoundMode::Round)
         .build_strict();
-    write_float::<_, BINARY>(23.45678901234567890f64, &ROUND1, "11000.0");
-    write_float::<_, BINARY>(23.45678901234567890f64, &TRUNCATE1, "10110.0");
+    write_float::<_, BINARY>(23.45678901234568f64, &ROUND1, "11000.0");
+    write_float::<_, BINARY>(23.45678901234568f64, &TRUNCATE1, "10110.0");
 
     const TRUNCATE2: Options = Options::builder()
         .max_significant_digits(num::NonZeroUsize::new(8))
@@ -1050,20 +994,20 @@ fn write_float_test() {
         .round_mode(RoundMode::Round)
         .build_strict();
 
-    write_float::<_, BINARY>(1.2345678901234567890e0f64, &TRUNCATE2, "1.001111");
-    write_float::<_, BINARY>(1.2345678901234567890e0f64, &ROUND2, "1.001111");
-    write_float::<_, BINARY>(1.2345678901234567890e1f64, &TRUNCATE2, "1100.0101");
-    write_float::<_, BINARY>(1.2345678901234567890e1f64, &ROUND2, "1100.011");
-    write_float::<_, BINARY>(1.2345678901234567890e2f64, &TRUNCATE2, "1111011.0");
-    write_float::<_, BINARY>(1.2345678901234567890e2f64, &ROUND2, "1111011.1");
-    write_float::<_, BINARY>(1.2345678901234567890e3f64, &TRUNCATE2, "1.001101e1010");
-    write_float::<_, BINARY>(1.2345678901234567890e3f64, &ROUND2, "1.001101e1010");
+    write_float::<_, BINARY>(1.2345678901234567f64, &TRUNCATE2, "1.001111");
+    write_float::<_, BINARY>(1.2345678901234567f64, &ROUND2, "1.001111");
+    write_float::<_, BINARY>(1.2345678901234567e1f64, &TRUNCATE2, "1100.0101");
+    write_float::<_, BINARY>(1.2345678901234567e1f64, &ROUND2, "1100.011");
+    write_float::<_, BINARY>(1.2345678901234568e2f64, &TRUNCATE2, "1111011.0");
+    write_float::<_, BINARY>(1.2345678901234568e2f64, &ROUND2, "1111011.1");
+    write_float::<_, BINARY>(1.234567890123457e3f64, &TRUNCATE2, "1.001101e1010");
+    write_float::<_, BINARY>(1.234567890123457e3f64, &ROUND2, "1.001101e1010");
 
     const TRUNCATE3: Options = Options::builder()
         .max_significant_digits(num::NonZeroUsize::new(8))
         .round_mode(RoundMode::Truncate)
         .trim_floats(true)
         .build_strict();
-    write_float::<_, BINARY>(1.2345678901234567890e2f64, &TRUNCATE3, "1111011");
-    write_float::<_, BINARY>(1.2345678901234567890e2f64, &ROUND2, "1111011.1");
+    write_float::<_, BINARY>(1.2345678901234568e2f64, &TRUNCATE3, "1111011");
+    write_float::<_, BINARY>(1.2345678901234568e2f64, &ROUND2, "1111011.1");
 }
@@ -267,7 +267,7 @@ fn write_float_test() {
     write_float::<_, DECIMAL>(0.0f64, &OPTS1, "0.0");
     write_float::<_, DECIMAL>(1.0f64, &OPTS1, "1.0");
     write_float::<_, DECIMAL>(1.5f64, &OPTS1, "1.5");
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f64, &OPTS1, "1.2345678901234567");
+    write_float::<_, DECIMAL>(1.2345678901234567f64, &OPTS1, "1.2345678901234567");
     write_float::<_, DECIMAL>(1.0e-17f64, &OPTS1, "1.0e-17");
     write_float::<_, DECIMAL>(9.99999999999999e-16f64, &OPTS1, "9.99999999999999e-16");
     write_float::<_, DECIMAL>(9.99999999999999e-15f64, &OPTS1, "9.99999999999999e-15");
@@ -299,7 +299,7 @@ fn write_float_test() {
         "1.5000000000000000000000000000000000000000000000000",
     );
     write_float::<_, DECIMAL>(
-        1.2345678901234567890e0f64,
+        1.2345678901234567f64,
         &OPTS2,
         "1.2345678901234567000000000000000000000000000000000",
     );
@@ -369,7 +369,7 @@ fn write_float_test() {
     write_float::<_, DECIMAL>(0.0f64, &OPTS3, "0.0");
     write_float::<_, DECIMAL>(1.0f64, &OPTS3, "1.0");
     write_float::<_, DECIMAL>(1.5f64, &OPTS3, "1.5");
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f64, &OPTS3, "1.2346");
+    write_float::<_, DECIMAL>(1.2345678901234567f64, &OPTS3, "1.2346");
     write_float::<_, DECIMAL>(1.0e-17f64, &OPTS3, "1.0e-17");
     write_float::<_, DECIMAL>(9.99999999999999e-16f64, &OPTS3, "1.0e-15");
     write_float::<_, DECIMAL>(9.99999999999999e-15f64, &OPTS3, "1.0e-14");
@@ -387,7 +387,7 @@ fn write_float_test() {
     write_float::<_, DECIMAL>(0.0f64, &OPTS4, "0");
     write_float::<_, DECIMAL>(1.0f64, &OPTS4, "1");
     write_float::<_, DECIMAL>(1.5f64, &OPTS4, "1.5");
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f64, &OPTS4, "1.2345678901234567");
+    write_float::<_, DECIMAL>(1.2345678901234567f64, &OPTS4, "1.2345678901234567");
     write_float::<_, DECIMAL>(1.0e-17f64, &OPTS4, "1e-17");
     write_float::<_, DECIMAL>(9.99999999999999e-16f64, &OPTS4, "9.99999999999999e-16");
     write_float::<_, DECIMAL>(9.99999999999999e-15f64, &OPTS4, "9.99999999999999e-15");
@@ -428,14 +428,14 @@ fn write_float_test() {
         .round_mode(RoundMode::Round)
         .build_strict();
 
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f64, &TRUNCATE, "1.234");
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f64, &ROUND, "1.235");
-    write_float::<_, DECIMAL>(1.2345678901234567890e1f64, &TRUNCATE, "12.34");
-    write_float::<_, DECIMAL>(1.2345678901234567890e1f64, &ROUND, "12.35");
-    write_float::<_, DECIMAL>(1.2345678901234567890e2f64, &TRUNCATE, "123.4");
-    write_float::<_, DECIMAL>(1.2345678901234567890e2f64, &ROUND, "123.5");
-    write_float::<_, DECIMAL>(1.2345678901234567890e3f64, &TRUNCATE, "1234.0");
-    write_float::<_, DECIMAL>(1.2345678901234567890e3f64, &ROUND, "1235.0");
+    write_float::<_, DECIMAL>(1.2345678901234567f64, &TRUNCATE, "1.234");
+    write_float::<_, DECIMAL>(1.2345678901234567f64, &ROUND, "1.235");
+    write_float::<_, DECIMAL>(1.2345678901234567e1f64, &TRUNCATE, "12.34");
+    write_float::<_, DECIMAL>(1.2345678901234567e1f64, &ROUND, "12.35");
+    write_float::<_, DECIMAL>(1.2345678901234568e2f64, &TRUNCATE, "123.4");
+    write_float::<_, DECIMAL>(1.2345678901234568e2f64, &ROUND, "123.5");
+    write_float::<_, DECIMAL>(1.234567890123457e3f64, &TRUNCATE, "1234.0");
+    write_float::<_, DECIMAL>(1.234567890123457e3f64, &ROUND, "1235.0");
 
     // Check min and max digits
     const OPTS6: Options = Options::builder()
@@ -445,7 +445,7 @@ fn write_float_test() {
         .build_strict();
     write_float::<_, DECIMAL>(0.0f64, &OPTS6, "0.00");
     write_float::<_, DECIMAL>(1.5f64, &OPTS6, "1.50");
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f64, &OPTS6, "1.234");
+    write_float::<_, DECIMAL>(1.2345678901234567f64, &OPTS6, "1.234");
 }
 
 // Test data for roundtrips.
@@ -474,7 +474,7 @@ const F32_DATA: [f32; 31] = [
     123456789.123,
     123456789.1234,
     123456789.12345,
-    1.2345678912345e8,
+    1.2345679e8,
     1.2345e+8,
     1.2345e+11,
     1.2345e+38,
@@ -525,10 +525,10 @@ fn f32_test() {
     write_float::<_, DECIMAL>(1.0f32, &OPTIONS, "1");
     write_float::<_, DECIMAL>(10.0f32, &OPTIONS, "10");
     write_float::<_, DECIMAL>(10.0f32, &OPTIONS, "10");
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f32, &OPTIONS, "1.2345679");
-    write_float::<_, DECIMAL>(1.2345678901234567890e1f32, &OPTIONS, "12.345679");
-    write_float::<_, DECIMAL>(1.2345678901234567890e2f32, &OPTIONS, "123.45679");
-    write_float::<_, DECIMAL>(1.2345678901234567890e3f32, &OPTIONS, "1234.5679");
+    write_float::<_, DECIMAL>(1.2345679f32, &OPTIONS, "1.2345679");
+    write_float::<_, DECIMAL>(1.2345679e1f32, &OPTIONS, "12.345679");
+    write_float::<_, DECIMAL>(1.2345679e2f32, &OPTIONS, "123.45679");
+    write_float::<_, DECIMAL>(1.2345679e3f32, &OPTIONS, "1234.5679");
 }
 
 #[test]
@@ -547,7 +547,7 @@ fn write_float_scientific(digits: &mut [u8], k: i32, options: &Options, expected
     let mut buffer = [b'\x00'; BUFFER_SIZE];
     let ndigits = digits.len();
     let count =
-        compact::write_float_scientific::<DECIMAL>(&mut buffer, digits, ndigits, k, &options);
+        compact::write_float_scientific::<DECIMAL>(&mut buffer, digits, ndigits, k, options);
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -580,13 +580,8 @@ fn write_float_scientific_test() {
 fn write_float_positive_exponent(digits: &mut [u8], k: i32, options: &Options, expected: &str) {
     let mut buffer = [b'\x00'; 512];
     let ndigits = digits.len();
-    let count = compact::write_float_positive_exponent::<DECIMAL>(
-        &mut buffer,
-        digits,
-        ndigits,
-        k,
-        &options,
-    );
+    let count =
+        compact::write_float_positive_exponent::<DECIMAL>(&mut buffer, digits, ndigits, k, options);
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -611,13 +606,8 @@ fn write_float_positive_exponent_test() {
 fn write_float_negative_exponent(digits: &mut [u8], k: i32, options: &Options, expected: &str) {
     let mut buffer = [b'\x00'; 512];
     let ndigits = digits.len();
-    let count = compact::write_float_negative_exponent::<DECIMAL>(
-        &mut buffer,
-        digits,
-        ndigits,
-        k,
-        &options,
-    );
+    let count =
+        compact::write_float_negative_exponent::<DECIMAL>(&mut buffer, digits, ndigits, k, options);
     let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
     assert_eq!(actual, expected);
 }
@@ -646,10 +636,10 @@ fn f64_test() {
     write_float::<_, DECIMAL>(1.0f64, &OPTIONS, "1");
     write_float::<_, DECIMAL>(10.0f64, &OPTIONS, "10");
     write_float::<_, DECIMAL>(10.0f64, &OPTIONS, "10");
-    write_float::<_, DECIMAL>(1.2345678901234567890e0f64, &OPTIONS, "1.2345678901234567");
-    write_float::<_, DECIMAL>(1.2345678901234567890e1f64, &OPTIONS, "12.345678901234567");
-    write_float::<_, DECIMAL>(1.2345678901234567890e2f64, &OPTIONS, "123.45678901234568");
-    write_float::<_, DECIMAL>(1.2345678901234567890e3f64, &OPTIONS, "1234.567890123457");
+    write_float::<_, DECIMAL>(1.2345678901234567f64, &OPTIONS, "1.2345678901234567");
+    write_float::<_, DECIMAL>(1.2345678901234567e1f64, &OPTIONS, "12.345678901234567");
+    write_float::<_, DECIMAL>(1.2345678901234568e2f64, &OPTIONS, "123.45678901234568");
+    write_float::<_, DECIMAL>(1.234567890123457e3f64, &OPTIONS, "1234.567890123457");
 }
 
 #[test]
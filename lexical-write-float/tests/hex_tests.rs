@@ -67,7 +67,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BASE4_2_10>(2.0f64, &OPTS1, "2.0e0");
     write_float_scientific::<_, BASE4_2_10>(0.5f64, &OPTS1, "2.0e-2");
     write_float_scientific::<_, BASE4_2_10>(
-        0.2345678901234567890e20f64,
+        2.345678901234568e19f64,
         &OPTS1,
         "1.10112013100111033030021213e64",
     );
@@ -93,7 +93,7 @@ fn write_float_scientific_test() {
     );
 
     write_float_scientific::<_, BASE4_2_10>(
-        0.2345678901234567890e-20f64,
+        2.345678901234568e-21f64,
         &OPTS1,
         "2.30103300013110301132322302e-70",
     );
@@ -124,7 +124,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BASE8_2_10>(2.0f64, &OPTS1, "2.0e0");
     write_float_scientific::<_, BASE8_2_10>(0.5f64, &OPTS1, "4.0e-3");
     write_float_scientific::<_, BASE8_2_10>(
-        0.2345678901234567890e20f64,
+        2.345678901234568e19f64,
         &OPTS1,
         "2.42607202517141147e63",
     );
@@ -150,7 +150,7 @@ fn write_float_scientific_test() {
     );
 
     write_float_scientific::<_, BASE8_2_10>(
-        0.2345678901234567890e-20f64,
+        2.345678901234568e-21f64,
         &OPTS1,
         "1.304740165142756544e-69",
     );
@@ -181,7 +181,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BASE16_2_10>(2.0f64, &HEX_OPTIONS, "2.0^0");
     write_float_scientific::<_, BASE16_2_10>(0.5f64, &HEX_OPTIONS, "8.0^-4");
     write_float_scientific::<_, BASE16_2_10>(
-        0.2345678901234567890e20f64,
+        2.345678901234568e19f64,
         &HEX_OPTIONS,
         "1.45874153CC267^64",
     );
@@ -207,7 +207,7 @@ fn write_float_scientific_test() {
     );
 
     write_float_scientific::<_, BASE16_2_10>(
-        0.2345678901234567890e-20f64,
+        2.345678901234568e-21f64,
         &HEX_OPTIONS,
         "B.13C075317BAC8^-72",
     );
@@ -238,7 +238,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BASE32_2_10>(2.0f64, &HEX_OPTIONS, "2.0^0");
     write_float_scientific::<_, BASE32_2_10>(0.5f64, &HEX_OPTIONS, "G.0^-5");
     write_float_scientific::<_, BASE32_2_10>(
-        0.2345678901234567890e20f64,
+        2.345678901234568e19f64,
         &HEX_OPTIONS,
         "K.B1Q1AF62CS^60",
     );
@@ -264,7 +264,7 @@ fn write_float_scientific_test() {
     );
 
     write_float_scientific::<_, BASE32_2_10>(
-        0.2345678901234567890e-20f64,
+        2.345678901234568e-21f64,
         &HEX_OPTIONS,
         "2.OJO1QJ2UTCG^-70",
     );
@@ -295,7 +295,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BASE16_4_10>(2.0f64, &HEX_OPTIONS, "2.0^0");
     write_float_scientific::<_, BASE16_4_10>(0.5f64, &HEX_OPTIONS, "8.0^-2");
     write_float_scientific::<_, BASE16_4_10>(
-        0.2345678901234567890e20f64,
+        2.345678901234568e19f64,
         &HEX_OPTIONS,
         "1.45874153CC267^32",
     );
@@ -321,7 +321,7 @@ fn write_float_scientific_test() {
     );
 
     write_float_scientific::<_, BASE16_4_10>(
-        0.2345678901234567890e-20f64,
+        2.345678901234568e-21f64,
         &HEX_OPTIONS,
         "B.13C075317BAC8^-36",
     );
@@ -353,11 +353,7 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BASE16_4_10>(1.0f64, &OPTS2, "1.0000e0");
     write_float_scientific::<_, BASE16_4_10>(2.0f64, &OPTS2, "2.0000e0");
     write_float_scientific::<_, BASE16_4_10>(0.5f64, &OPTS2, "8.0000e-2");
-    write_float_scientific::<_, BASE16_4_10>(
-        0.2345678901234567890e2f64,
-        &OPTS2,
-        "1.774F01FED3264e2",
-    );
+    write_float_scientific::<_, BASE16_4_10>(2.345678901234568e1f64, &OPTS2, "1.774F01FED3264e2");
 
     const OPTS3: Options = Options::builder()
         .min_significant_digits(num::NonZeroUsize::new(5))
@@ -367,17 +363,13 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BASE16_4_10>(1.0f64, &OPTS3, "1e0");
     write_float_scientific::<_, BASE16_4_10>(2.0f64, &OPTS3, "2e0");
     write_float_scientific::<_, BASE16_4_10>(0.5f64, &OPTS3, "8e-2");
-    write_float_scientific::<_, BASE16_4_10>(
-        0.2345678901234567890e2f64,
-        &OPTS3,
-        "1.774F01FED3264e2",
-    );
+    write_float_scientific::<_, BASE16_4_10>(2.345678901234568e1f64, &OPTS3, "1.774F01FED3264e2");
 
     // Check trimming floats
     const OPTS4: Options = Options::builder().trim_floats(true).build_strict();
     write_float_scientific::<_, BASE16_4_10>(1f32, &OPTS4, "1e0");
     write_float_scientific::<_, BASE16_4_10>(1.4e-45f32, &OPTS4, "8e-76");
-    write_float_scientific::<_, BASE16_4_10>(1.2345678901234567890f32, &OPTS4, "1.3C0CA4e0");
+    write_float_scientific::<_, BASE16_4_10>(1.2345679f32, &OPTS4, "1.3C0CA4e0");
 }
 
 // NOTE: This doesn't handle float rounding or truncation.
@@ -396,11 +388,7 @@ where
 fn write_float_test() {
     const OPTIONS: Options = Options::builder().build_strict();
 
-    write_float::<_, BASE4_2_10>(
-        0.2345678901234567890f64,
-        &OPTIONS,
-        "0.033000302210022030112133232",
-    );
+    write_float::<_, BASE4_2_10>(0.2345678901234568f64, &OPTIONS, "0.033000302210022030112133232");
     write_float::<_, BASE4_2_10>(0.1172839450617284f64, &OPTIONS, "0.013200121102011012023033313");
     write_float::<_, BASE4_2_10>(0.0586419725308642f64, &OPTIONS, "0.0033000302210022030112133232");
     write_float::<_, BASE4_2_10>(0.0293209862654321f64, &OPTIONS, "1.3200121102011012023033313e-6");
@@ -55,6 +55,14 @@ fn invalid_inf_test() {
     builder = builder.inf_string(Some(b"in00f"));
     assert!(!builder.is_valid());
     assert!(builder.build().is_err());
+    builder = builder.inf_string(Some(b"+nf"));
+    assert!(!builder.is_valid());
+    builder = builder.inf_string(Some(b"+"));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.inf_string(Some(b"+Inf"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
     builder = builder.inf_string(Some(b"inf"));
     assert!(builder.is_valid());
     assert!(builder.build().is_ok());
@@ -76,17 +84,31 @@ fn builder_test() {
     builder = builder.decimal_point(b',');
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
+    builder = builder.decimal_places(Some(2));
+    builder = builder.exact_digits(true);
+    builder = builder.notation(options::Notation::Engineering);
+    builder = builder.exponent_plus_sign(true);
+    builder = builder.min_exponent_digits(num::NonZeroUsize::new(2));
+    builder = builder.trim_scientific(true);
+    builder = builder.signed_zero(false);
 
     assert_eq!(builder.get_max_significant_digits().unwrap().get(), 10);
     assert_eq!(builder.get_min_significant_digits().unwrap().get(), 5);
     assert_eq!(builder.get_positive_exponent_break().unwrap().get(), 9);
     assert_eq!(builder.get_negative_exponent_break().unwrap().get(), -9);
     assert_eq!(builder.get_round_mode(), options::RoundMode::Truncate);
-    assert_eq!(builder.get_trim_floats(), true);
+    assert!(builder.get_trim_floats());
     assert_eq!(builder.get_exponent(), b'^');
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
+    assert_eq!(builder.get_decimal_places(), Some(2));
+    assert!(builder.get_exact_digits());
+    assert_eq!(builder.get_notation(), options::Notation::Engineering);
+    assert!(builder.get_exponent_plus_sign());
+    assert_eq!(builder.get_min_exponent_digits().unwrap().get(), 2);
+    assert!(builder.get_trim_scientific());
+    assert!(!builder.get_signed_zero());
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(builder.build_unchecked()));
@@ -113,7 +135,7 @@ fn options_test() {
     assert_eq!(opts.positive_exponent_break().unwrap().get(), 9);
     assert_eq!(opts.negative_exponent_break().unwrap().get(), -9);
     assert_eq!(opts.round_mode(), options::RoundMode::Truncate);
-    assert_eq!(opts.trim_floats(), true);
+    assert!(opts.trim_floats());
     assert_eq!(opts.exponent(), b'^');
     assert_eq!(opts.decimal_point(), b',');
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
@@ -2,12 +2,152 @@
 
 #![doc(hidden)]
 
+use lexical_util::constants::FormattedSize;
+use lexical_util::error::Error;
 use lexical_util::format::{NumberFormat, STANDARD};
 use lexical_util::num::SignedInteger;
+use lexical_util::result::Result;
 use lexical_util::{to_lexical, to_lexical_with_options};
 
-use crate::options::Options;
-use crate::write::WriteInteger;
+use crate::grouping::write_integer_separators;
+use crate::options::{Options, Padding};
+use crate::write::{write_base_prefix, write_base_suffix, WriteInteger};
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Size of the scratch buffer for checked writes.
+///
+/// This fits the digits for any integer in any radix, with a digit
+/// separator between every digit, the sign, and the base prefix and
+/// suffix, so only the padding can exceed it.
+const SCRATCH_SIZE: usize = 2 * i128::FORMATTED_SIZE + 4;
+
+/// Pad the written number to the minimum width, returning the new length.
+///
+/// `start` is the index of the first digit, after the sign and base prefix.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_padding<const FORMAT: u128>(
+    buffer: &mut [u8],
+    count: usize,
+    start: usize,
+    options: &Options,
+) -> usize {
+    let width = options.min_width();
+    if count >= width {
+        return count;
+    }
+
+    let padding = width - count;
+    let (start, fill) = match options.padding() {
+        Padding::Zero => (start, b'0'),
+        Padding::Space => (0, b' '),
+    };
+    buffer.copy_within(start..count, start + padding);
+    buffer[start..start + padding].fill(fill);
+    width
+}
+
+/// Write the sign, base prefix, the digits from `write`, and the base suffix.
+///
+/// The digits are then formatted using the options, such as the digit
+/// case, digit separators, and padding.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_number<Cb, const FORMAT: u128>(
+    buffer: &mut [u8],
+    sign: Option<u8>,
+    options: &Options,
+    write: Cb,
+) -> usize
+where
+    Cb: FnOnce(&mut [u8]) -> usize,
+{
+    let format = NumberFormat::<FORMAT> {};
+    let mut start = 0;
+    if let Some(sign) = sign {
+        buffer[0] = sign;
+        start += 1;
+    }
+    start += write_base_prefix::<FORMAT>(&mut buffer[start..]);
+
+    let digits = &mut buffer[start..];
+    let mut count = write(digits);
+    if options.lowercase_digits() {
+        digits[..count].make_ascii_lowercase();
+    }
+    if format.has_digit_separator() && format.integer_internal_digit_separator() {
+        let separator = format.digit_separator();
+        count = write_integer_separators(digits, count, separator, options.digit_grouping());
+    }
+    count += write_base_suffix::<FORMAT>(&mut digits[count..]);
+
+    write_padding::<FORMAT>(buffer, start + count, start, options)
+}
+
+/// Check if the padding can be written by the number format.
+///
+/// Zero padding writes leading zeros, so the number could not be parsed
+/// with a format that does not allow integer leading zeros.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+const fn is_valid_padding<const FORMAT: u128>(options: &Options) -> bool {
+    let format = NumberFormat::<FORMAT> {};
+    let is_zero = matches!(options.padding(), Padding::Zero);
+    !(cfg!(feature = "format") && is_zero && format.no_integer_leading_zeros())
+}
+
+/// Get the sign to write for a positive number, if any.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn positive_sign<const FORMAT: u128>(options: &Options) -> Option<u8> {
+    let format = NumberFormat::<FORMAT> {};
+    if cfg!(feature = "format") && format.no_positive_mantissa_sign() {
+        None
+    } else if options.plus_sign() || (cfg!(feature = "format") && format.required_mantissa_sign()) {
+        Some(b'+')
+    } else {
+        None
+    }
+}
+
+/// Write the number using `write`, returning an error rather than panicking.
+///
+/// If the buffer may be too small, the number is first written to a
+/// scratch buffer, so the exact number of bytes required can be reported.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn try_write<T, Cb, const FORMAT: u128>(
+    buffer: &mut [u8],
+    options: &Options,
+    write: Cb,
+) -> Result<usize>
+where
+    T: FormattedSize,
+    Cb: FnOnce(&mut [u8]) -> usize,
+{
+    let error = NumberFormat::<FORMAT> {}.error();
+    if !error.is_success() {
+        return Err(error);
+    }
+    if !is_valid_padding::<FORMAT>(options) {
+        return Err(Error::UnsupportedPadding);
+    }
+
+    let size = options.buffer_size_const::<T, FORMAT>();
+    if buffer.len() >= size {
+        Ok(write(buffer))
+    } else if size <= SCRATCH_SIZE {
+        let mut scratch = [0u8; SCRATCH_SIZE];
+        let count = write(&mut scratch);
+        match buffer.get_mut(..count) {
+            Some(digits) => {
+                digits.copy_from_slice(&scratch[..count]);
+                Ok(count)
+            },
+            None => Err(Error::BufferTooSmall(count)),
+        }
+    } else {
+        // Only the padding can be larger than the scratch buffer, so
+        // the number is written with exactly the minimum width.
+        Err(Error::BufferTooSmall(size))
+    }
+}
 
 // UNSIGNED
 
@@ -15,21 +155,14 @@ use crate::write::WriteInteger;
 ///
 /// # Safety
 ///
-/// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
-/// (or `FORMATTED_SIZE_DECIMAL` for decimal).
+/// Safe as long as the buffer can hold `buffer_size_const` elements.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-fn unsigned<T, const FORMAT: u128>(value: T, buffer: &mut [u8]) -> usize
+fn unsigned<T, const FORMAT: u128>(value: T, buffer: &mut [u8], options: &Options) -> usize
 where
     T: WriteInteger,
 {
-    let format = NumberFormat::<FORMAT> {};
-    if cfg!(feature = "format") && format.required_mantissa_sign() {
-        buffer[0] = b'+';
-        let buffer = &mut buffer[1..];
-        value.write_mantissa::<FORMAT>(buffer) + 1
-    } else {
-        value.write_mantissa::<FORMAT>(buffer)
-    }
+    let sign = positive_sign::<FORMAT>(options);
+    write_number::<_, FORMAT>(buffer, sign, options, |x| value.write_mantissa::<FORMAT>(x))
 }
 
 // SIGNED
@@ -38,33 +171,29 @@ where
 ///
 /// # Safety
 ///
-/// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
-/// (or `FORMATTED_SIZE_DECIMAL` for decimal).
+/// Safe as long as the buffer can hold `buffer_size_const` elements.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-fn signed<Signed, Unsigned, const FORMAT: u128>(value: Signed, buffer: &mut [u8]) -> usize
+fn signed<Signed, Unsigned, const FORMAT: u128>(
+    value: Signed,
+    buffer: &mut [u8],
+    options: &Options,
+) -> usize
 where
     Signed: SignedInteger,
     Unsigned: WriteInteger,
 {
-    let format = NumberFormat::<FORMAT> {};
-    if value < Signed::ZERO {
+    let (unsigned, sign) = if value < Signed::ZERO {
         // Need to cast the value to the same size as unsigned type, since if
         // the value is **exactly** `Narrow::MIN`, and it it is then cast
         // as the wrapping negative as the unsigned value, a wider type
         // will have a very different value.
-        let unsigned = Unsigned::as_cast(value.wrapping_neg());
-        buffer[0] = b'-';
-        let buffer = &mut buffer[1..];
-        unsigned.write_mantissa_signed::<FORMAT>(buffer) + 1
-    } else if cfg!(feature = "format") && format.required_mantissa_sign() {
-        let unsigned = Unsigned::as_cast(value);
-        buffer[0] = b'+';
-        let buffer = &mut buffer[1..];
-        unsigned.write_mantissa_signed::<FORMAT>(buffer) + 1
+        (Unsigned::as_cast(value.wrapping_neg()), Some(b'-'))
     } else {
-        let unsigned = Unsigned::as_cast(value);
-        unsigned.write_mantissa_signed::<FORMAT>(buffer)
-    }
+        (Unsigned::as_cast(value), positive_sign::<FORMAT>(options))
+    };
+    write_number::<_, FORMAT>(buffer, sign, options, |x| {
+        unsigned.write_mantissa_signed::<FORMAT>(x)
+    })
 }
 
 // API
@@ -77,9 +206,19 @@ macro_rules! unsigned_to_lexical {
             fn to_lexical(self, bytes: &mut [u8])
                 -> &mut [u8]
             {
-                let len = unsigned::<$t, { STANDARD }>(self, bytes);
+                let len = unsigned::<$t, { STANDARD }>(self, bytes, &DEFAULT_OPTIONS);
                 &mut bytes[..len]
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn try_to_lexical(self, bytes: &mut [u8])
+                -> Result<&mut [u8]>
+            {
+                let len = try_write::<$t, _, { STANDARD }>(bytes, &DEFAULT_OPTIONS, |x| {
+                    unsigned::<$t, { STANDARD }>(self, x, &DEFAULT_OPTIONS)
+                })?;
+                Ok(&mut bytes[..len])
+            }
         }
 
         impl ToLexicalWithOptions for $t {
@@ -92,11 +231,24 @@ macro_rules! unsigned_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                _ = options;
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                let len = unsigned::<$t, FORMAT>(self, bytes);
+                assert!(is_valid_padding::<FORMAT>(options));
+                let len = unsigned::<$t, FORMAT>(self, bytes, options);
                 &mut bytes[..len]
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> Result<&'a mut [u8]>
+            {
+                let len = try_write::<$t, _, FORMAT>(bytes, options, |x| {
+                    unsigned::<$t, FORMAT>(self, x, options)
+                })?;
+                Ok(&mut bytes[..len])
+            }
         }
     )*)
 }
@@ -113,9 +265,19 @@ macro_rules! signed_to_lexical {
             fn to_lexical(self, bytes: &mut [u8])
                 -> &mut [u8]
             {
-                let len = signed::<$signed, $unsigned, { STANDARD }>(self, bytes);
+                let len = signed::<$signed, $unsigned, { STANDARD }>(self, bytes, &DEFAULT_OPTIONS);
                 &mut bytes[..len]
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn try_to_lexical(self, bytes: &mut [u8])
+                -> Result<&mut [u8]>
+            {
+                let len = try_write::<$signed, _, { STANDARD }>(bytes, &DEFAULT_OPTIONS, |x| {
+                    signed::<$signed, $unsigned, { STANDARD }>(self, x, &DEFAULT_OPTIONS)
+                })?;
+                Ok(&mut bytes[..len])
+            }
         }
 
         impl ToLexicalWithOptions for $signed {
@@ -127,11 +289,24 @@ macro_rules! signed_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                _ = options;
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                let len = signed::<$signed, $unsigned, FORMAT>(self, bytes);
+                assert!(is_valid_padding::<FORMAT>(options));
+                let len = signed::<$signed, $unsigned, FORMAT>(self, bytes, options);
                 &mut bytes[..len]
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> Result<&'a mut [u8]>
+            {
+                let len = try_write::<$signed, _, FORMAT>(bytes, options, |x| {
+                    signed::<$signed, $unsigned, FORMAT>(self, x, options)
+                })?;
+                Ok(&mut bytes[..len])
+            }
         }
     )*)
 }
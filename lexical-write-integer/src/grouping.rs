@@ -0,0 +1,86 @@
+//! Insert digit separators into previously written digits.
+//!
+//! The digits are first written without separators, and then expanded
+//! in-place from the back of the buffer, so the optimized writers never
+//! need to know about digit separators.
+
+#![doc(hidden)]
+
+use lexical_util::options::DigitGrouping;
+
+/// Insert digit separators into integer digits, grouped from the right.
+///
+/// `buffer[..count]` must contain the digits, and `buffer` must have room
+/// for [`integer_separators`] extra bytes. Returns the number of bytes
+/// written, including the separators.
+///
+/// [`integer_separators`]: DigitGrouping::integer_separators
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn write_integer_separators(
+    buffer: &mut [u8],
+    count: usize,
+    separator: u8,
+    grouping: DigitGrouping,
+) -> usize {
+    let separators = grouping.integer_separators(count);
+    if separators == 0 {
+        return count;
+    }
+
+    // The destination is always at or after the source, so copying
+    // from the back never overwrites digits we have yet to read.
+    let length = count + separators;
+    let mut src = count;
+    let mut dst = length;
+    let mut group = grouping.primary() as usize;
+    let mut index = 0;
+    while src != 0 {
+        if index == group {
+            dst -= 1;
+            buffer[dst] = separator;
+            group = grouping.secondary() as usize;
+            index = 0;
+        }
+        src -= 1;
+        dst -= 1;
+        buffer[dst] = buffer[src];
+        index += 1;
+    }
+    debug_assert!(dst == 0, "all separators must be written");
+
+    length
+}
+
+/// Insert digit separators into fraction digits, grouped from the left.
+///
+/// `buffer[..count]` must contain the digits, and `buffer` must have room
+/// for [`fraction_separators`] extra bytes. Returns the number of bytes
+/// written, including the separators.
+///
+/// [`fraction_separators`]: DigitGrouping::fraction_separators
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn write_fraction_separators(
+    buffer: &mut [u8],
+    count: usize,
+    separator: u8,
+    grouping: DigitGrouping,
+) -> usize {
+    let separators = grouping.fraction_separators(count);
+    if separators == 0 {
+        return count;
+    }
+
+    // Digit `i` moves to `i + i / size`, which is never before `i`.
+    let size = grouping.primary() as usize;
+    let mut index = count;
+    while index != 0 {
+        index -= 1;
+        let dst = index + index / size;
+        buffer[dst] = buffer[index];
+        if index != 0 && index % size == 0 {
+            buffer[dst - 1] = separator;
+        }
+    }
+
+    count + separators
+}
@@ -28,8 +28,7 @@
 //!
 //! # Features
 //!
-//! * `format` - Add support for custom integer formatting (currently
-//!   unsupported).
+//! * `format` - Add support for custom integer formatting.
 //! * `power-of-two` - Add support for writing power-of-two integer strings.
 //! * `radix` - Add support for strings of any radix.
 //! * `compact` - Reduce code size at the cost of performance.
@@ -41,10 +40,30 @@
 //!
 //! #### format
 //!
-//! Add support for custom integer formatting. Currently no custom styles are
-//! supported but this could include digit [`separator`] support in the future.
+//! Add support for custom integer formatting, such as a required sign or
+//! digit [`separator`] support. Digit separators are written between groups
+//! of digits, sized by [`Options::digit_grouping`], if
+//! [`integer_internal_digit_separator`] is enabled.
+//!
+//! ```rust
+//! # #[cfg(feature = "format")] {
+//! # use core::{num, str};
+//! use lexical_write_integer::{NumberFormatBuilder, Options, ToLexicalWithOptions};
+//!
+//! const FORMAT: u128 = NumberFormatBuilder::new()
+//!     .digit_separator(num::NonZeroU8::new(b'_'))
+//!     .integer_internal_digit_separator(true)
+//!     .build_strict();
+//! const OPTIONS: Options = Options::new();
+//! const SIZE: usize = OPTIONS.buffer_size_const::<u64, FORMAT>();
+//! let mut buffer = [0u8; SIZE];
+//! let digits = 1000000u64.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS);
+//! assert_eq!(str::from_utf8(digits), Ok("1_000_000"));
+//! # }
+//! ```
 //!
 //! [`separator`]: NumberFormatBuilder::digit_separator
+//! [`integer_internal_digit_separator`]: NumberFormatBuilder::integer_internal_digit_separator
 //!
 //! <!--
 //! For a list of all supported fields, see [Write Integer
@@ -328,8 +347,7 @@
     clippy::unnecessary_safety_comment,
     clippy::semicolon_if_nothing_returned,
     clippy::unwrap_used,
-    clippy::as_underscore,
-    clippy::doc_markdown
+    clippy::as_underscore
 )]
 #![allow(
     // used when concepts are logically separate
@@ -352,6 +370,7 @@ pub mod algorithm;
 pub mod compact;
 pub mod decimal;
 pub mod digit_count;
+pub mod grouping;
 pub mod jeaiii;
 pub mod options;
 pub mod radix;
@@ -367,9 +386,9 @@ mod table_radix;
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 pub use lexical_util::error::Error;
 pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
-pub use lexical_util::options::WriteOptions;
+pub use lexical_util::options::{DigitGrouping, WriteOptions};
 pub use lexical_util::result::Result;
 
 pub use self::api::{ToLexical, ToLexicalWithOptions};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{Options, OptionsBuilder, Padding};
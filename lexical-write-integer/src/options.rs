@@ -1,7 +1,8 @@
 //! Configuration options for writing integers.
 //!
-//! This currently has no functionality, since we do not
-//! support any features for writing integers at this time.
+//! This controls how digits are grouped when the number format has
+//! a digit separator, the case of digits in radixes above 10, and the
+//! width, padding, and sign of the written number.
 //!
 //! # Examples
 //!
@@ -22,9 +23,25 @@
 
 use lexical_util::constants::FormattedSize;
 use lexical_util::format::NumberFormat;
-use lexical_util::options::WriteOptions;
+use lexical_util::options::{DigitGrouping, WriteOptions};
 use lexical_util::result::Result;
 
+/// Enumeration for how to pad numbers shorter than the minimum width.
+///
+/// For example, using a minimum width of `6`, `-123` would be written
+/// as `  -123` using [`Space`][Padding::Space] padding, and `-00123`
+/// using [`Zero`][Padding::Zero] padding.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Padding {
+    /// Pad with spaces before the sign.
+    Space,
+
+    /// Pad with zeros after the sign and base prefix.
+    ///
+    /// The padding is not grouped by digit separators.
+    Zero,
+}
+
 /// Builder for [`Options`].
 ///
 /// # Examples
@@ -45,13 +62,240 @@ use lexical_util::result::Result;
 /// assert_eq!(str::from_utf8(digits), Ok("1234"));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct OptionsBuilder {}
+pub struct OptionsBuilder {
+    /// Sizes of the digit groups between digit separators.
+    digit_grouping: DigitGrouping,
+
+    /// Write digits above 9 as lowercase letters.
+    lowercase_digits: bool,
+
+    /// Minimum number of bytes to write, padding shorter numbers.
+    min_width: usize,
+
+    /// Characters to pad numbers shorter than the minimum width.
+    padding: Padding,
+
+    /// Write a `+` sign before positive numbers.
+    plus_sign: bool,
+}
 
 impl OptionsBuilder {
     /// Create new options builder with default options.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            digit_grouping: DigitGrouping::THOUSANDS,
+            lowercase_digits: false,
+            min_width: 0,
+            padding: Padding::Space,
+            plus_sign: false,
+        }
+    }
+
+    // GETTERS
+
+    /// Get the sizes of the digit groups between digit separators.
+    ///
+    /// This is only used if the format has a [`digit_separator`] and
+    /// [`integer_internal_digit_separator`] is enabled. Defaults to
+    /// [`DigitGrouping::THOUSANDS`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::{DigitGrouping, Options};
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_digit_grouping(), DigitGrouping::THOUSANDS);
+    /// ```
+    ///
+    /// [`digit_separator`]: lexical_util::format::NumberFormatBuilder::digit_separator
+    /// [`integer_internal_digit_separator`]: lexical_util::format::NumberFormatBuilder::integer_internal_digit_separator
+    #[inline(always)]
+    pub const fn get_digit_grouping(&self) -> DigitGrouping {
+        self.digit_grouping
+    }
+
+    /// Get if digits above 9 are written as lowercase letters.
+    ///
+    /// For example, `255` in hexadecimal would be `ff` rather than `FF`.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_lowercase_digits(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_lowercase_digits(&self) -> bool {
+        self.lowercase_digits
+    }
+
+    /// Get the minimum number of bytes to write.
+    ///
+    /// Numbers shorter than this are padded using [`get_padding`]. The
+    /// width includes the sign, base prefix and suffix, and any digit
+    /// separators. Defaults to `0`, which never pads.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_min_width(), 0);
+    /// ```
+    ///
+    /// [`get_padding`]: Self::get_padding
+    #[inline(always)]
+    pub const fn get_min_width(&self) -> usize {
+        self.min_width
+    }
+
+    /// Get the characters to pad numbers shorter than the minimum width.
+    ///
+    /// Defaults to [`Padding::Space`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::{Options, Padding};
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_padding(), Padding::Space);
+    /// ```
+    #[inline(always)]
+    pub const fn get_padding(&self) -> Padding {
+        self.padding
+    }
+
+    /// Get if a `+` sign is written before positive numbers.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_plus_sign(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_plus_sign(&self) -> bool {
+        self.plus_sign
+    }
+
+    // SETTERS
+
+    /// Set the sizes of the digit groups between digit separators.
+    ///
+    /// This is only used if the format has a [`digit_separator`] and
+    /// [`integer_internal_digit_separator`] is enabled. Defaults to
+    /// [`DigitGrouping::THOUSANDS`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::{DigitGrouping, Options};
+    ///
+    /// let builder = Options::builder()
+    ///     .digit_grouping(DigitGrouping::INDIAN);
+    /// assert_eq!(builder.get_digit_grouping(), DigitGrouping::INDIAN);
+    /// ```
+    ///
+    /// [`digit_separator`]: lexical_util::format::NumberFormatBuilder::digit_separator
+    /// [`integer_internal_digit_separator`]: lexical_util::format::NumberFormatBuilder::integer_internal_digit_separator
+    #[inline(always)]
+    pub const fn digit_grouping(mut self, digit_grouping: DigitGrouping) -> Self {
+        self.digit_grouping = digit_grouping;
+        self
+    }
+
+    /// Set if digits above 9 are written as lowercase letters.
+    ///
+    /// For example, `255` in hexadecimal would be `ff` rather than `FF`.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .lowercase_digits(true);
+    /// assert_eq!(builder.get_lowercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn lowercase_digits(mut self, lowercase_digits: bool) -> Self {
+        self.lowercase_digits = lowercase_digits;
+        self
+    }
+
+    /// Set the minimum number of bytes to write.
+    ///
+    /// Numbers shorter than this are padded using [`padding`]. The
+    /// width includes the sign, base prefix and suffix, and any digit
+    /// separators. Defaults to `0`, which never pads.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .min_width(8);
+    /// assert_eq!(builder.get_min_width(), 8);
+    /// ```
+    ///
+    /// [`padding`]: Self::padding
+    #[inline(always)]
+    pub const fn min_width(mut self, min_width: usize) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Set the characters to pad numbers shorter than the minimum width.
+    ///
+    /// Zero padding is an error if the number format does not allow
+    /// integer leading zeros. Defaults to [`Padding::Space`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::{Options, Padding};
+    ///
+    /// let builder = Options::builder()
+    ///     .padding(Padding::Zero);
+    /// assert_eq!(builder.get_padding(), Padding::Zero);
+    /// ```
+    #[inline(always)]
+    pub const fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set if a `+` sign is written before positive numbers.
+    ///
+    /// This is ignored if the number format does not allow a positive
+    /// sign. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .plus_sign(true);
+    /// assert_eq!(builder.get_plus_sign(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn plus_sign(mut self, plus_sign: bool) -> Self {
+        self.plus_sign = plus_sign;
+        self
     }
 
     // BUILDERS
@@ -75,7 +319,13 @@ impl OptionsBuilder {
     /// [`is_valid`]: Self::is_valid
     #[inline(always)]
     pub const fn build_unchecked(&self) -> Options {
-        Options {}
+        Options {
+            digit_grouping: self.digit_grouping,
+            lowercase_digits: self.lowercase_digits,
+            min_width: self.min_width,
+            padding: self.padding,
+            plus_sign: self.plus_sign,
+        }
     }
 
     /// Build the [`Options`] struct. This can never panic.
@@ -123,13 +373,28 @@ impl Default for OptionsBuilder {
 // FIXME: Add phantom data for private fields.
 //  This is a BREAKING change so requires a major API release.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Options {}
+pub struct Options {
+    /// Sizes of the digit groups between digit separators.
+    digit_grouping: DigitGrouping,
+
+    /// Write digits above 9 as lowercase letters.
+    lowercase_digits: bool,
+
+    /// Minimum number of bytes to write, padding shorter numbers.
+    min_width: usize,
+
+    /// Characters to pad numbers shorter than the minimum width.
+    padding: Padding,
+
+    /// Write a `+` sign before positive numbers.
+    plus_sign: bool,
+}
 
 impl Options {
     /// Create options with default values.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self::builder().build_unchecked()
     }
 
     /// Create the default options for a given radix.
@@ -145,18 +410,153 @@ impl Options {
         true
     }
 
+    /// Get the sizes of the digit groups between digit separators.
+    ///
+    /// This is only used if the format has a [`digit_separator`] and
+    /// [`integer_internal_digit_separator`] is enabled. Defaults to
+    /// [`DigitGrouping::THOUSANDS`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::{DigitGrouping, Options};
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .digit_grouping(DigitGrouping::INDIAN)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.digit_grouping(), DigitGrouping::INDIAN);
+    /// ```
+    ///
+    /// [`digit_separator`]: lexical_util::format::NumberFormatBuilder::digit_separator
+    /// [`integer_internal_digit_separator`]: lexical_util::format::NumberFormatBuilder::integer_internal_digit_separator
+    #[inline(always)]
+    pub const fn digit_grouping(&self) -> DigitGrouping {
+        self.digit_grouping
+    }
+
+    /// Get if digits above 9 are written as lowercase letters.
+    ///
+    /// For example, `255` in hexadecimal would be `ff` rather than `FF`.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .lowercase_digits(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.lowercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn lowercase_digits(&self) -> bool {
+        self.lowercase_digits
+    }
+
+    /// Get the minimum number of bytes to write.
+    ///
+    /// Numbers shorter than this are padded using [`padding`]. The
+    /// width includes the sign, base prefix and suffix, and any digit
+    /// separators. Defaults to `0`, which never pads.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_integer::{Options, Padding, ToLexicalWithOptions};
+    /// use lexical_write_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .min_width(6)
+    ///     .padding(Padding::Zero)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.min_width(), 6);
+    ///
+    /// let mut buffer = [0u8; OPTIONS.buffer_size_const::<i32, STANDARD>()];
+    /// let digits = (-123i32).to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("-00123"));
+    /// ```
+    ///
+    /// [`padding`]: Self::padding
+    #[inline(always)]
+    pub const fn min_width(&self) -> usize {
+        self.min_width
+    }
+
+    /// Get the characters to pad numbers shorter than the minimum width.
+    ///
+    /// Zero padding is an error if the number format does not allow
+    /// integer leading zeros. Defaults to [`Padding::Space`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::{Options, Padding};
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .padding(Padding::Zero)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.padding(), Padding::Zero);
+    /// ```
+    #[inline(always)]
+    pub const fn padding(&self) -> Padding {
+        self.padding
+    }
+
+    /// Get if a `+` sign is written before positive numbers.
+    ///
+    /// This is ignored if the number format does not allow a positive
+    /// sign. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .plus_sign(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.plus_sign(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn plus_sign(&self) -> bool {
+        self.plus_sign
+    }
+
     /// Get an upper bound on the required buffer size.
     ///
     /// This is always [`FORMATTED_SIZE`][FormattedSize::FORMATTED_SIZE]
     /// or [`FORMATTED_SIZE_DECIMAL`][FormattedSize::FORMATTED_SIZE_DECIMAL],
-    /// depending on the radix.
+    /// depending on the radix, plus room for any digit separators, base
+    /// prefix or suffix, and `+` sign, or the minimum width if larger.
     #[inline(always)]
     pub const fn buffer_size_const<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
-        if (NumberFormat::<FORMAT> {}.radix()) == 10 {
+        let format = NumberFormat::<FORMAT> {};
+        let formatted_size = if format.radix() == 10 {
             T::FORMATTED_SIZE_DECIMAL
         } else {
             T::FORMATTED_SIZE
+        };
+        let mut count = formatted_size;
+        if format.has_digit_separator() && format.integer_internal_digit_separator() {
+            count += self.digit_grouping.integer_separators(formatted_size);
+        }
+        if format.has_base_prefix() {
+            count += 2;
         }
+        if format.has_base_suffix() {
+            count += 1;
+        }
+        // Unsigned formatted sizes have no room for a sign.
+        if self.plus_sign || format.required_mantissa_sign() {
+            count += 1;
+        }
+        if count < self.min_width {
+            count = self.min_width;
+        }
+        count
     }
 
     // BUILDERS
@@ -170,7 +570,13 @@ impl Options {
     /// Create [`OptionsBuilder`] using existing values.
     #[inline(always)]
     pub const fn rebuild(&self) -> OptionsBuilder {
-        OptionsBuilder {}
+        OptionsBuilder {
+            digit_grouping: self.digit_grouping,
+            lowercase_digits: self.lowercase_digits,
+            min_width: self.min_width,
+            padding: self.padding,
+            plus_sign: self.plus_sign,
+        }
     }
 }
 
@@ -2,7 +2,7 @@
 
 #![doc(hidden)]
 
-use lexical_util::format;
+use lexical_util::format::{self, NumberFormat};
 
 /// Select the back-end.
 #[cfg(feature = "compact")]
@@ -12,6 +12,35 @@ use crate::decimal::Decimal;
 #[cfg(all(not(feature = "compact"), feature = "power-of-two"))]
 use crate::radix::Radix;
 
+/// Write the base prefix, such as `0x`, if the format has one.
+///
+/// Returns the number of bytes written.
+#[inline(always)]
+pub fn write_base_prefix<const FORMAT: u128>(buffer: &mut [u8]) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    if format.has_base_prefix() {
+        buffer[0] = b'0';
+        buffer[1] = format.base_prefix();
+        2
+    } else {
+        0
+    }
+}
+
+/// Write the base suffix, such as `h`, if the format has one.
+///
+/// Returns the number of bytes written.
+#[inline(always)]
+pub fn write_base_suffix<const FORMAT: u128>(buffer: &mut [u8]) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    if format.has_base_suffix() {
+        buffer[0] = format.base_suffix();
+        1
+    } else {
+        0
+    }
+}
+
 /// Define the implementation to write significant digits.
 macro_rules! write_mantissa {
     ($($t:tt)+) => {
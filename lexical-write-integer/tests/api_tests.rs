@@ -8,7 +8,7 @@ use lexical_util::constants::BUFFER_SIZE;
 #[cfg(feature = "format")]
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::format::STANDARD;
-use lexical_write_integer::{Options, ToLexical, ToLexicalWithOptions};
+use lexical_write_integer::{Options, Padding, ToLexical, ToLexicalWithOptions};
 #[cfg(feature = "radix")]
 use util::from_radix;
 
@@ -40,6 +40,101 @@ fn mandatory_sign_test() {
     assert_eq!(b"+1", 1i8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &OPTIONS));
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn format_sign_padding_test() {
+    let mut buffer = [b'\x00'; 16];
+    const OPTIONS: Options =
+        Options::builder().plus_sign(true).min_width(4).padding(Padding::Zero).build_strict();
+    const NO_SIGN: u128 = NumberFormatBuilder::new().no_positive_mantissa_sign(true).build_strict();
+    assert_eq!(b"0001", 1i8.to_lexical_with_options::<{ NO_SIGN }>(&mut buffer, &OPTIONS));
+    assert_eq!(b"-001", (-1i8).to_lexical_with_options::<{ NO_SIGN }>(&mut buffer, &OPTIONS));
+    assert_eq!(b"0001", 1u8.to_lexical_with_options::<{ NO_SIGN }>(&mut buffer, &OPTIONS));
+
+    const NO_ZEROS: u128 = NumberFormatBuilder::new().no_integer_leading_zeros(true).build_strict();
+    const SPACE: Options = OPTIONS.rebuild().padding(Padding::Space).build_strict();
+    assert_eq!(b"  +1", 1i8.to_lexical_with_options::<{ NO_ZEROS }>(&mut buffer, &SPACE));
+    assert_eq!(
+        Err(lexical_util::error::Error::UnsupportedPadding),
+        (-1i8).try_to_lexical_with_options::<{ NO_ZEROS }>(&mut buffer, &OPTIONS)
+    );
+    assert_eq!(
+        Err(lexical_util::error::Error::UnsupportedPadding),
+        100u8.try_to_lexical_with_options::<{ NO_ZEROS }>(&mut buffer, &OPTIONS)
+    );
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "format")]
+fn format_zero_padding_panic_test() {
+    let mut buffer = [b'\x00'; 16];
+    const OPTIONS: Options = Options::builder().min_width(4).padding(Padding::Zero).build_strict();
+    const NO_ZEROS: u128 = NumberFormatBuilder::new().no_integer_leading_zeros(true).build_strict();
+    1i8.to_lexical_with_options::<{ NO_ZEROS }>(&mut buffer, &OPTIONS);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_separator_test() {
+    use core::num;
+
+    use lexical_util::constants::FormattedSize;
+    use lexical_write_integer::DigitGrouping;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b','))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+    const INDIAN: Options = Options::builder().digit_grouping(DigitGrouping::INDIAN).build_strict();
+    const SIZE: usize = OPTIONS.buffer_size_const::<i128, FORMAT>();
+    assert_eq!(SIZE, i128::FORMATTED_SIZE_DECIMAL + 13);
+
+    let mut buffer = [b'\x00'; SIZE];
+    assert_eq!(b"0", 0u32.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS));
+    assert_eq!(b"999", 999u32.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS));
+    assert_eq!(b"1,000", 1000u32.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS));
+    assert_eq!(
+        b"-1,234,567",
+        (-1234567i32).to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS)
+    );
+    assert_eq!(b"12,34,567", 1234567u32.to_lexical_with_options::<FORMAT>(&mut buffer, &INDIAN));
+    assert_eq!(
+        b"-170,141,183,460,469,231,731,687,303,715,884,105,728",
+        i128::MIN.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS)
+    );
+
+    // Digit separators are only written if allowed between integer digits.
+    const LEADING: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .leading_digit_separator(true)
+        .build_strict();
+    assert_eq!(b"1234567", 1234567u32.to_lexical_with_options::<LEADING>(&mut buffer, &OPTIONS));
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn base_prefix_suffix_test() {
+    use core::num;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .base_suffix(num::NonZeroU8::new(b'h'))
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+    const LOWER: Options = Options::builder().lowercase_digits(true).build_strict();
+    let mut buffer = [b'\x00'; OPTIONS.buffer_size_const::<i64, FORMAT>()];
+    assert_eq!(b"0x0h", 0u8.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS));
+    assert_eq!(b"0xFFh", 255u8.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS));
+    assert_eq!(b"-0x1fh", (-31i32).to_lexical_with_options::<FORMAT>(&mut buffer, &LOWER));
+    assert_eq!(
+        b"-0x8000000000000000h",
+        i64::MIN.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS)
+    );
+}
+
 #[test]
 fn u8_test() {
     let mut buffer = [b'\x00'; 16];
@@ -206,6 +301,95 @@ fn options_test() {
     assert_eq!(b"0", 0i128.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &OPTIONS));
 }
 
+#[test]
+fn width_padding_sign_test() {
+    const SPACE: Options = Options::builder().min_width(6).build_strict();
+    const ZERO: Options = Options::builder().min_width(6).padding(Padding::Zero).build_strict();
+    const PLUS: Options = ZERO.rebuild().plus_sign(true).build_strict();
+
+    let mut buffer = [b'\x00'; SPACE.buffer_size_const::<i64, STANDARD>()];
+    assert_eq!(b"   123", 123u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &SPACE));
+    assert_eq!(b"  -123", (-123i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &SPACE));
+    assert_eq!(b"-00123", (-123i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ZERO));
+    assert_eq!(b"+00123", 123u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &PLUS));
+    assert_eq!(b"+00000", 0i64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &PLUS));
+    assert_eq!(
+        b"-1234567",
+        (-1234567i64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ZERO)
+    );
+
+    let mut buffer = [b'\x00'; PLUS.buffer_size_const::<u64, STANDARD>()];
+    assert_eq!(
+        b"+18446744073709551615",
+        u64::MAX.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &PLUS)
+    );
+
+    const WIDE: Options = Options::builder().min_width(30).build_strict();
+    assert_eq!(WIDE.buffer_size_const::<u8, STANDARD>(), 30);
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn zero_padding_prefix_test() {
+    use core::num;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .build_strict();
+    const OPTIONS: Options = Options::builder().min_width(7).padding(Padding::Zero).build_strict();
+    let mut buffer = [b'\x00'; OPTIONS.buffer_size_const::<i32, FORMAT>()];
+    assert_eq!(b"-0x001F", (-0x1Fi32).to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS));
+}
+
+#[test]
+fn try_write_test() {
+    use lexical_write_integer::Error;
+
+    let mut buffer = [b'\x00'; 64];
+    for length in 0..3 {
+        assert_eq!(Err(Error::BufferTooSmall(3)), 255u8.try_to_lexical(&mut buffer[..length]));
+        assert_eq!(
+            Err(Error::BufferTooSmall(4)),
+            (-128i8).try_to_lexical(&mut buffer[..length + 1])
+        );
+    }
+    assert_eq!(Ok(&b"255"[..]), 255u8.try_to_lexical(&mut buffer[..3]).map(|x| &*x));
+    assert_eq!(Ok(&b"-128"[..]), (-128i8).try_to_lexical(&mut buffer[..4]).map(|x| &*x));
+    assert_eq!(Err(Error::BufferTooSmall(39)), u128::MAX.try_to_lexical(&mut buffer[..38]));
+    assert_eq!(
+        Ok(&b"-170141183460469231731687303715884105728"[..]),
+        i128::MIN.try_to_lexical(&mut buffer).map(|x| &*x)
+    );
+
+    const ZERO: Options = Options::builder().min_width(6).padding(Padding::Zero).build_strict();
+    const WIDE: Options = Options::builder().min_width(1000).build_strict();
+    let mut buffer = [b'\x00'; 6];
+    assert_eq!(
+        Ok(&b"-00123"[..]),
+        (-123i32).try_to_lexical_with_options::<STANDARD>(&mut buffer, &ZERO).map(|x| &*x)
+    );
+    assert_eq!(
+        Err(Error::BufferTooSmall(6)),
+        (-123i32).try_to_lexical_with_options::<STANDARD>(&mut buffer[..5], &ZERO)
+    );
+    assert_eq!(
+        Err(Error::BufferTooSmall(1000)),
+        1u8.try_to_lexical_with_options::<STANDARD>(&mut buffer, &WIDE)
+    );
+
+    #[cfg(feature = "power-of-two")]
+    {
+        use lexical_util::format::NumberFormatBuilder;
+
+        const INVALID: u128 = NumberFormatBuilder::new().mantissa_radix(1).build_unchecked();
+        assert_eq!(
+            Err(Error::InvalidMantissaRadix),
+            1u8.try_to_lexical_with_options::<INVALID>(&mut buffer, &ZERO)
+        );
+    }
+}
+
 #[test]
 #[cfg(feature = "radix")]
 fn options_radix_test() {
@@ -1,4 +1,5 @@
 use lexical_write_integer::options::{Options, OptionsBuilder};
+use lexical_write_integer::{DigitGrouping, Padding};
 
 #[test]
 fn options_tests() {
@@ -10,3 +11,33 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(X.rebuild(), Options::builder());
 }
+
+#[test]
+fn digit_grouping_tests() {
+    assert_eq!(DigitGrouping::NONE.integer_separators(10), 0);
+    assert_eq!(DigitGrouping::THOUSANDS.integer_separators(3), 0);
+    assert_eq!(DigitGrouping::THOUSANDS.integer_separators(4), 1);
+    assert_eq!(DigitGrouping::THOUSANDS.integer_separators(7), 2);
+    assert_eq!(DigitGrouping::INDIAN.integer_separators(5), 1);
+    assert_eq!(DigitGrouping::INDIAN.integer_separators(6), 2);
+    assert_eq!(DigitGrouping::THOUSANDS.fraction_separators(3), 0);
+    assert_eq!(DigitGrouping::THOUSANDS.fraction_separators(4), 1);
+    assert_eq!(DigitGrouping::with_secondary(0, 2), DigitGrouping::NONE);
+    assert_eq!(DigitGrouping::with_secondary(3, 0), DigitGrouping::THOUSANDS);
+
+    const X: Options = Options::builder().digit_grouping(DigitGrouping::INDIAN).build_strict();
+    assert_eq!(X.digit_grouping(), DigitGrouping::INDIAN);
+    assert_eq!(X.rebuild().get_digit_grouping(), DigitGrouping::INDIAN);
+}
+
+#[test]
+fn padding_tests() {
+    const X: Options =
+        Options::builder().min_width(8).padding(Padding::Zero).plus_sign(true).build_strict();
+    assert_eq!(X.min_width(), 8);
+    assert_eq!(X.padding(), Padding::Zero);
+    assert!(X.plus_sign());
+    assert_eq!(X.rebuild().get_min_width(), 8);
+    assert_eq!(X.rebuild().get_padding(), Padding::Zero);
+    assert!(X.rebuild().get_plus_sign());
+}
@@ -612,8 +612,7 @@
     clippy::unnecessary_safety_comment,
     clippy::semicolon_if_nothing_returned,
     clippy::unwrap_used,
-    clippy::as_underscore,
-    clippy::doc_markdown
+    clippy::as_underscore
 )]
 #![allow(
     // used when concepts are logically separate
@@ -641,6 +640,15 @@ extern crate alloc;
 #[cfg(any(feature = "write-floats", feature = "write-integers"))]
 use alloc::string::String;
 
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+use alloc::vec::Vec;
+
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+use core::mem::MaybeUninit;
+
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+mod reader;
+
 // Re-exports
 pub use lexical_core::Error;
 pub use lexical_core::Result;
@@ -667,15 +675,20 @@ pub use lexical_core::ParseOptions;
 pub use lexical_core::{FromLexical, FromLexicalWithOptions};
 
 #[cfg(feature = "parse-floats")]
-pub use lexical_core::{parse_float_options, ParseFloatOptions, ParseFloatOptionsBuilder};
+pub use lexical_core::{
+    parse_float_options, ParseFloatOptions, ParseFloatOptionsBuilder, ParseRoundMode,
+};
 
 #[cfg(feature = "parse-integers")]
 pub use lexical_core::{parse_integer_options, ParseIntegerOptions, ParseIntegerOptionsBuilder};
 
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "parse-integers")))]
+pub use self::reader::{NumberReader, ReadError};
+
 // WRITE
 
 #[cfg(any(feature = "write-floats", feature = "write-integers"))]
-pub use lexical_core::WriteOptions;
+pub use lexical_core::{DigitGrouping, WriteOptions};
 
 #[cfg(any(feature = "write-floats", feature = "write-integers"))]
 pub use lexical_core::{ToLexical, ToLexicalWithOptions};
@@ -683,11 +696,18 @@ pub use lexical_core::{ToLexical, ToLexicalWithOptions};
 #[cfg(any(feature = "write-floats", feature = "write-integers"))]
 pub use lexical_core::{FormattedSize, BUFFER_SIZE};
 
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub use lexical_core::{display, NumberDisplay};
+
 #[cfg(feature = "write-floats")]
-pub use lexical_core::{write_float_options, WriteFloatOptions, WriteFloatOptionsBuilder};
+pub use lexical_core::{
+    write_float_options, Notation, WriteFloatOptions, WriteFloatOptionsBuilder, WriteRoundMode,
+};
 
 #[cfg(feature = "write-integers")]
-pub use lexical_core::{write_integer_options, WriteIntegerOptions, WriteIntegerOptionsBuilder};
+pub use lexical_core::{
+    write_integer_options, Padding, WriteIntegerOptions, WriteIntegerOptionsBuilder,
+};
 
 // NOTE: We cannot just use an uninitialized vector with excess capacity and
 // then use read-assign rather than `ptr::write` or `MaybeUninit.write` to
@@ -775,6 +795,178 @@ pub fn to_string_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
     }
 }
 
+/// Append a number to the end of a vector using `write`.
+///
+/// The spare capacity of the vector is reserved if required, and the
+/// number is written directly to it. If the number does not fit, such as
+/// with a large minimum width, the exact number of bytes required is
+/// reserved and the number is written again.
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn append_to_vec_impl<Cb>(vec: &mut Vec<u8>, size: usize, mut write: Cb)
+where
+    Cb: FnMut(&mut [MaybeUninit<u8>]) -> Result<usize>,
+{
+    vec.reserve(size);
+    let len = match write(vec.spare_capacity_mut()) {
+        Ok(len) => len,
+        Err(Error::BufferTooSmall(count)) => {
+            vec.reserve(count);
+            match write(vec.spare_capacity_mut()) {
+                Ok(len) => len,
+                Err(error) => core::panic!("{}", error.description()),
+            }
+        },
+        Err(error) => core::panic!("{}", error.description()),
+    };
+
+    // SAFETY: safe since the first `len` elements of the spare capacity were
+    // initialized by the writer.
+    unsafe { vec.set_len(vec.len() + len) };
+}
+
+/// Append a number to the end of a string using `append`.
+///
+/// The appended bytes are checked to be valid UTF-8 before they are
+/// committed to the string.
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn append_to_string_impl<Cb>(string: &mut String, append: Cb)
+where
+    Cb: FnOnce(&mut Vec<u8>),
+{
+    // SAFETY: safe since the appended bytes are removed before returning
+    // or panicking if they are not valid UTF-8.
+    let vec = unsafe { string.as_mut_vec() };
+    let start = vec.len();
+    append(vec);
+    if core::str::from_utf8(&vec[start..]).is_err() {
+        vec.truncate(start);
+        core::panic!("written number is not valid UTF-8");
+    }
+}
+
+/// Append a number as a decimal-encoded string to the end of a vector.
+///
+/// The spare capacity of the vector is reserved if required, and the
+/// number is written directly to it without a temporary buffer.
+///
+/// * `n`       - Number to convert to string.
+/// * `vec`     - Vector to append the number to.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut vec = b"x=".to_vec();
+/// lexical::append_to_vec(5, &mut vec);
+/// assert_eq!(vec, b"x=5");
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn append_to_vec<N: ToLexical>(n: N, vec: &mut Vec<u8>) {
+    append_to_vec_impl(vec, N::FORMATTED_SIZE_DECIMAL, |bytes| {
+        lexical_core::write_uninit(n, bytes).map(|digits| digits.len())
+    });
+}
+
+/// Append a number as a string to the end of a vector with custom writing
+/// options.
+///
+/// The spare capacity of the vector is reserved if required, and the
+/// number is written directly to it without a temporary buffer.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to convert to string.
+/// * `vec`     - Vector to append the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Panics
+///
+/// Panics if the format or options are invalid, or if a special float is
+/// disabled by the format or options.
+///
+/// # Examples
+///
+/// ```rust
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// const OPTIONS: lexical::WriteFloatOptions = lexical::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build_strict();
+/// let mut vec = b"x=".to_vec();
+/// lexical::append_to_vec_with_options::<_, FORMAT>(1.0, &mut vec, &OPTIONS);
+/// assert_eq!(vec, b"x=1");
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn append_to_vec_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    vec: &mut Vec<u8>,
+    options: &N::Options,
+) {
+    append_to_vec_impl(vec, N::FORMATTED_SIZE, |bytes| {
+        lexical_core::write_uninit_with_options::<_, FORMAT>(n, bytes, options)
+            .map(|digits| digits.len())
+    });
+}
+
+/// Append a number as a decimal-encoded string to the end of a string.
+///
+/// The spare capacity of the string is reserved if required, and the
+/// number is written directly to it without a temporary buffer.
+///
+/// * `n`       - Number to convert to string.
+/// * `string`  - String to append the number to.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut string = String::from("x=");
+/// lexical::append_to_string(0.5, &mut string);
+/// assert_eq!(string, "x=0.5");
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn append_to_string<N: ToLexical>(n: N, string: &mut String) {
+    append_to_string_impl(string, |vec| append_to_vec(n, vec));
+}
+
+/// Append a number as a string to the end of a string with custom writing
+/// options.
+///
+/// The spare capacity of the string is reserved if required, and the
+/// number is written directly to it without a temporary buffer.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to convert to string.
+/// * `string`  - String to append the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Panics
+///
+/// Panics if the format or options are invalid, or if a special float is
+/// disabled by the format or options.
+///
+/// # Examples
+///
+/// ```rust
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// const OPTIONS: lexical::WriteIntegerOptions = lexical::WriteIntegerOptions::builder()
+///     .min_width(4)
+///     .build_strict();
+/// let mut string = String::from("x=");
+/// lexical::append_to_string_with_options::<_, FORMAT>(12, &mut string, &OPTIONS);
+/// assert_eq!(string, "x=  12");
+/// ```
+#[inline]
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub fn append_to_string_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    string: &mut String,
+    options: &N::Options,
+) {
+    append_to_string_impl(string, |vec| append_to_vec_with_options::<_, FORMAT>(n, vec, options));
+}
+
 /// High-level conversion of decimal-encoded bytes to a number.
 ///
 /// This function only returns a value if the entire string is
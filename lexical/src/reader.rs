@@ -0,0 +1,328 @@
+//! Reader to parse delimiter-separated numbers from a byte stream.
+
+use std::io::{self, BufRead};
+use std::vec::Vec;
+use std::{error, fmt, result};
+
+use lexical_core::format::STANDARD;
+use lexical_core::{Error, FromLexicalWithOptions};
+
+/// The bytes that always separate numbers: ASCII whitespace.
+const WHITESPACE: &[u8] = b" \t\n\x0b\x0c\r";
+
+/// Error while reading numbers from a [`NumberReader`].
+#[derive(Debug)]
+pub enum ReadError {
+    /// An error occurred reading from the underlying reader.
+    Io(io::Error),
+    /// A token could not be parsed as a number.
+    ///
+    /// The `line` and `column` are the 1-based position of the first byte
+    /// of the token, where the column is in bytes. The index stored in the
+    /// `error` is relative to the start of the token.
+    Parse {
+        /// The error from parsing the token.
+        error: Error,
+        /// The line the token starts on.
+        line: usize,
+        /// The column, in bytes, the token starts on.
+        column: usize,
+    },
+}
+
+impl ReadError {
+    /// Get the parse error, if the token could not be parsed.
+    #[inline(always)]
+    pub const fn parse_error(&self) -> Option<&Error> {
+        match self {
+            Self::Parse {
+                error,
+                ..
+            } => Some(error),
+            Self::Io(_) => None,
+        }
+    }
+
+    /// Get the line and column the invalid token starts at, if any.
+    #[inline(always)]
+    pub const fn position(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                ..
+            } => Some((*line, *column)),
+            Self::Io(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    #[inline(always)]
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(formatter, "lexical read error: {error}"),
+            Self::Parse {
+                error,
+                line,
+                column,
+            } => write!(formatter, "{error} at line {line}, column {column}"),
+        }
+    }
+}
+
+impl error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Parse {
+                error,
+                ..
+            } => Some(error),
+        }
+    }
+}
+
+/// Reader that parses delimiter-separated numbers from a [`BufRead`].
+///
+/// Numbers are separated by runs of ASCII whitespace and any custom
+/// [`delimiters`], so empty fields are skipped. Each number is parsed
+/// from the bytes using the `FORMAT` and options, without validating the
+/// input as UTF-8. Tokens are parsed directly from the reader's buffer,
+/// and are only copied if they are split across the end of the buffer.
+///
+/// The reader can be used as an [`Iterator`] over the parsed numbers, or
+/// with [`read`]. A token that fails to parse returns a [`ReadError`] with
+/// the line and column of the token, and reading resumes from the next
+/// token.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "parse-floats")] {
+/// use lexical::{NumberReader, ParseFloatOptions};
+///
+/// const OPTIONS: ParseFloatOptions = ParseFloatOptions::new();
+///
+/// let data = b"1.5 2.0\n-3e2, 4\n";
+/// let reader = NumberReader::<_, f64>::new(&data[..], &OPTIONS).delimiters(b",");
+/// let values: Result<Vec<_>, _> = reader.collect();
+/// assert_eq!(values.unwrap(), [1.5, 2.0, -300.0, 4.0]);
+/// # }
+/// ```
+///
+/// [`delimiters`]: Self::delimiters
+/// [`read`]: Self::read
+pub struct NumberReader<'a, R, N: FromLexicalWithOptions, const FORMAT: u128 = STANDARD> {
+    /// The underlying reader.
+    reader: R,
+    /// The options to parse the numbers with.
+    options: &'a N::Options,
+    /// Lookup table for if a byte separates numbers.
+    delimiters: [bool; 256],
+    /// Storage for tokens split across the end of the buffer.
+    token: Vec<u8>,
+    /// The 1-based line of the next byte.
+    line: usize,
+    /// The 1-based column, in bytes, of the next byte.
+    column: usize,
+}
+
+impl<'a, R: BufRead, N: FromLexicalWithOptions, const FORMAT: u128> NumberReader<'a, R, N, FORMAT> {
+    /// Create a reader that parses numbers separated by ASCII whitespace.
+    ///
+    /// * `reader`  - The reader to parse numbers from.
+    /// * `options` - Options to specify number parsing.
+    ///
+    /// # Panics
+    ///
+    /// If the provided `FORMAT` is not valid, reading may panic. Please
+    /// ensure `is_valid()` is called prior to using the format, or checking
+    /// its validity using a static assertion.
+    #[inline]
+    pub fn new(reader: R, options: &'a N::Options) -> Self {
+        let mut delimiters = [false; 256];
+        for &byte in WHITESPACE {
+            delimiters[byte as usize] = true;
+        }
+        Self {
+            reader,
+            options,
+            delimiters,
+            token: Vec::new(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// Add bytes that separate numbers, in addition to ASCII whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse-integers")] {
+    /// use lexical::{NumberReader, ParseIntegerOptions};
+    ///
+    /// const OPTIONS: ParseIntegerOptions = ParseIntegerOptions::new();
+    ///
+    /// let mut reader = NumberReader::<_, u32>::new(&b"1;2,,3"[..], &OPTIONS)
+    ///     .delimiters(b",;");
+    /// assert_eq!(reader.read().unwrap(), Some(1));
+    /// assert_eq!(reader.read().unwrap(), Some(2));
+    /// assert_eq!(reader.read().unwrap(), Some(3));
+    /// assert_eq!(reader.read().unwrap(), None);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn delimiters(mut self, delimiters: &[u8]) -> Self {
+        for &byte in delimiters {
+            self.delimiters[byte as usize] = true;
+        }
+        self
+    }
+
+    /// Read and parse the next number, or [`None`] at the end of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse-integers")] {
+    /// use lexical::{NumberReader, ParseIntegerOptions};
+    ///
+    /// const OPTIONS: ParseIntegerOptions = ParseIntegerOptions::new();
+    ///
+    /// let mut reader = NumberReader::<_, i32>::new(&b"1\n 2a 3"[..], &OPTIONS);
+    /// assert_eq!(reader.read().unwrap(), Some(1));
+    /// let error = reader.read().unwrap_err();
+    /// assert!(error.parse_error().unwrap().is_invalid_digit());
+    /// assert_eq!(error.position(), Some((2, 2)));
+    /// assert_eq!(reader.read().unwrap(), Some(3));
+    /// assert_eq!(reader.read().unwrap(), None);
+    /// # }
+    /// ```
+    pub fn read(&mut self) -> result::Result<Option<N>, ReadError> {
+        if !self.skip_delimiters()? {
+            return Ok(None);
+        }
+
+        let line = self.line;
+        let column = self.column;
+        let into_error = |error| ReadError::Parse {
+            error,
+            line,
+            column,
+        };
+        self.token.clear();
+        loop {
+            let buffer = fill_buf(&mut self.reader)?;
+            if buffer.is_empty() {
+                break;
+            }
+            match buffer.iter().position(|&b| self.delimiters[b as usize]) {
+                Some(end) if self.token.is_empty() => {
+                    // Fast path: the token is entirely in the buffer.
+                    let result =
+                        N::from_lexical_with_options::<FORMAT>(&buffer[..end], self.options);
+                    self.reader.consume(end);
+                    self.column += end;
+                    return result.map(Some).map_err(into_error);
+                },
+                Some(end) => {
+                    self.token.extend_from_slice(&buffer[..end]);
+                    self.reader.consume(end);
+                    self.column += end;
+                    break;
+                },
+                None => {
+                    let length = buffer.len();
+                    self.token.extend_from_slice(buffer);
+                    self.reader.consume(length);
+                    self.column += length;
+                },
+            }
+        }
+
+        N::from_lexical_with_options::<FORMAT>(&self.token, self.options)
+            .map(Some)
+            .map_err(into_error)
+    }
+
+    /// Skip any delimiters, returning if there is another token.
+    fn skip_delimiters(&mut self) -> result::Result<bool, ReadError> {
+        loop {
+            let buffer = fill_buf(&mut self.reader)?;
+            if buffer.is_empty() {
+                return Ok(false);
+            }
+            let mut count = 0;
+            for &byte in buffer.iter().take_while(|&&b| self.delimiters[b as usize]) {
+                count += 1;
+                if byte == b'\n' {
+                    self.line += 1;
+                    self.column = 1;
+                } else {
+                    self.column += 1;
+                }
+            }
+            let found = count < buffer.len();
+            self.reader.consume(count);
+            if found {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Get the 1-based line and column, in bytes, of the next byte to read.
+    #[inline(always)]
+    pub const fn position(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+
+    /// Get a reference to the underlying reader.
+    #[inline(always)]
+    pub const fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Unwrap the reader, returning the underlying reader.
+    ///
+    /// Any bytes of a token that were partially read are lost.
+    #[inline(always)]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead, N: FromLexicalWithOptions, const FORMAT: u128> Iterator
+    for NumberReader<'_, R, N, FORMAT>
+{
+    type Item = result::Result<N, ReadError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+/// Fill the buffer of the reader, retrying if interrupted.
+///
+/// The buffer is filled again after a successful read, since returning it
+/// from inside the loop is rejected by the current borrow checker. This is
+/// cheap, since a filled buffer is returned without reading.
+#[inline]
+fn fill_buf<R: BufRead>(reader: &mut R) -> io::Result<&[u8]> {
+    loop {
+        match reader.fill_buf() {
+            Ok(_) => break,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    reader.fill_buf()
+}
@@ -16,6 +16,50 @@ fn float_to_string_test() {
     assert_eq!(lexical::to_string_with_options::<_, FORMAT>(12345.0f32, &OPTIONS), "12345.0");
 }
 
+#[test]
+#[cfg(all(feature = "write-integers", feature = "write-floats"))]
+fn append_to_string_test() {
+    let mut vec = Vec::new();
+    lexical::append_to_vec(12345u32, &mut vec);
+    vec.push(b',');
+    lexical::append_to_vec(-1.5f64, &mut vec);
+    assert_eq!(vec, b"12345,-1.5");
+
+    let mut string = String::with_capacity(2);
+    for value in [1u8, 20, 255] {
+        lexical::append_to_string(value, &mut string);
+    }
+    assert_eq!(string, "120255");
+
+    const FORMAT: u128 = lexical::format::STANDARD;
+    const DIGITS: lexical::WriteFloatOptions = lexical::WriteFloatOptions::builder()
+        .min_significant_digits(core::num::NonZeroUsize::new(500))
+        .build_strict();
+    let mut string = String::from("x=");
+    lexical::append_to_string_with_options::<_, FORMAT>(0.5f64, &mut string, &DIGITS);
+    assert_eq!(string.len(), 504);
+    assert!(string.starts_with("x=0.5000"));
+    assert_eq!(lexical::to_string_with_options::<_, FORMAT>(0.5f64, &DIGITS), string[2..]);
+
+    const WIDTH: lexical::WriteIntegerOptions =
+        lexical::WriteIntegerOptions::builder().min_width(300).build_strict();
+    let mut vec = b"x=".to_vec();
+    lexical::append_to_vec_with_options::<_, FORMAT>(1u8, &mut vec, &WIDTH);
+    assert_eq!(vec.len(), 302);
+    assert_eq!(vec.last(), Some(&b'1'));
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "write-floats")]
+fn append_to_string_invalid_test() {
+    const FORMAT: u128 = lexical::format::STANDARD;
+    const OPTIONS: lexical::WriteFloatOptions =
+        lexical::WriteFloatOptions::builder().decimal_point(0xFF).build_unchecked();
+    let mut string = String::from("x=");
+    lexical::append_to_string_with_options::<_, FORMAT>(1.5f64, &mut string, &OPTIONS);
+}
+
 #[test]
 #[cfg(feature = "parse-integers")]
 fn string_to_integer_test() {
@@ -45,3 +89,68 @@ fn string_to_float_test() {
         Ok((12345.0f32, 7))
     );
 }
+
+/// Reader that returns the data in chunks, to split numbers across buffers.
+#[cfg(all(feature = "std", feature = "parse-floats"))]
+struct ChunkedReader<'a> {
+    data: &'a [u8],
+    chunk: usize,
+}
+
+#[cfg(all(feature = "std", feature = "parse-floats"))]
+impl std::io::Read for ChunkedReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let count = std::io::BufRead::fill_buf(self)?.len().min(buf.len());
+        buf[..count].copy_from_slice(&self.data[..count]);
+        self.data = &self.data[count..];
+        Ok(count)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "parse-floats"))]
+impl std::io::BufRead for ChunkedReader<'_> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(&self.data[..self.chunk.min(self.data.len())])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.data = &self.data[amt..];
+    }
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "parse-floats"))]
+fn number_reader_test() {
+    use lexical::{NumberReader, ParseFloatOptions, ReadError};
+
+    const OPTIONS: ParseFloatOptions = ParseFloatOptions::new();
+    let data = b"1.25 -3.5e10\r\n\n  12345.678,0.5\t7";
+    let expected = [1.25, -3.5e10, 12345.678, 0.5, 7.0];
+    for chunk in 1..=data.len() {
+        let reader = ChunkedReader {
+            data,
+            chunk,
+        };
+        let values: Result<Vec<f64>, ReadError> =
+            NumberReader::<_, f64>::new(reader, &OPTIONS).delimiters(b",").collect();
+        assert_eq!(values.unwrap(), expected);
+    }
+
+    // Errors report the position of the token, even when split.
+    let reader = ChunkedReader {
+        data: b"1.0\n2.0 3.x0\n",
+        chunk: 2,
+    };
+    let mut reader = NumberReader::<_, f64>::new(reader, &OPTIONS);
+    assert_eq!(reader.read().unwrap(), Some(1.0));
+    assert_eq!(reader.read().unwrap(), Some(2.0));
+    let error = reader.read().unwrap_err();
+    assert_eq!(error.parse_error(), Some(&lexical::Error::InvalidDigit(2)));
+    assert_eq!(error.position(), Some((2, 5)));
+    assert_eq!(
+        error.to_string(),
+        "lexical parse error: 'invalid digit found' at index 2 at line 2, column 5"
+    );
+    assert_eq!(reader.read().unwrap(), None);
+    assert_eq!(reader.position(), (3, 1));
+}